@@ -1,23 +1,68 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
+use std::collections::BTreeMap;
+
 use cosmwasm_std::{
-    BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, Event, MessageInfo, 
-    Response, StdResult, Uint128, to_json_binary,
+    Addr, BankMsg, Binary, BlockInfo, Coin, CosmosMsg, Deps, DepsMut, Env, Event, MessageInfo, Order,
+    Response, StdResult, Storage, Uint128, WasmMsg, from_json, to_json_binary,
 };
-use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+use cw2::{get_contract_version, set_contract_version};
+use cw20::{BalanceResponse as Cw20BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg};
+use semver::Version;
+use sha2::{Digest, Sha256};
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, SudoMsg, EscrowResponse, CollectedFeesResponse, EscrowsResponse};
+use crate::msg::{ExecuteMsg, ReceiveMsg, ReleaseItem, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg, EscrowResponse, CollectedFeesResponse, EscrowsResponse, ReceiptResponse, DepositResponse, ChannelResponse, OpenChannelResponse, SubscriptionResponse, ConfigResponse, EffectiveFeeResponse, FeeOverrideTarget, UnaccountedBalanceResponse, FeeSource, VerifyEscrowReason, VerifyEscrowResponse, VerifyEscrowTokenResponse};
 use cosmwasm_std::StdError;
+use crate::migrations;
 use crate::registry_interface::query_tool;
-use crate::state::{Config, Escrow, CONFIG, ESCROWS, NEXT_ID};
+use crate::state::{
+    Channel, Config, Escrow, EscrowStatus, Expiration, ExpiryBounds, KeyType, PauseFlags, PendingWithdrawal, Subscription, CHANNELS, CONFIG,
+    DEPOSITS, ESCROWS, LIABILITIES, NEXT_CHANNEL_ID, NEXT_ID, PENDING_OWNER, PROVIDER_FEES, SUBSCRIPTIONS, TOOL_FEES,
+    WITHDRAWALS,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:escrow";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
-// Maximum number of blocks an escrow can be active
-const MAX_ESCROW_BLOCKS: u64 = 50;
+// TTL bounds of escrows for tools without their own bounds, unless set at instantiation
+pub const DEFAULT_EXPIRY_BOUNDS: ExpiryBounds = ExpiryBounds {
+    min_blocks: 0,
+    max_blocks: 50,
+    min_seconds: 0,
+    max_seconds: 300,
+};
+
+// Blocks a deposit withdrawal waits by default before it can be claimed
+pub const DEFAULT_WITHDRAWAL_DELAY: u64 = 100;
+
+// Blocks the provider has to settle outstanding vouchers after the caller requests a close
+pub const CHANNEL_CHALLENGE_BLOCKS: u64 = 100;
+
+// Domain separator for voucher digests
+const VOUCHER_PREFIX: &[u8] = b"toolpay-voucher";
+
+// Prefix of denoms that refer to a CW20 token contract, e.g. "cw20:neutron1..."
+pub const CW20_DENOM_PREFIX: &str = "cw20:";
+
+// Basis points in 100%, the upper bound of every fee rate
+const MAX_FEE_BPS: u64 = 10_000;
+
+// Upper bound of the keeper reward, which comes out of the callers' own refunds
+pub const MAX_KEEPER_REWARD_BPS: u64 = 100;
+
+// Pagination settings for GetEscrows
+const DEFAULT_LIMIT: u32 = 30;
+const MAX_LIMIT: u32 = 30;
+// Maximum number of escrows examined by a single GetEscrows query
+const MAX_SCAN: usize = 1000;
+
+// Number of escrows refunded by SweepExpired by default
+const DEFAULT_SWEEP_LIMIT: u32 = 10;
+// Maximum number of escrows settled by a single batch or sweep
+const MAX_BATCH_SIZE: u32 = 30;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -32,18 +77,24 @@ pub fn instantiate(
     // Validate registry address
     let registry_addr = deps.api.addr_validate(&msg.registry_addr)?;
     
-    // Validate fee percentage (0-100)
-    if msg.fee_percentage > 100 {
-        return Err(ContractError::InvalidFeePercentage(msg.fee_percentage));
+    // Validate fee (0-10000 basis points)
+    if msg.fee_bps > MAX_FEE_BPS {
+        return Err(ContractError::InvalidFeeBps(msg.fee_bps));
     }
     
+    let expiry_bounds = msg.expiry_bounds.unwrap_or(DEFAULT_EXPIRY_BOUNDS);
+    validate_expiry_bounds(&expiry_bounds)?;
+    
     // Initialize contract configuration
     CONFIG.save(deps.storage, &Config { 
-        frozen: false,
+        paused: PauseFlags::default(),
         registry_addr,
         owner: info.sender.clone(),
-        fee_percentage: msg.fee_percentage,
+        fee_bps: msg.fee_bps,
         collected_fees: vec![],
+        withdrawal_delay: msg.withdrawal_delay.unwrap_or(DEFAULT_WITHDRAWAL_DELAY),
+        expiry_bounds,
+        keeper_reward_bps: 0,
     })?;
     
     // Initialize the escrow ID counter
@@ -53,7 +104,7 @@ pub fn instantiate(
         .add_attribute("action", "instantiate")
         .add_attribute("registry_addr", msg.registry_addr)
         .add_attribute("owner", info.sender)
-        .add_attribute("fee_percentage", msg.fee_percentage.to_string()))
+        .add_attribute("fee_bps", msg.fee_bps.to_string()))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -63,35 +114,155 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // Check if contract is frozen
-    let config = CONFIG.load(deps.storage)?;
-    if config.frozen {
-        return Err(ContractError::Frozen {});
-    }
+    check_paused(&CONFIG.load(deps.storage)?.paused, &msg)?;
 
     match msg {
         ExecuteMsg::LockFunds {
             tool_id,
             max_fee,
-            auth_token,
+            expected_price,
+            auth_commitment,
+            expires,
+            from_deposit,
+        } => lock_funds(
+            deps,
+            env,
+            info,
+            tool_id,
+            max_fee,
+            expected_price,
+            auth_commitment,
             expires,
-        } => lock_funds(deps, env, info, tool_id, max_fee, auth_token, expires),
+            from_deposit.unwrap_or(false),
+        ),
+        ExecuteMsg::Receive(wrapper) => receive_cw20(deps, env, info, wrapper),
         ExecuteMsg::Release {
             escrow_id,
             usage_fee,
         } => release(deps, env, info, escrow_id, usage_fee),
+        ExecuteMsg::ReleaseBatch { items } => release_batch(deps, env, info, items),
+        ExecuteMsg::Charge { escrow_id, amount } => charge(deps, env, info, escrow_id, amount),
+        ExecuteMsg::Close { escrow_id } => close(deps, env, info, escrow_id),
         ExecuteMsg::RefundExpired { escrow_id } => refund_expired(deps, env, info, escrow_id),
+        ExecuteMsg::RefundExpiredBatch { escrow_ids } => refund_expired_batch(deps, env, info, escrow_ids),
+        ExecuteMsg::SweepExpired { limit } => sweep_expired(deps, env, info, limit),
+        ExecuteMsg::EmergencyWithdraw { escrow_id } => emergency_withdraw(deps, env, info, escrow_id),
+        ExecuteMsg::DeclineEscrow { escrow_id } => decline_escrow(deps, env, info, escrow_id),
+        ExecuteMsg::AcknowledgeEscrow { escrow_id } => acknowledge_escrow(deps, env, info, escrow_id),
+        ExecuteMsg::CancelEscrow { escrow_id } => cancel_escrow(deps, env, info, escrow_id),
+        ExecuteMsg::Deposit {} => deposit(deps, info),
+        ExecuteMsg::Withdraw { denom, amount } => withdraw(deps, env, info, denom, amount),
+        ExecuteMsg::ClaimWithdrawal { denom } => claim_withdrawal(deps, env, info, denom),
+        ExecuteMsg::OpenChannel { tool_id, pubkey, key_type } => open_channel(deps, info, tool_id, pubkey, key_type),
+        ExecuteMsg::SettleChannel { channel_id, amount, signature } => {
+            settle_channel(deps, env, info, channel_id, amount, signature)
+        }
+        ExecuteMsg::RequestCloseChannel { channel_id } => request_close_channel(deps, env, info, channel_id),
+        ExecuteMsg::CloseChannel { channel_id } => close_channel(deps, env, info, channel_id),
+        ExecuteMsg::Subscribe { tool_id, periods } => subscribe(deps, env, info, tool_id, periods),
+        ExecuteMsg::ClaimSubscription { caller, tool_id } => claim_subscription(deps, env, info, caller, tool_id),
+        ExecuteMsg::CancelSubscription { tool_id } => cancel_subscription(deps, env, info, tool_id),
+        ExecuteMsg::UpdateConfig { registry_addr, fee_bps, withdrawal_delay, expiry_bounds, keeper_reward_bps } => {
+            update_config(deps, info, registry_addr, fee_bps, withdrawal_delay, expiry_bounds, keeper_reward_bps)
+        }
+        ExecuteMsg::SetFeeOverride { target, fee_bps } => set_fee_override(deps, info, target, fee_bps),
+        ExecuteMsg::ProposeOwner { new_owner } => propose_owner(deps, info, new_owner),
+        ExecuteMsg::AcceptOwner {} => accept_owner(deps, info),
+        ExecuteMsg::SetPause { locks, releases, fee_claims } => {
+            let config = CONFIG.load(deps.storage)?;
+            if info.sender != config.owner {
+                return Err(ContractError::Unauthorized {});
+            }
+            set_pause(deps, locks, releases, fee_claims)
+        }
+        ExecuteMsg::RecoverUnaccounted { denom, recipient } => recover_unaccounted(deps, env, info, denom, recipient),
         ExecuteMsg::ClaimFees { denom } => claim_fees(deps, info, denom),
+        ExecuteMsg::PruneReceipts { escrow_ids } => prune_receipts(deps, info, escrow_ids),
+    }
+}
+
+// Rejects operations covered by a pause flag. Refunds, closes and withdrawals are
+// never covered, so callers can always get their funds back.
+fn check_paused(paused: &PauseFlags, msg: &ExecuteMsg) -> Result<(), ContractError> {
+    let operation = match msg {
+        ExecuteMsg::LockFunds { .. }
+        | ExecuteMsg::Receive(_)
+        | ExecuteMsg::Deposit {}
+        | ExecuteMsg::OpenChannel { .. }
+        | ExecuteMsg::Subscribe { .. } if paused.locks => "locks",
+        ExecuteMsg::Release { .. }
+        | ExecuteMsg::ReleaseBatch { .. }
+        | ExecuteMsg::Charge { .. }
+        | ExecuteMsg::SettleChannel { .. }
+        | ExecuteMsg::ClaimSubscription { .. } if paused.releases => "releases",
+        ExecuteMsg::ClaimFees { .. } if paused.fee_claims => "fee claims",
+        _ => return Ok(()),
+    };
+    
+    Err(ContractError::Paused { operation: operation.to_string() })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    // Only upgrade state written by this contract, and never to an older version
+    let stored = get_contract_version(deps.storage)?;
+    if stored.contract != CONTRACT_NAME {
+        return Err(ContractError::InvalidContractName {
+            expected: CONTRACT_NAME.to_string(),
+            found: stored.contract,
+        });
+    }
+    let stored_version: Version = stored.version.parse()?;
+    let new_version: Version = CONTRACT_VERSION.parse()?;
+    if stored_version > new_version {
+        return Err(ContractError::CannotDowngrade {
+            from: stored.version,
+            to: CONTRACT_VERSION.to_string(),
+        });
     }
+    
+    let mut response = Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", stored.version)
+        .add_attribute("to_version", CONTRACT_VERSION);
+    
+    // Run state transforms for every release between the stored and the new version
+    if stored_version < Version::new(0, 2, 0) {
+        let migrated = migrations::migrate_from_v0_1(deps.branch())?;
+        response = response.add_attribute("migrated_escrows", migrated.to_string());
+    }
+    
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    
+    Ok(response)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::GetEscrow { escrow_id } => to_json_binary(&query_escrow(deps, escrow_id)?),
+        QueryMsg::VerifyEscrowToken { escrow_id, token } => {
+            to_json_binary(&query_verify_escrow_token(deps, env, escrow_id, token)?)
+        }
+        QueryMsg::VerifyEscrow { escrow_id, tool_id, provider, min_amount } => {
+            to_json_binary(&query_verify_escrow(deps, env, escrow_id, tool_id, provider, min_amount)?)
+        }
+        QueryMsg::GetReceipt { escrow_id } => to_json_binary(&query_receipt(deps, escrow_id)?),
+        QueryMsg::GetDeposit { caller, denom } => to_json_binary(&query_deposit(deps, caller, denom)?),
+        QueryMsg::GetChannel { channel_id } => to_json_binary(&query_channel(deps, channel_id)?),
+        QueryMsg::GetSubscription { caller, tool_id } => {
+            to_json_binary(&query_subscription(deps, env, caller, tool_id)?)
+        }
+        QueryMsg::GetConfig {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::GetEffectiveFee { provider, tool_id } => {
+            to_json_binary(&query_effective_fee(deps, provider, tool_id)?)
+        }
+        QueryMsg::GetUnaccountedBalance { denom } => {
+            to_json_binary(&query_unaccounted_balance(deps, env, denom)?)
+        }
         QueryMsg::GetCollectedFees {} => to_json_binary(&query_collected_fees(deps)?),
-        QueryMsg::GetEscrows { caller, provider, start_after, limit } => {
-            to_json_binary(&query_escrows(deps, caller, provider, start_after, limit)?)
+        QueryMsg::GetEscrows { caller, provider, tool_id, status, start_after, limit, descending } => {
+            to_json_binary(&query_escrows(deps, caller, provider, tool_id, status, start_after, limit, descending)?)
         }
     }
 }
@@ -100,15 +271,116 @@ fn query_escrow(deps: Deps, escrow_id: u64) -> StdResult<EscrowResponse> {
     let escrow = ESCROWS.may_load(deps.storage, escrow_id)?
         .ok_or_else(|| StdError::not_found(format!("Escrow {} not found", escrow_id)))?;
     
-    // Convert to response format
-    Ok(EscrowResponse {
+    Ok(to_escrow_response(escrow_id, escrow))
+}
+
+// Convert a stored escrow to its response format
+fn to_escrow_response(escrow_id: u64, escrow: Escrow) -> EscrowResponse {
+    EscrowResponse {
         escrow_id,
         caller: escrow.caller,
         provider: escrow.provider,
+        tool_id: escrow.tool_id,
         max_fee: escrow.max_fee,
+        price: escrow.price,
         denom: escrow.denom,
         expires: escrow.expires,
-        auth_token: escrow.auth_token,
+        auth_commitment: escrow.auth_commitment,
+        status: escrow.status,
+        charged: escrow.charged,
+        platform_fee: escrow.platform_fee,
+        refunded: escrow.refunded,
+        settled_at: escrow.settled_at,
+        cancellable: escrow.cancellable,
+        acknowledged: escrow.acknowledged,
+    }
+}
+
+fn query_verify_escrow_token(
+    deps: Deps,
+    env: Env,
+    escrow_id: u64,
+    token: String,
+) -> StdResult<VerifyEscrowTokenResponse> {
+    let escrow = ESCROWS.may_load(deps.storage, escrow_id)?
+        .ok_or_else(|| StdError::not_found(format!("Escrow {} not found", escrow_id)))?;
+    
+    // Compare the hash of the presented token with the stored commitment
+    let matches = Sha256::digest(token.as_bytes()).as_slice() == escrow.auth_commitment.as_slice();
+    let valid = matches
+        && escrow.status == EscrowStatus::Locked
+        && !escrow.expires.is_expired(&env.block);
+    
+    Ok(VerifyEscrowTokenResponse {
+        valid,
+        remaining: if valid { escrow.chargeable() } else { Uint128::zero() },
+        expires: escrow.expires,
+    })
+}
+
+fn query_verify_escrow(
+    deps: Deps,
+    env: Env,
+    escrow_id: u64,
+    tool_id: String,
+    provider: String,
+    min_amount: Uint128,
+) -> StdResult<VerifyEscrowResponse> {
+    let verdict = |reason: Option<VerifyEscrowReason>, remaining: Uint128, expires: Option<Expiration>| {
+        VerifyEscrowResponse {
+            valid: reason.is_none(),
+            reason,
+            remaining,
+            expires,
+        }
+    };
+    
+    let Some(escrow) = ESCROWS.may_load(deps.storage, escrow_id)? else {
+        return Ok(verdict(Some(VerifyEscrowReason::NotFound), Uint128::zero(), None));
+    };
+    let remaining = escrow.chargeable();
+    let expires = Some(escrow.expires);
+    
+    // Report the first check that fails, contract-wide conditions first
+    let reason = if CONFIG.load(deps.storage)?.paused.releases {
+        Some(VerifyEscrowReason::Frozen)
+    } else if escrow.status != EscrowStatus::Locked {
+        Some(VerifyEscrowReason::NotLocked)
+    } else if escrow.expires.is_expired(&env.block) {
+        Some(VerifyEscrowReason::Expired)
+    } else if escrow.tool_id != tool_id {
+        Some(VerifyEscrowReason::WrongTool)
+    } else if escrow.provider.as_str() != provider {
+        Some(VerifyEscrowReason::WrongProvider)
+    } else if remaining < min_amount {
+        Some(VerifyEscrowReason::Insufficient)
+    } else {
+        None
+    };
+    
+    Ok(verdict(reason, remaining, expires))
+}
+
+fn query_receipt(deps: Deps, escrow_id: u64) -> StdResult<ReceiptResponse> {
+    let escrow = ESCROWS.may_load(deps.storage, escrow_id)?
+        .ok_or_else(|| StdError::not_found(format!("Escrow {} not found", escrow_id)))?;
+    
+    // Only settled escrows have a receipt
+    let settled_at = escrow.settled_at
+        .ok_or_else(|| StdError::generic_err(format!("Escrow {} has not been settled", escrow_id)))?;
+    
+    Ok(ReceiptResponse {
+        escrow_id,
+        tool_id: escrow.tool_id,
+        caller: escrow.caller,
+        provider: escrow.provider,
+        denom: escrow.denom,
+        status: escrow.status,
+        max_fee: escrow.max_fee,
+        charged: escrow.charged,
+        platform_fee: escrow.platform_fee,
+        refunded: escrow.refunded,
+        settled_at,
     })
 }
 
@@ -117,94 +389,210 @@ fn query_collected_fees(deps: Deps) -> StdResult<CollectedFeesResponse> {
     
     Ok(CollectedFeesResponse {
         owner: config.owner,
-        fee_percentage: config.fee_percentage,
+        fee_bps: config.fee_bps,
         collected_fees: config.collected_fees,
     })
 }
 
+fn query_channel(deps: Deps, channel_id: u64) -> StdResult<ChannelResponse> {
+    let channel = CHANNELS.may_load(deps.storage, channel_id)?
+        .ok_or_else(|| StdError::not_found(format!("Channel {} not found", channel_id)))?;
+    
+    Ok(ChannelResponse {
+        channel_id,
+        caller: channel.caller,
+        provider: channel.provider,
+        tool_id: channel.tool_id,
+        denom: channel.denom,
+        deposit: channel.deposit,
+        settled: channel.settled,
+        platform_fee: channel.platform_fee,
+        pubkey: channel.pubkey,
+        key_type: channel.key_type,
+        closes_at: channel.closes_at,
+        closed: channel.closed,
+    })
+}
+
+fn query_subscription(
+    deps: Deps,
+    env: Env,
+    caller: String,
+    tool_id: String,
+) -> StdResult<Option<SubscriptionResponse>> {
+    let caller = deps.api.addr_validate(&caller)?;
+    let Some(subscription) = SUBSCRIPTIONS.may_load(deps.storage, (&caller, &tool_id))? else {
+        return Ok(None);
+    };
+    
+    let paid_until = subscription.paid_until()?;
+    Ok(Some(SubscriptionResponse {
+        caller,
+        tool_id,
+        provider: subscription.provider,
+        denom: subscription.denom,
+        prices: subscription.prices,
+        period: subscription.period,
+        start: subscription.start,
+        periods: subscription.periods,
+        claimed: subscription.claimed,
+        paid_until,
+        entitled: env.block.height >= subscription.start && env.block.height < paid_until,
+    }))
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    
+    Ok(ConfigResponse {
+        registry_addr: config.registry_addr,
+        owner: config.owner,
+        pending_owner: PENDING_OWNER.may_load(deps.storage)?,
+        fee_bps: config.fee_bps,
+        withdrawal_delay: config.withdrawal_delay,
+        expiry_bounds: config.expiry_bounds,
+        keeper_reward_bps: config.keeper_reward_bps,
+        paused: config.paused,
+    })
+}
+
+fn query_effective_fee(deps: Deps, provider: String, tool_id: String) -> StdResult<EffectiveFeeResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let provider = deps.api.addr_validate(&provider)?;
+    let (fee_bps, source) = fee_bps_for(deps.storage, &config, &provider, &tool_id)?;
+    
+    Ok(EffectiveFeeResponse { fee_bps, source })
+}
+
+fn query_unaccounted_balance(deps: Deps, env: Env, denom: String) -> StdResult<UnaccountedBalanceResponse> {
+    let balance = contract_balance(deps, &env, &denom)?;
+    let liabilities = LIABILITIES.may_load(deps.storage, &denom)?.unwrap_or_default();
+    
+    Ok(UnaccountedBalanceResponse {
+        unaccounted: balance.saturating_sub(liabilities),
+        denom,
+        balance,
+        liabilities,
+    })
+}
+
+// Balance the contract holds in a native or CW20 denom
+fn contract_balance(deps: Deps, env: &Env, denom: &str) -> StdResult<Uint128> {
+    match denom.strip_prefix(CW20_DENOM_PREFIX) {
+        Some(token) => {
+            let res: Cw20BalanceResponse = deps.querier.query_wasm_smart(
+                token,
+                &Cw20QueryMsg::Balance { address: env.contract.address.to_string() },
+            )?;
+            Ok(res.balance)
+        }
+        None => Ok(deps.querier.query_balance(&env.contract.address, denom)?.amount),
+    }
+}
+
+fn query_deposit(deps: Deps, caller: String, denom: String) -> StdResult<DepositResponse> {
+    let caller = deps.api.addr_validate(&caller)?;
+    let balance = DEPOSITS.may_load(deps.storage, (&caller, &denom))?.unwrap_or_default();
+    let pending_withdrawal = WITHDRAWALS.may_load(deps.storage, (&caller, &denom))?;
+    
+    Ok(DepositResponse {
+        caller,
+        denom,
+        balance,
+        pending_withdrawal,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
 fn query_escrows(
     deps: Deps,
     caller: Option<String>,
     provider: Option<String>,
+    tool_id: Option<String>,
+    status: Option<EscrowStatus>,
     start_after: Option<u64>,
     limit: Option<u32>,
+    descending: Option<bool>,
 ) -> StdResult<EscrowsResponse> {
     // Set default and maximum limit
-    let limit = limit.unwrap_or(30).min(30) as usize;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    
+    // Validate filter addresses if provided
+    let caller_addr = caller.map(|c| deps.api.addr_validate(&c)).transpose()?;
+    let provider_addr = provider.map(|p| deps.api.addr_validate(&p)).transpose()?;
     
-    // Validate caller address if provided
-    let caller_addr = if let Some(caller_str) = caller {
-        Some(deps.api.addr_validate(&caller_str)?)
+    // The cursor bounds the range from above when walking newest first
+    let (order, min, max) = if descending.unwrap_or(false) {
+        (Order::Descending, None, start_after.map(Bound::exclusive))
     } else {
-        None
+        (Order::Ascending, start_after.map(Bound::exclusive), None)
     };
     
-    // Validate provider address if provided
-    let provider_addr = if let Some(provider_str) = provider {
-        Some(deps.api.addr_validate(&provider_str)?)
+    // Pick the narrowest index available for the requested filters
+    let items: Box<dyn Iterator<Item = StdResult<(u64, Escrow)>>> = if let Some(tool_id) = &tool_id {
+        ESCROWS.idx.tool_id.prefix(tool_id.clone()).range(deps.storage, min, max, order)
+    } else if let Some(provider) = &provider_addr {
+        ESCROWS.idx.provider.prefix(provider.to_string()).range(deps.storage, min, max, order)
+    } else if let Some(caller) = &caller_addr {
+        ESCROWS.idx.caller.prefix(caller.to_string()).range(deps.storage, min, max, order)
     } else {
-        None
+        ESCROWS.range(deps.storage, min, max, order)
     };
     
-    // Get all escrows starting from start_after
-    let start_bound = start_after.map(|id| cw_storage_plus::Bound::Exclusive((id, std::marker::PhantomData)));
-    let escrows: Result<Vec<_>, _> = ESCROWS
-        .range(deps.storage, start_bound, None, cosmwasm_std::Order::Ascending)
-        .take(limit)
-        .collect();
-    
-    let escrows = escrows?;
+    let mut escrows = Vec::new();
+    let mut last_seen = None;
+    let mut exhausted = true;
     
-    // Filter and convert to response format
-    let mut filtered_escrows = Vec::new();
-    
-    for (escrow_id, escrow) in escrows {
-        // Apply caller filter if specified
-        if let Some(ref caller_filter) = caller_addr {
-            if escrow.caller != *caller_filter {
-                continue;
-            }
-        }
-        
-        // Apply provider filter if specified
-        if let Some(ref provider_filter) = provider_addr {
-            if escrow.provider != *provider_filter {
-                continue;
-            }
+    for (scanned, item) in items.enumerate() {
+        // Stop once the page is full or the scan budget is spent
+        if escrows.len() >= limit || scanned >= MAX_SCAN {
+            exhausted = false;
+            break;
         }
         
-        // Convert to response format
-        filtered_escrows.push(EscrowResponse {
-            escrow_id,
-            caller: escrow.caller,
-            provider: escrow.provider,
-            max_fee: escrow.max_fee,
-            denom: escrow.denom,
-            expires: escrow.expires,
-            auth_token: escrow.auth_token,
-        });
+        let (escrow_id, escrow) = item?;
+        last_seen = Some(escrow_id);
         
-        // Stop if we've reached the limit after filtering
-        if filtered_escrows.len() >= limit {
-            break;
+        if caller_addr.as_ref().is_some_and(|c| escrow.caller != *c) {
+            continue;
+        }
+        if provider_addr.as_ref().is_some_and(|p| escrow.provider != *p) {
+            continue;
         }
+        if tool_id.as_ref().is_some_and(|t| escrow.tool_id != *t) {
+            continue;
+        }
+        if status.as_ref().is_some_and(|s| escrow.status != *s) {
+            continue;
+        }
+        
+        escrows.push(to_escrow_response(escrow_id, escrow));
     }
     
     Ok(EscrowsResponse {
-        escrows: filtered_escrows,
+        escrows,
+        next_start_after: if exhausted { None } else { last_seen },
     })
 }
 
 // Implementation of LockFunds functionality
+#[allow(clippy::too_many_arguments)]
 pub fn lock_funds(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     tool_id: String,
     max_fee: Uint128,
-    auth_token: String,
-    expires: u64,
+    expected_price: Option<Uint128>,
+    auth_commitment: Binary,
+    expires: Expiration,
+    from_deposit: bool,
 ) -> Result<Response, ContractError> {
+    // The commitment must be a sha256 hash so the token itself never touches the chain
+    if auth_commitment.len() != 32 {
+        return Err(ContractError::InvalidCommitment { len: auth_commitment.len() });
+    }
+    
     // Get the registry address from config
     let config = CONFIG.load(deps.storage)?;
     
@@ -217,47 +605,90 @@ pub fn lock_funds(
         return Err(ContractError::ToolNotActive {});
     }
 
-    // Find the funds with the matching denom
-    let attached_funds = info
-        .funds
-        .iter()
-        .find(|c| c.denom == tool.denom)
-        .map(|c| c.amount)
-        .unwrap_or(Uint128::zero());
+    // Abort if the provider changed the price since the caller was quoted
+    if let Some(expected_price) = expected_price {
+        if expected_price != tool.price {
+            return Err(ContractError::PriceChanged {
+                expected: expected_price.to_string(),
+                current: tool.price.to_string(),
+            });
+        }
+    }
 
-    // Check if any funds with the correct denom were provided
-    if attached_funds.is_zero() {
-        return Err(ContractError::NoDenomFunds { 
-            denom: tool.denom.clone() 
+    // The caller must be willing to pay at least the tool price
+    if max_fee < tool.price {
+        return Err(ContractError::MaxFeeBelowPrice {
+            price: tool.price.to_string(),
+            max_fee: max_fee.to_string(),
         });
     }
 
-    // Validate that max_fee doesn't exceed attached funds
-    if attached_funds < max_fee {
-        return Err(ContractError::InsufficientFunds {
-            required: max_fee.to_string(),
-            available: attached_funds.to_string(),
-        });
+    if from_deposit {
+        // Draw max_fee from the caller's prepaid balance
+        if !info.funds.is_empty() {
+            return Err(ContractError::UnexpectedFunds {});
+        }
+        let key = (&info.sender, tool.denom.as_str());
+        let balance = DEPOSITS.may_load(deps.storage, key)?.unwrap_or_default();
+        if balance < max_fee {
+            return Err(ContractError::InsufficientDeposit {
+                required: max_fee.to_string(),
+                available: balance.to_string(),
+            });
+        }
+        DEPOSITS.save(deps.storage, key, &(balance - max_fee))?;
+    } else {
+        // Find the funds with the matching denom
+        let attached_funds = info
+            .funds
+            .iter()
+            .find(|c| c.denom == tool.denom)
+            .map(|c| c.amount)
+            .unwrap_or(Uint128::zero());
+
+        // Check if any funds with the correct denom were provided
+        if attached_funds.is_zero() {
+            return Err(ContractError::NoDenomFunds { 
+                denom: tool.denom.clone() 
+            });
+        }
+
+        // Validate that max_fee doesn't exceed attached funds
+        if attached_funds < max_fee {
+            return Err(ContractError::InsufficientFunds {
+                required: max_fee.to_string(),
+                available: attached_funds.to_string(),
+            });
+        }
     }
 
-    // Validate expires is within limits (≤ 50 blocks)
-    let current_block = env.block.height;
-    let blocks_until_expiry = expires.saturating_sub(current_block);
-    if blocks_until_expiry > MAX_ESCROW_BLOCKS {
-        return Err(ContractError::ExpirationTooLong {
-            max_blocks: MAX_ESCROW_BLOCKS,
-            got_blocks: blocks_until_expiry,
-        });
+    // Take on max_fee and hand back everything else that was attached
+    let surplus_msgs = refund_surplus(&info.sender, &info.funds, &tool.denom, max_fee)?;
+    if !from_deposit {
+        credit_liability(deps.storage, &tool.denom, max_fee)?;
     }
+
+    // The tool's own TTL bounds take precedence over the contract defaults
+    validate_expiration(&env.block, &expires, &tool.expiry_bounds.unwrap_or(config.expiry_bounds))?;
     
     // Create and store Escrow object
     let escrow = Escrow {
         caller: info.sender.clone(),
         provider: tool.provider,
+        tool_id: tool_id.clone(),
         max_fee,
+        price: tool.price,
         denom: tool.denom.clone(),
-        auth_token,
+        auth_commitment,
         expires,
+        status: EscrowStatus::Locked,
+        charged: Uint128::zero(),
+        platform_fee: Uint128::zero(),
+        refunded: Uint128::zero(),
+        settled_at: None,
+        from_deposit,
+        cancellable: tool.cancellable,
+        acknowledged: false,
     };
 
     // Get new escrow ID
@@ -275,8 +706,10 @@ pub fn lock_funds(
         .add_attribute("tool_id", tool_id)
         .add_attribute("caller", info.sender)
         .add_attribute("max_fee", max_fee.to_string())
+        .add_attribute("price", tool.price.to_string())
         .add_attribute("denom", tool.denom.clone())
-        .add_attribute("expires", expires.to_string());
+        .add_attribute("expires", expires.to_string())
+        .add_attribute("from_deposit", from_deposit.to_string());
     
     // Create response data with escrow_id and denom
     let response_data = to_json_binary(&crate::msg::LockFundsResponse { 
@@ -286,109 +719,316 @@ pub fn lock_funds(
 
     // Return success response with escrow_id
     Ok(Response::new()
+        .add_messages(surplus_msgs)
         .add_event(event)
         .add_attribute("action", "lock_funds")
         .add_attribute("escrow_id", id.to_string())
         .set_data(response_data))
 }
 
-// Implementation of Release functionality
-pub fn release(
+// Implementation of Receive functionality: CW20 tokens sent with an embedded LockFunds.
+// The tokens are treated like attached funds in the "cw20:<token contract>" denom.
+pub fn receive_cw20(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    escrow_id: u64,
-    usage_fee: Uint128,
+    wrapper: Cw20ReceiveMsg,
 ) -> Result<Response, ContractError> {
-    // Load escrow by id
-    let escrow = ESCROWS.may_load(deps.storage, escrow_id)?
-        .ok_or(ContractError::EscrowNotFound {})?;
+    let caller = deps.api.addr_validate(&wrapper.sender)?;
+    let funds = vec![Coin {
+        denom: format!("{}{}", CW20_DENOM_PREFIX, info.sender),
+        amount: wrapper.amount,
+    }];
+    
+    match from_json(&wrapper.msg)? {
+        ReceiveMsg::LockFunds {
+            tool_id,
+            max_fee,
+            expected_price,
+            auth_commitment,
+            expires,
+        } => lock_funds(
+            deps,
+            env,
+            MessageInfo { sender: caller, funds },
+            tool_id,
+            max_fee,
+            expected_price,
+            auth_commitment,
+            expires,
+            false,
+        ),
+    }
+}
+
+// Fee rate for a provider's tool: a tool override wins over a provider override,
+// which wins over the configured default
+fn fee_bps_for(
+    storage: &dyn Storage,
+    config: &Config,
+    provider: &Addr,
+    tool_id: &str,
+) -> StdResult<(u64, FeeSource)> {
+    if let Some(fee_bps) = TOOL_FEES.may_load(storage, tool_id)? {
+        return Ok((fee_bps, FeeSource::Tool));
+    }
+    if let Some(fee_bps) = PROVIDER_FEES.may_load(storage, provider)? {
+        return Ok((fee_bps, FeeSource::Provider));
+    }
+    Ok((config.fee_bps, FeeSource::Default))
+}
+
+// Platform fee owed on a cumulative charged amount, rounded down in favor of the provider
+fn platform_fee_for(fee_bps: u64, charged: Uint128) -> Uint128 {
+    charged.multiply_ratio(fee_bps, MAX_FEE_BPS)
+}
+
+// Add a platform fee to the collected fees of its denom
+fn add_collected_fee(config: &mut Config, denom: &str, amount: Uint128) {
+    if amount.is_zero() {
+        return;
+    }
     
+    // Find and update existing entry for this denom, or add a new one
+    match config.collected_fees.iter_mut().find(|(d, _)| d == denom) {
+        Some((_, collected)) => *collected += amount,
+        None => config.collected_fees.push((denom.to_string(), amount)),
+    }
+}
+
+// Checks that the provider may charge `amount` now and books it on the escrow.
+// The platform fee is computed on the cumulative total so that rounding across
+// several charges matches a single release of the same total.
+//
+// The platform fee is added to `config`, which the caller saves.
+//
+// Returns the provider's share and the platform fee of this charge.
+fn apply_charge(
+    storage: &dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    escrow: &mut Escrow,
+    amount: Uint128,
+) -> Result<(Uint128, Uint128), ContractError> {
     // Verify caller is the original provider
-    if info.sender != escrow.provider {
+    if *sender != escrow.provider {
         return Err(ContractError::Unauthorized {});
     }
     
+    // Verify escrow hasn't been settled yet
+    if escrow.status != EscrowStatus::Locked {
+        return Err(ContractError::EscrowNotLocked {});
+    }
+    
     // Verify escrow hasn't expired
-    if env.block.height > escrow.expires {
+    if escrow.expires.is_expired(&env.block) {
         return Err(ContractError::EscrowExpired {});
     }
     
-    // Verify usage_fee ≤ max_fee
-    if usage_fee > escrow.max_fee {
+    // Verify amount ≤ what is left of max_fee
+    let remaining = escrow.max_fee - escrow.charged;
+    if amount > remaining {
         return Err(ContractError::FeeTooHigh {
-            max_fee: escrow.max_fee.to_string(),
-            requested_fee: usage_fee.to_string(),
+            max_fee: remaining.to_string(),
+            requested_fee: amount.to_string(),
         });
     }
     
-    // Load config to get fee percentage
-    let mut config = CONFIG.load(deps.storage)?;
+    // Verify the total charged ≤ price recorded at lock time, so splitting a
+    // charge into several cannot get around the price
+    let new_charged = escrow.charged + amount;
+    if new_charged > escrow.price {
+        return Err(ContractError::FeeExceedsPrice {
+            price: escrow.price.to_string(),
+            requested_fee: new_charged.to_string(),
+        });
+    }
     
-    // Calculate platform fee
-    let platform_fee = if config.fee_percentage > 0 {
-        usage_fee.multiply_ratio(config.fee_percentage, 100u64)
-    } else {
-        Uint128::zero()
-    };
+    // The fee percentage may have changed since earlier charges, so the fee owed on the
+    // cumulative amount is clamped to what this charge can cover
+    let (fee_bps, _) = fee_bps_for(storage, config, &escrow.provider, &escrow.tool_id)?;
+    let platform_fee = platform_fee_for(fee_bps, new_charged)
+        .saturating_sub(escrow.platform_fee)
+        .min(amount);
+    let provider_fee = amount - platform_fee;
     
-    // Calculate provider fee (after platform fee)
-    let provider_fee = usage_fee.checked_sub(platform_fee)
-        .expect("Platform fee is a percentage of usage fee, cannot overflow");
+    add_collected_fee(config, &escrow.denom, platform_fee);
     
-    // Calculate refund amount (if any)
-    let refund_amount = escrow.max_fee.checked_sub(usage_fee)
-        .expect("Usage fee is already verified to be <= max_fee");
+    escrow.charged = new_charged;
+    escrow.platform_fee += platform_fee;
+    // Charging implies the provider is serving the call
+    escrow.acknowledged = true;
     
-    // Update collected fees in config
-    if !platform_fee.is_zero() {
-        let denom = escrow.denom.clone();
-        
-        // Find and update existing entry for this denom, or add a new one
-        let mut found = false;
-        for fee in &mut config.collected_fees {
-            if fee.0 == denom {
-                fee.1 += platform_fee;
-                found = true;
-                break;
-            }
-        }
-        
-        if !found {
-            config.collected_fees.push((denom, platform_fee));
+    Ok((provider_fee, platform_fee))
+}
+
+// Records funds the contract now owes to callers, providers or the owner
+fn credit_liability(storage: &mut dyn Storage, denom: &str, amount: Uint128) -> StdResult<()> {
+    if !amount.is_zero() {
+        LIABILITIES.update(storage, denom, |owed| -> StdResult<_> { Ok(owed.unwrap_or_default() + amount) })?;
+    }
+    Ok(())
+}
+
+// Pays out owed funds: the payout is removed from the liabilities of its denom
+fn send_msg(
+    storage: &mut dyn Storage,
+    to: &Addr,
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<Option<CosmosMsg>> {
+    if !amount.is_zero() {
+        LIABILITIES.update(storage, denom, |owed| -> StdResult<_> {
+            Ok(owed.unwrap_or_default().saturating_sub(amount))
+        })?;
+    }
+    transfer_msg(to, denom, amount)
+}
+
+// Returns attached coins the contract does not take on: the surplus over `accepted`
+// in `denom` and every coin in another denom
+fn refund_surplus(to: &Addr, funds: &[Coin], denom: &str, accepted: Uint128) -> StdResult<Vec<CosmosMsg>> {
+    let mut messages = vec![];
+    for coin in funds {
+        let surplus = if coin.denom == denom { coin.amount - accepted } else { coin.amount };
+        messages.extend(transfer_msg(to, &coin.denom, surplus)?);
+    }
+    Ok(messages)
+}
+
+// Rejects expirations that already passed or fall outside the TTL bounds
+fn validate_expiration(block: &BlockInfo, expires: &Expiration, bounds: &ExpiryBounds) -> Result<(), ContractError> {
+    if expires.is_expired(block) {
+        return Err(ContractError::ExpirationInPast {});
+    }
+    
+    let (ttl, min, max, unit) = match expires {
+        Expiration::AtHeight(height) => (height - block.height, bounds.min_blocks, bounds.max_blocks, "blocks"),
+        Expiration::AtTime(time) => {
+            (time.seconds() - block.time.seconds(), bounds.min_seconds, bounds.max_seconds, "seconds")
         }
-        
-        // Save updated config
-        CONFIG.save(deps.storage, &config)?;
+    };
+    if ttl < min {
+        return Err(ContractError::ExpirationTooSoon { min, got: ttl, unit: unit.to_string() });
+    }
+    if ttl > max {
+        return Err(ContractError::ExpirationTooLong { max, got: ttl, unit: unit.to_string() });
+    }
+    Ok(())
+}
+
+fn validate_expiry_bounds(bounds: &ExpiryBounds) -> Result<(), ContractError> {
+    if bounds.min_blocks > bounds.max_blocks || bounds.min_seconds > bounds.max_seconds {
+        return Err(ContractError::InvalidExpiryBounds {});
+    }
+    Ok(())
+}
+
+// Bank or CW20 transfer message sending `amount` of `denom`, or None for a zero amount
+fn transfer_msg(to: &Addr, denom: &str, amount: Uint128) -> StdResult<Option<CosmosMsg>> {
+    if amount.is_zero() {
+        return Ok(None);
     }
     
-    // Create messages for transferring funds
-    let mut messages: Vec<CosmosMsg> = vec![];
+    // CW20 denoms are paid out by the token contract
+    if let Some(token) = denom.strip_prefix(CW20_DENOM_PREFIX) {
+        return Ok(Some(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: token.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: to.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        })));
+    }
     
-    // Transfer provider_fee to provider
-    if !provider_fee.is_zero() {
-        messages.push(CosmosMsg::Bank(BankMsg::Send {
-            to_address: escrow.provider.to_string(),
-            amount: vec![Coin {
-                denom: escrow.denom.clone(),
-                amount: provider_fee,
-            }],
-        }));
+    Ok(Some(CosmosMsg::Bank(BankMsg::Send {
+        to_address: to.to_string(),
+        amount: vec![Coin {
+            denom: denom.to_string(),
+            amount,
+        }],
+    })))
+}
+
+// Returns uncharged escrow funds to the caller: credited back to the deposit for
+// escrows drawn from it, otherwise sent to the caller's account
+fn refund_caller(
+    storage: &mut dyn Storage,
+    escrow: &Escrow,
+    amount: Uint128,
+) -> StdResult<Option<CosmosMsg>> {
+    if !escrow.from_deposit {
+        return send_msg(storage, &escrow.caller, &escrow.denom, amount);
     }
     
-    // Transfer remaining funds (if any) to original caller
-    if !refund_amount.is_zero() {
-        messages.push(CosmosMsg::Bank(BankMsg::Send {
-            to_address: escrow.caller.to_string(),
-            amount: vec![Coin {
-                denom: escrow.denom.clone(),
-                amount: refund_amount,
-            }],
-        }));
+    if !amount.is_zero() {
+        DEPOSITS.update(storage, (&escrow.caller, &escrow.denom), |balance| -> StdResult<_> {
+            Ok(balance.unwrap_or_default() + amount)
+        })?;
     }
+    Ok(None)
+}
+
+// Implementation of Release functionality
+pub fn release(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    escrow_id: u64,
+    usage_fee: Uint128,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let released = release_escrow(deps.storage, &env, &info.sender, &mut config, escrow_id, usage_fee)?;
+    CONFIG.save(deps.storage, &config)?;
+    
+    // Create messages for transferring funds
+    let escrow = &released.escrow;
+    let messages: Vec<CosmosMsg> = [
+        send_msg(deps.storage, &escrow.provider, &escrow.denom, released.provider_fee)?,
+        refund_caller(deps.storage, escrow, escrow.refunded)?,
+    ].into_iter().flatten().collect();
+    
+    // Return success response
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_event(released.event)
+        .add_attribute("action", "release")
+        .add_attribute("escrow_id", escrow_id.to_string()))
+}
+
+// Escrow settled by a release, with the payouts still to be made
+struct Released {
+    escrow: Escrow,
+    provider_fee: Uint128,
+    platform_fee: Uint128,
+    event: Event,
+}
+
+// Books the final charge of an escrow and marks it released. The provider fee and
+// the refund recorded on the escrow are left for the caller to pay out.
+fn release_escrow(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    escrow_id: u64,
+    usage_fee: Uint128,
+) -> Result<Released, ContractError> {
+    // Load escrow by id
+    let mut escrow = ESCROWS.may_load(storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+    
+    // Book the final charge, then refund whatever is left
+    let (provider_fee, platform_fee) = apply_charge(storage, env, sender, config, &mut escrow, usage_fee)?;
+    let refund_amount = escrow.max_fee - escrow.charged;
     
-    // Remove escrow from storage
-    ESCROWS.remove(deps.storage, escrow_id);
+    // Keep the settled escrow as a receipt
+    escrow.status = EscrowStatus::Released;
+    escrow.refunded = refund_amount;
+    escrow.settled_at = Some(env.block.height);
+    ESCROWS.save(storage, escrow_id, &escrow)?;
     
     // Create wasm-toolpay.released event
     let event = Event::new("wasm-toolpay.released")
@@ -399,77 +1039,1248 @@ pub fn release(
         .add_attribute("provider_fee", provider_fee.to_string())
         .add_attribute("platform_fee", platform_fee.to_string())
         .add_attribute("refund_amount", refund_amount.to_string())
-        .add_attribute("denom", escrow.denom);
+        .add_attribute("denom", escrow.denom.clone());
+    
+    Ok(Released { escrow, provider_fee, platform_fee, event })
+}
+
+// Implementation of ReleaseBatch functionality
+pub fn release_batch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    items: Vec<ReleaseItem>,
+) -> Result<Response, ContractError> {
+    if items.len() > MAX_BATCH_SIZE as usize {
+        return Err(ContractError::BatchTooLarge { max: MAX_BATCH_SIZE });
+    }
+    
+    let mut config = CONFIG.load(deps.storage)?;
+    let mut events = vec![];
+    // Payouts are merged per recipient and denom so each gets a single transfer
+    let mut payouts: BTreeMap<(Addr, String), Uint128> = BTreeMap::new();
+    let mut platform_fees: BTreeMap<String, Uint128> = BTreeMap::new();
+    
+    // Any invalid item fails the whole batch
+    for item in items {
+        let released = release_escrow(deps.storage, &env, &info.sender, &mut config, item.escrow_id, item.usage_fee)?;
+        let escrow = released.escrow;
+        
+        *payouts.entry((escrow.provider.clone(), escrow.denom.clone())).or_default() += released.provider_fee;
+        if escrow.from_deposit {
+            refund_caller(deps.storage, &escrow, escrow.refunded)?;
+        } else {
+            *payouts.entry((escrow.caller.clone(), escrow.denom.clone())).or_default() += escrow.refunded;
+        }
+        *platform_fees.entry(escrow.denom).or_default() += released.platform_fee;
+        events.push(released.event);
+    }
+    CONFIG.save(deps.storage, &config)?;
+    
+    let mut messages = vec![];
+    for ((to, denom), amount) in &payouts {
+        messages.extend(send_msg(deps.storage, to, denom, *amount)?);
+    }
+    
+    let platform_fees = platform_fees.iter().map(|(denom, amount)| format!("{amount}{denom}")).collect::<Vec<_>>();
+    let summary = Event::new("wasm-toolpay.batch_released")
+        .add_attribute("provider", info.sender.to_string())
+        .add_attribute("count", events.len().to_string())
+        .add_attribute("platform_fees", platform_fees.join(","));
+    
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_events(events)
+        .add_event(summary)
+        .add_attribute("action", "release_batch"))
+}
+
+// Implementation of Charge functionality
+pub fn charge(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    escrow_id: u64,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    // Load escrow by id
+    let mut escrow = ESCROWS.may_load(deps.storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+    
+    if amount.is_zero() {
+        return Err(ContractError::ZeroCharge {});
+    }
+    
+    let mut config = CONFIG.load(deps.storage)?;
+    let (provider_fee, platform_fee) = apply_charge(deps.storage, &env, &info.sender, &mut config, &mut escrow, amount)?;
+    CONFIG.save(deps.storage, &config)?;
+    ESCROWS.save(deps.storage, escrow_id, &escrow)?;
+    
+    // Create wasm-toolpay.charged event
+    let event = Event::new("wasm-toolpay.charged")
+        .add_attribute("escrow_id", escrow_id.to_string())
+        .add_attribute("provider", escrow.provider.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("provider_fee", provider_fee.to_string())
+        .add_attribute("platform_fee", platform_fee.to_string())
+        .add_attribute("total_charged", escrow.charged.to_string())
+        .add_attribute("remaining", escrow.chargeable().to_string())
+        .add_attribute("denom", escrow.denom.clone());
+    
+    // Return success response
+    Ok(Response::new()
+        .add_messages(send_msg(deps.storage, &escrow.provider, &escrow.denom, provider_fee)?)
+        .add_event(event)
+        .add_attribute("action", "charge")
+        .add_attribute("escrow_id", escrow_id.to_string()))
+}
+
+// Implementation of Close functionality
+pub fn close(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    escrow_id: u64,
+) -> Result<Response, ContractError> {
+    // Load escrow by id
+    let mut escrow = ESCROWS.may_load(deps.storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+    
+    // Verify caller is the original provider
+    if info.sender != escrow.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Verify escrow hasn't been settled yet
+    if escrow.status != EscrowStatus::Locked {
+        return Err(ContractError::EscrowNotLocked {});
+    }
+    
+    // Refund whatever has not been charged
+    let refund_amount = escrow.max_fee - escrow.charged;
+    let refund_msg = refund_caller(deps.storage, &escrow, refund_amount)?;
+    
+    // Keep the settled escrow as a receipt
+    escrow.status = EscrowStatus::Released;
+    escrow.refunded = refund_amount;
+    escrow.settled_at = Some(env.block.height);
+    ESCROWS.save(deps.storage, escrow_id, &escrow)?;
+    
+    // Create wasm-toolpay.closed event
+    let event = Event::new("wasm-toolpay.closed")
+        .add_attribute("escrow_id", escrow_id.to_string())
+        .add_attribute("provider", escrow.provider.to_string())
+        .add_attribute("caller", escrow.caller.to_string())
+        .add_attribute("total_charged", escrow.charged.to_string())
+        .add_attribute("platform_fee", escrow.platform_fee.to_string())
+        .add_attribute("refund_amount", refund_amount.to_string())
+        .add_attribute("denom", escrow.denom);
+    
+    // Return success response
+    Ok(Response::new()
+        .add_messages(refund_msg)
+        .add_event(event)
+        .add_attribute("action", "close")
+        .add_attribute("escrow_id", escrow_id.to_string()))
+}
+
+// Implementation of RefundExpired functionality
+pub fn refund_expired(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    escrow_id: u64,
+) -> Result<Response, ContractError> {
+    // Load escrow by id
+    let mut escrow = ESCROWS.may_load(deps.storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+    
+    // Verify caller is the original caller
+    if info.sender != escrow.caller {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Verify escrow hasn't been settled yet
+    if escrow.status != EscrowStatus::Locked {
+        return Err(ContractError::EscrowNotLocked {});
+    }
+    
+    // Verify escrow has expired
+    if !escrow.expires.is_expired(&env.block) {
+        return Err(ContractError::EscrowNotExpired {});
+    }
+    
+    let (refund_msg, _, event) = refund_expired_escrow(deps.storage, &env, escrow_id, &mut escrow, 0)?;
+    
+    // Return success response
+    Ok(Response::new()
+        .add_messages(refund_msg)
+        .add_event(event)
+        .add_attribute("action", "refund_expired")
+        .add_attribute("escrow_id", escrow_id.to_string()))
+}
+
+// Returns the uncharged funds of an expired escrow to its caller, less a keeper
+// reward of `keeper_reward_bps` left for the caller to pay out, and keeps the
+// settled escrow as a receipt.
+//
+// Returns the refund message, the keeper reward and the refund event.
+fn refund_expired_escrow(
+    storage: &mut dyn Storage,
+    env: &Env,
+    escrow_id: u64,
+    escrow: &mut Escrow,
+    keeper_reward_bps: u64,
+) -> StdResult<(Option<CosmosMsg>, Uint128, Event)> {
+    let uncharged = escrow.max_fee - escrow.charged;
+    let keeper_reward = uncharged.multiply_ratio(keeper_reward_bps, MAX_FEE_BPS);
+    let refund_amount = uncharged - keeper_reward;
+    let refund_msg = refund_caller(storage, escrow, refund_amount)?;
+    
+    escrow.status = EscrowStatus::Refunded;
+    escrow.refunded = refund_amount;
+    escrow.settled_at = Some(env.block.height);
+    ESCROWS.save(storage, escrow_id, escrow)?;
+    
+    let event = Event::new("wasm-toolpay.refunded")
+        .add_attribute("escrow_id", escrow_id.to_string())
+        .add_attribute("caller", escrow.caller.to_string())
+        .add_attribute("refund_amount", refund_amount.to_string())
+        .add_attribute("keeper_reward", keeper_reward.to_string())
+        .add_attribute("denom", escrow.denom.clone());
+    Ok((refund_msg, keeper_reward, event))
+}
+
+// Implementation of RefundExpiredBatch functionality
+pub fn refund_expired_batch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    escrow_ids: Vec<u64>,
+) -> Result<Response, ContractError> {
+    if escrow_ids.len() > MAX_BATCH_SIZE as usize {
+        return Err(ContractError::BatchTooLarge { max: MAX_BATCH_SIZE });
+    }
+    
+    refund_expired_escrows(deps, env, info, escrow_ids, "refund_expired_batch")
+}
+
+// Implementation of SweepExpired functionality
+pub fn sweep_expired(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_SWEEP_LIMIT).min(MAX_BATCH_SIZE) as usize;
+    
+    // Locked escrows past their height come first, then those past their time.
+    // Entries are ordered by expiration, so the scan stops at the first live one.
+    let mut escrow_ids = ESCROWS.idx.expires
+        .sub_prefix((1, 0))
+        .keys(deps.storage, None, Some(Bound::exclusive((env.block.height, 0))), Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    let remaining = limit - escrow_ids.len();
+    escrow_ids.extend(
+        ESCROWS.idx.expires
+            .sub_prefix((1, 1))
+            .keys(deps.storage, None, Some(Bound::exclusive((env.block.time.nanos(), 0))), Order::Ascending)
+            .take(remaining)
+            .collect::<StdResult<Vec<_>>>()?,
+    );
+    
+    refund_expired_escrows(deps, env, info, escrow_ids, "sweep_expired")
+}
+
+// Refunds expired escrows and pays the keeper reward for every escrow the sender
+// does not own, aggregated per denom. The reward comes out of the escrow's own
+// refund, so sweeping never costs anyone but the caller who let it expire.
+// Any escrow that is not locked and expired fails the whole call.
+fn refund_expired_escrows(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    escrow_ids: Vec<u64>,
+    action: &str,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut messages = vec![];
+    let mut events = vec![];
+    let mut rewards: Vec<(String, Uint128)> = vec![];
+    
+    for escrow_id in escrow_ids {
+        // Loaded right before the refund, so an id listed twice is no longer locked
+        let mut escrow = ESCROWS.may_load(deps.storage, escrow_id)?
+            .ok_or(ContractError::EscrowNotFound {})?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(ContractError::EscrowNotLocked {});
+        }
+        if !escrow.expires.is_expired(&env.block) {
+            return Err(ContractError::EscrowNotExpired {});
+        }
+        
+        let keeper_reward_bps = if escrow.caller != info.sender { config.keeper_reward_bps } else { 0 };
+        let (refund_msg, reward, event) =
+            refund_expired_escrow(deps.storage, &env, escrow_id, &mut escrow, keeper_reward_bps)?;
+        messages.extend(refund_msg);
+        events.push(event);
+        
+        if !reward.is_zero() {
+            match rewards.iter_mut().find(|(d, _)| *d == escrow.denom) {
+                Some((_, total)) => *total += reward,
+                None => rewards.push((escrow.denom.clone(), reward)),
+            }
+        }
+    }
+    
+    for (denom, amount) in &rewards {
+        messages.extend(send_msg(deps.storage, &info.sender, denom, *amount)?);
+    }
+    
+    let rewards = rewards.iter().map(|(denom, amount)| format!("{amount}{denom}")).collect::<Vec<_>>();
+    let summary = Event::new("wasm-toolpay.expired_swept")
+        .add_attribute("keeper", info.sender.to_string())
+        .add_attribute("count", events.len().to_string())
+        .add_attribute("rewards", rewards.join(","));
+    
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_events(events)
+        .add_event(summary)
+        .add_attribute("action", action))
+}
+
+// Implementation of EmergencyWithdraw functionality
+pub fn emergency_withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    escrow_id: u64,
+) -> Result<Response, ContractError> {
+    // Load escrow by id
+    let mut escrow = ESCROWS.may_load(deps.storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+    
+    // Verify caller is the original caller
+    if info.sender != escrow.caller {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Verify escrow hasn't been settled yet
+    if escrow.status != EscrowStatus::Locked {
+        return Err(ContractError::EscrowNotLocked {});
+    }
+    
+    // While releases work the provider may still be serving the call, so callers
+    // must wait for expiry as usual
+    if !CONFIG.load(deps.storage)?.paused.releases {
+        return Err(ContractError::EmergencyWithdrawUnavailable {});
+    }
+    
+    let (refund_msg, refund_amount) = refund_cancelled(deps.storage, &env, escrow_id, &mut escrow)?;
+    
+    let event = Event::new("wasm-toolpay.emergency_withdrawn")
+        .add_attribute("escrow_id", escrow_id.to_string())
+        .add_attribute("caller", escrow.caller.to_string())
+        .add_attribute("refund_amount", refund_amount.to_string())
+        .add_attribute("denom", escrow.denom);
+    
+    Ok(Response::new()
+        .add_messages(refund_msg)
+        .add_event(event)
+        .add_attribute("action", "emergency_withdraw")
+        .add_attribute("escrow_id", escrow_id.to_string()))
+}
+
+// Returns all uncharged funds of a called off escrow to its caller and keeps the
+// cancelled escrow as a receipt
+fn refund_cancelled(
+    storage: &mut dyn Storage,
+    env: &Env,
+    escrow_id: u64,
+    escrow: &mut Escrow,
+) -> StdResult<(Option<CosmosMsg>, Uint128)> {
+    let refund_amount = escrow.max_fee - escrow.charged;
+    let refund_msg = refund_caller(storage, escrow, refund_amount)?;
+    
+    escrow.status = EscrowStatus::Cancelled;
+    escrow.refunded = refund_amount;
+    escrow.settled_at = Some(env.block.height);
+    ESCROWS.save(storage, escrow_id, escrow)?;
+    
+    Ok((refund_msg, refund_amount))
+}
+
+// Implementation of DeclineEscrow functionality
+pub fn decline_escrow(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    escrow_id: u64,
+) -> Result<Response, ContractError> {
+    // Load escrow by id
+    let mut escrow = ESCROWS.may_load(deps.storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+    
+    // Verify caller is the original provider
+    if info.sender != escrow.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Verify escrow hasn't been settled yet
+    if escrow.status != EscrowStatus::Locked {
+        return Err(ContractError::EscrowNotLocked {});
+    }
+    
+    // Partly charged escrows are settled with Close
+    if !escrow.charged.is_zero() {
+        return Err(ContractError::EscrowCharged {});
+    }
+    
+    let (refund_msg, refund_amount) = refund_cancelled(deps.storage, &env, escrow_id, &mut escrow)?;
+    
+    let event = Event::new("wasm-toolpay.declined")
+        .add_attribute("escrow_id", escrow_id.to_string())
+        .add_attribute("provider", escrow.provider.to_string())
+        .add_attribute("caller", escrow.caller.to_string())
+        .add_attribute("refund_amount", refund_amount.to_string())
+        .add_attribute("denom", escrow.denom);
+    
+    Ok(Response::new()
+        .add_messages(refund_msg)
+        .add_event(event)
+        .add_attribute("action", "decline_escrow")
+        .add_attribute("escrow_id", escrow_id.to_string()))
+}
+
+// Implementation of AcknowledgeEscrow functionality
+pub fn acknowledge_escrow(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    escrow_id: u64,
+) -> Result<Response, ContractError> {
+    // Load escrow by id
+    let mut escrow = ESCROWS.may_load(deps.storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+    
+    // Verify caller is the original provider
+    if info.sender != escrow.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Verify escrow hasn't been settled yet
+    if escrow.status != EscrowStatus::Locked {
+        return Err(ContractError::EscrowNotLocked {});
+    }
+    
+    // Verify escrow hasn't expired
+    if escrow.expires.is_expired(&env.block) {
+        return Err(ContractError::EscrowExpired {});
+    }
+    
+    if escrow.acknowledged {
+        return Err(ContractError::EscrowAcknowledged {});
+    }
+    
+    escrow.acknowledged = true;
+    ESCROWS.save(deps.storage, escrow_id, &escrow)?;
+    
+    let event = Event::new("wasm-toolpay.acknowledged")
+        .add_attribute("escrow_id", escrow_id.to_string())
+        .add_attribute("provider", escrow.provider.to_string());
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("action", "acknowledge_escrow")
+        .add_attribute("escrow_id", escrow_id.to_string()))
+}
+
+// Implementation of CancelEscrow functionality
+pub fn cancel_escrow(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    escrow_id: u64,
+) -> Result<Response, ContractError> {
+    // Load escrow by id
+    let mut escrow = ESCROWS.may_load(deps.storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+    
+    // Verify caller is the original caller
+    if info.sender != escrow.caller {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Verify escrow hasn't been settled yet
+    if escrow.status != EscrowStatus::Locked {
+        return Err(ContractError::EscrowNotLocked {});
+    }
+    
+    // Only tools that opted in can be cancelled, and only until the provider starts serving
+    if !escrow.cancellable {
+        return Err(ContractError::CancelNotAllowed {});
+    }
+    if escrow.acknowledged {
+        return Err(ContractError::EscrowAcknowledged {});
+    }
+    
+    let (refund_msg, refund_amount) = refund_cancelled(deps.storage, &env, escrow_id, &mut escrow)?;
+    
+    let event = Event::new("wasm-toolpay.cancelled")
+        .add_attribute("escrow_id", escrow_id.to_string())
+        .add_attribute("caller", escrow.caller.to_string())
+        .add_attribute("refund_amount", refund_amount.to_string())
+        .add_attribute("denom", escrow.denom);
+    
+    Ok(Response::new()
+        .add_messages(refund_msg)
+        .add_event(event)
+        .add_attribute("action", "cancel_escrow")
+        .add_attribute("escrow_id", escrow_id.to_string()))
+}
+
+// Implementation of Deposit functionality
+pub fn deposit(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    if info.funds.is_empty() {
+        return Err(ContractError::NoFunds {});
+    }
+    
+    let mut event = Event::new("wasm-toolpay.deposited")
+        .add_attribute("caller", info.sender.to_string());
+    
+    // Credit every attached coin to the sender's balance in that denom
+    for coin in &info.funds {
+        let balance = DEPOSITS.update(deps.storage, (&info.sender, &coin.denom), |balance| -> StdResult<_> {
+            Ok(balance.unwrap_or_default() + coin.amount)
+        })?;
+        credit_liability(deps.storage, &coin.denom, coin.amount)?;
+        event = event
+            .add_attribute(format!("amount_{}", coin.denom), coin.amount.to_string())
+            .add_attribute(format!("balance_{}", coin.denom), balance.to_string());
+    }
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("action", "deposit"))
+}
+
+// Implementation of Withdraw functionality
+pub fn withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let key = (&info.sender, denom.as_str());
+    let balance = DEPOSITS.may_load(deps.storage, key)?.unwrap_or_default();
+    if amount.is_zero() || amount > balance {
+        return Err(ContractError::InsufficientDeposit {
+            required: amount.to_string(),
+            available: balance.to_string(),
+        });
+    }
+    DEPOSITS.save(deps.storage, key, &(balance - amount))?;
+    
+    // Adding to a pending withdrawal restarts its delay
+    let config = CONFIG.load(deps.storage)?;
+    let pending = WITHDRAWALS.may_load(deps.storage, key)?;
+    let withdrawal = PendingWithdrawal {
+        amount: pending.map(|p| p.amount).unwrap_or_default() + amount,
+        available_at: env.block.height + config.withdrawal_delay,
+    };
+    WITHDRAWALS.save(deps.storage, key, &withdrawal)?;
+    
+    let event = Event::new("wasm-toolpay.withdrawal_requested")
+        .add_attribute("caller", info.sender.to_string())
+        .add_attribute("denom", denom)
+        .add_attribute("amount", withdrawal.amount.to_string())
+        .add_attribute("available_at", withdrawal.available_at.to_string());
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("action", "withdraw"))
+}
+
+// Implementation of ClaimWithdrawal functionality
+pub fn claim_withdrawal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response, ContractError> {
+    let key = (&info.sender, denom.as_str());
+    let withdrawal = WITHDRAWALS.may_load(deps.storage, key)?
+        .ok_or_else(|| ContractError::NoPendingWithdrawal { denom: denom.clone() })?;
+    
+    if env.block.height < withdrawal.available_at {
+        return Err(ContractError::WithdrawalNotReady {
+            available_at: withdrawal.available_at,
+        });
+    }
+    WITHDRAWALS.remove(deps.storage, key);
+    
+    let event = Event::new("wasm-toolpay.withdrawn")
+        .add_attribute("caller", info.sender.to_string())
+        .add_attribute("denom", denom.clone())
+        .add_attribute("amount", withdrawal.amount.to_string());
+    
+    Ok(Response::new()
+        .add_messages(send_msg(deps.storage, &info.sender, &denom, withdrawal.amount)?)
+        .add_event(event)
+        .add_attribute("action", "claim_withdrawal"))
+}
+
+// Digest a caller signs to authorize a cumulative channel payment
+pub fn voucher_digest(contract_addr: &Addr, channel_id: u64, amount: Uint128) -> Vec<u8> {
+    Sha256::new()
+        .chain_update(VOUCHER_PREFIX)
+        .chain_update(contract_addr.as_bytes())
+        .chain_update(channel_id.to_be_bytes())
+        .chain_update(amount.u128().to_be_bytes())
+        .finalize()
+        .to_vec()
+}
+
+// Implementation of OpenChannel functionality
+pub fn open_channel(
+    deps: DepsMut,
+    info: MessageInfo,
+    tool_id: String,
+    pubkey: Binary,
+    key_type: KeyType,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Query registry contract to verify tool exists and is active
+    let tool = query_tool(&deps.querier, config.registry_addr, tool_id.clone())
+        .map_err(|_| ContractError::ToolNotActive {})?;
+    if !tool.is_active {
+        return Err(ContractError::ToolNotActive {});
+    }
+    
+    // The channel is funded with the attached funds in the tool's denom
+    let deposit = info
+        .funds
+        .iter()
+        .find(|c| c.denom == tool.denom)
+        .map(|c| c.amount)
+        .unwrap_or(Uint128::zero());
+    if deposit.is_zero() {
+        return Err(ContractError::NoDenomFunds { denom: tool.denom });
+    }
+    
+    let channel_id = NEXT_CHANNEL_ID.may_load(deps.storage)?.unwrap_or(1);
+    CHANNELS.save(deps.storage, channel_id, &Channel {
+        caller: info.sender.clone(),
+        provider: tool.provider.clone(),
+        tool_id: tool_id.clone(),
+        denom: tool.denom.clone(),
+        deposit,
+        settled: Uint128::zero(),
+        platform_fee: Uint128::zero(),
+        pubkey,
+        key_type,
+        closes_at: None,
+        closed: false,
+    })?;
+    NEXT_CHANNEL_ID.save(deps.storage, &(channel_id + 1))?;
+    credit_liability(deps.storage, &tool.denom, deposit)?;
+    
+    let event = Event::new("wasm-toolpay.channel_opened")
+        .add_attribute("channel_id", channel_id.to_string())
+        .add_attribute("tool_id", tool_id)
+        .add_attribute("caller", info.sender.to_string())
+        .add_attribute("provider", tool.provider.to_string())
+        .add_attribute("deposit", deposit.to_string())
+        .add_attribute("denom", tool.denom.clone());
+    
+    Ok(Response::new()
+        .add_messages(refund_surplus(&info.sender, &info.funds, &tool.denom, deposit)?)
+        .add_event(event)
+        .add_attribute("action", "open_channel")
+        .add_attribute("channel_id", channel_id.to_string())
+        .set_data(to_json_binary(&OpenChannelResponse { channel_id })?))
+}
+
+// Implementation of SettleChannel functionality
+pub fn settle_channel(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: u64,
+    amount: Uint128,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    let mut channel = CHANNELS.may_load(deps.storage, channel_id)?
+        .ok_or(ContractError::ChannelNotFound {})?;
+    
+    if info.sender != channel.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+    if channel.closed {
+        return Err(ContractError::ChannelClosed {});
+    }
+    if amount <= channel.settled {
+        return Err(ContractError::VoucherNotIncreasing {
+            amount: amount.to_string(),
+            settled: channel.settled.to_string(),
+        });
+    }
+    if amount > channel.deposit {
+        return Err(ContractError::VoucherExceedsDeposit {
+            amount: amount.to_string(),
+            deposit: channel.deposit.to_string(),
+        });
+    }
+    
+    // Verify the caller signed this cumulative amount for this channel
+    let digest = voucher_digest(&env.contract.address, channel_id, amount);
+    let verified = match channel.key_type {
+        KeyType::Secp256k1 => deps.api.secp256k1_verify(&digest, &signature, &channel.pubkey),
+        KeyType::Ed25519 => deps.api.ed25519_verify(&digest, &signature, &channel.pubkey),
+    };
+    if !verified.unwrap_or(false) {
+        return Err(ContractError::InvalidSignature {});
+    }
+    
+    // Fees are computed on the cumulative amount, as for escrow charges
+    let mut config = CONFIG.load(deps.storage)?;
+    let payment = amount - channel.settled;
+    let (fee_bps, _) = fee_bps_for(deps.storage, &config, &channel.provider, &channel.tool_id)?;
+    let platform_fee = platform_fee_for(fee_bps, amount)
+        .saturating_sub(channel.platform_fee)
+        .min(payment);
+    let provider_fee = payment - platform_fee;
+    add_collected_fee(&mut config, &channel.denom, platform_fee);
+    CONFIG.save(deps.storage, &config)?;
+    
+    channel.settled = amount;
+    channel.platform_fee += platform_fee;
+    CHANNELS.save(deps.storage, channel_id, &channel)?;
+    
+    let event = Event::new("wasm-toolpay.channel_settled")
+        .add_attribute("channel_id", channel_id.to_string())
+        .add_attribute("provider", channel.provider.to_string())
+        .add_attribute("amount", payment.to_string())
+        .add_attribute("provider_fee", provider_fee.to_string())
+        .add_attribute("platform_fee", platform_fee.to_string())
+        .add_attribute("total_settled", amount.to_string())
+        .add_attribute("denom", channel.denom.clone());
+    
+    Ok(Response::new()
+        .add_messages(send_msg(deps.storage, &channel.provider, &channel.denom, provider_fee)?)
+        .add_event(event)
+        .add_attribute("action", "settle_channel")
+        .add_attribute("channel_id", channel_id.to_string()))
+}
+
+// Implementation of RequestCloseChannel functionality
+pub fn request_close_channel(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: u64,
+) -> Result<Response, ContractError> {
+    let mut channel = CHANNELS.may_load(deps.storage, channel_id)?
+        .ok_or(ContractError::ChannelNotFound {})?;
+    
+    if info.sender != channel.caller {
+        return Err(ContractError::Unauthorized {});
+    }
+    if channel.closed {
+        return Err(ContractError::ChannelClosed {});
+    }
+    
+    // Repeated requests do not extend the challenge period
+    let closes_at = *channel.closes_at.get_or_insert(env.block.height + CHANNEL_CHALLENGE_BLOCKS);
+    CHANNELS.save(deps.storage, channel_id, &channel)?;
+    
+    let event = Event::new("wasm-toolpay.channel_close_requested")
+        .add_attribute("channel_id", channel_id.to_string())
+        .add_attribute("caller", channel.caller.to_string())
+        .add_attribute("closes_at", closes_at.to_string());
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("action", "request_close_channel")
+        .add_attribute("channel_id", channel_id.to_string()))
+}
+
+// Implementation of CloseChannel functionality
+pub fn close_channel(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: u64,
+) -> Result<Response, ContractError> {
+    let mut channel = CHANNELS.may_load(deps.storage, channel_id)?
+        .ok_or(ContractError::ChannelNotFound {})?;
+    
+    if channel.closed {
+        return Err(ContractError::ChannelClosed {});
+    }
+    
+    // The caller has to give the provider the challenge period to settle
+    if info.sender == channel.caller {
+        let closes_at = channel.closes_at.ok_or(ContractError::CloseNotRequested {})?;
+        if env.block.height < closes_at {
+            return Err(ContractError::ChallengePeriodActive { closes_at });
+        }
+    } else if info.sender != channel.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    let refund_amount = channel.deposit - channel.settled;
+    channel.closed = true;
+    CHANNELS.save(deps.storage, channel_id, &channel)?;
+    
+    let event = Event::new("wasm-toolpay.channel_closed")
+        .add_attribute("channel_id", channel_id.to_string())
+        .add_attribute("closed_by", info.sender.to_string())
+        .add_attribute("total_settled", channel.settled.to_string())
+        .add_attribute("refund_amount", refund_amount.to_string())
+        .add_attribute("denom", channel.denom.clone());
+    
+    Ok(Response::new()
+        .add_messages(send_msg(deps.storage, &channel.caller, &channel.denom, refund_amount)?)
+        .add_event(event)
+        .add_attribute("action", "close_channel")
+        .add_attribute("channel_id", channel_id.to_string()))
+}
+
+// Pays the provider `amount` for periods of a subscription, keeping the platform fee
+fn pay_subscription_periods(
+    storage: &mut dyn Storage,
+    subscription: &Subscription,
+    tool_id: &str,
+    amount: Uint128,
+) -> StdResult<(Uint128, Uint128, Option<CosmosMsg>)> {
+    let mut config = CONFIG.load(storage)?;
+    let (fee_bps, _) = fee_bps_for(storage, &config, &subscription.provider, tool_id)?;
+    let platform_fee = platform_fee_for(fee_bps, amount);
+    add_collected_fee(&mut config, &subscription.denom, platform_fee);
+    CONFIG.save(storage, &config)?;
+    
+    let provider_fee = amount - platform_fee;
+    Ok((provider_fee, platform_fee, send_msg(storage, &subscription.provider, &subscription.denom, provider_fee)?))
+}
+
+// Implementation of Subscribe functionality
+pub fn subscribe(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tool_id: String,
+    periods: u64,
+) -> Result<Response, ContractError> {
+    if periods == 0 {
+        return Err(ContractError::ZeroPeriods {});
+    }
+    
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Query registry contract to verify tool exists, is active and offers subscriptions
+    let tool = query_tool(&deps.querier, config.registry_addr, tool_id.clone())
+        .map_err(|_| ContractError::ToolNotActive {})?;
+    if !tool.is_active {
+        return Err(ContractError::ToolNotActive {});
+    }
+    let terms = tool.subscription.ok_or(ContractError::SubscriptionsNotOffered {})?;
+    
+    let key = (&info.sender, tool_id.as_str());
+    let mut messages = vec![];
+    let mut subscription = match SUBSCRIPTIONS.may_load(deps.storage, key)? {
+        // An active subscription is extended at the current price, as long as its
+        // periods still run in the same denom and length and pay the same provider
+        Some(subscription) if env.block.height < subscription.paid_until()? => {
+            if subscription.denom != tool.denom
+                || subscription.period != terms.period
+                || subscription.provider != tool.provider
+            {
+                return Err(ContractError::SubscriptionTermsChanged {});
+            }
+            subscription
+        }
+        existing => {
+            // Periods of an ended subscription the provider has not claimed yet are paid out first
+            if let Some(ended) = existing {
+                let unclaimed = ended.cost(ended.claimed, ended.periods - ended.claimed)?;
+                let (_, _, msg) = pay_subscription_periods(deps.storage, &ended, &tool_id, unclaimed)?;
+                messages.extend(msg);
+            }
+            Subscription {
+                provider: tool.provider.clone(),
+                denom: tool.denom.clone(),
+                prices: vec![],
+                period: terms.period,
+                start: env.block.height,
+                periods: 0,
+                claimed: 0,
+            }
+        }
+    };
+    subscription.extend(periods, terms.price)?;
+    let paid_until = subscription.paid_until()?;
+    
+    // Validate that the attached funds cover the prepaid periods
+    let required = terms.price.checked_mul(Uint128::from(periods))?;
+    let attached_funds = info
+        .funds
+        .iter()
+        .find(|c| c.denom == subscription.denom)
+        .map(|c| c.amount)
+        .unwrap_or(Uint128::zero());
+    if attached_funds < required {
+        return Err(ContractError::InsufficientFunds {
+            required: required.to_string(),
+            available: attached_funds.to_string(),
+        });
+    }
+    
+    SUBSCRIPTIONS.save(deps.storage, key, &subscription)?;
+    credit_liability(deps.storage, &subscription.denom, required)?;
+    messages.extend(refund_surplus(&info.sender, &info.funds, &subscription.denom, required)?);
+    
+    let event = Event::new("wasm-toolpay.subscribed")
+        .add_attribute("caller", info.sender.to_string())
+        .add_attribute("tool_id", tool_id.clone())
+        .add_attribute("provider", subscription.provider.to_string())
+        .add_attribute("periods", periods.to_string())
+        .add_attribute("amount", required.to_string())
+        .add_attribute("denom", subscription.denom.clone())
+        .add_attribute("paid_until", paid_until.to_string());
     
-    // Return success response
     Ok(Response::new()
         .add_messages(messages)
         .add_event(event)
-        .add_attribute("action", "release")
-        .add_attribute("escrow_id", escrow_id.to_string()))
+        .add_attribute("action", "subscribe")
+        .add_attribute("tool_id", tool_id))
 }
 
-// Implementation of RefundExpired functionality
-pub fn refund_expired(
+// Implementation of ClaimSubscription functionality
+pub fn claim_subscription(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    escrow_id: u64,
+    caller: String,
+    tool_id: String,
 ) -> Result<Response, ContractError> {
-    // Load escrow by id
-    let escrow = ESCROWS.may_load(deps.storage, escrow_id)?
-        .ok_or(ContractError::EscrowNotFound {})?;
+    let caller = deps.api.addr_validate(&caller)?;
+    let key = (&caller, tool_id.as_str());
+    let mut subscription = SUBSCRIPTIONS.may_load(deps.storage, key)?
+        .ok_or(ContractError::SubscriptionNotFound {})?;
     
-    // Verify caller is the original caller
-    if info.sender != escrow.caller {
+    if info.sender != subscription.provider {
         return Err(ContractError::Unauthorized {});
     }
     
-    // Verify escrow has expired (current block > expires)
-    if env.block.height <= escrow.expires {
-        return Err(ContractError::EscrowNotExpired {});
+    let claimable = subscription.elapsed_periods(env.block.height) - subscription.claimed;
+    if claimable == 0 {
+        return Err(ContractError::NothingToClaim {
+            next_claim_at: subscription.start + (subscription.claimed + 1) * subscription.period,
+        });
     }
     
-    // Prepare message to return all funds to original caller
-    let refund_msg = CosmosMsg::Bank(BankMsg::Send {
-        to_address: escrow.caller.to_string(),
-        amount: vec![Coin {
-            denom: escrow.denom.clone(),
-            amount: escrow.max_fee,
-        }],
-    });
+    let amount = subscription.cost(subscription.claimed, claimable)?;
+    let (provider_fee, platform_fee, msg) = pay_subscription_periods(deps.storage, &subscription, &tool_id, amount)?;
+    subscription.claimed += claimable;
     
-    // Remove escrow from storage
-    ESCROWS.remove(deps.storage, escrow_id);
+    // A fully claimed subscription has nothing left to track
+    if subscription.claimed == subscription.periods {
+        SUBSCRIPTIONS.remove(deps.storage, key);
+    } else {
+        SUBSCRIPTIONS.save(deps.storage, key, &subscription)?;
+    }
     
-    // Create wasm-toolpay.refunded event
-    let event = Event::new("wasm-toolpay.refunded")
-        .add_attribute("escrow_id", escrow_id.to_string())
-        .add_attribute("caller", escrow.caller.to_string())
-        .add_attribute("refund_amount", escrow.max_fee.to_string())
-        .add_attribute("denom", escrow.denom);
+    let event = Event::new("wasm-toolpay.subscription_claimed")
+        .add_attribute("caller", caller.to_string())
+        .add_attribute("tool_id", tool_id.clone())
+        .add_attribute("provider", subscription.provider.to_string())
+        .add_attribute("periods", claimable.to_string())
+        .add_attribute("provider_fee", provider_fee.to_string())
+        .add_attribute("platform_fee", platform_fee.to_string())
+        .add_attribute("denom", subscription.denom.clone());
     
-    // Return success response
     Ok(Response::new()
-        .add_message(refund_msg)
+        .add_messages(msg)
         .add_event(event)
-        .add_attribute("action", "refund_expired")
-        .add_attribute("escrow_id", escrow_id.to_string()))
+        .add_attribute("action", "claim_subscription")
+        .add_attribute("tool_id", tool_id))
+}
+
+// Implementation of CancelSubscription functionality
+pub fn cancel_subscription(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tool_id: String,
+) -> Result<Response, ContractError> {
+    let key = (&info.sender, tool_id.as_str());
+    let mut subscription = SUBSCRIPTIONS.may_load(deps.storage, key)?
+        .ok_or(ContractError::SubscriptionNotFound {})?;
+    
+    if env.block.height >= subscription.paid_until()? {
+        return Err(ContractError::SubscriptionEnded {});
+    }
+    
+    // The current period stays paid for, only periods that have not started are refunded
+    let started = subscription.elapsed_periods(env.block.height) + 1;
+    let unused = subscription.periods - started;
+    let refund_amount = subscription.cost(started, unused)?;
+    subscription.truncate(started);
+    SUBSCRIPTIONS.save(deps.storage, key, &subscription)?;
+    
+    let event = Event::new("wasm-toolpay.subscription_cancelled")
+        .add_attribute("caller", info.sender.to_string())
+        .add_attribute("tool_id", tool_id.clone())
+        .add_attribute("refunded_periods", unused.to_string())
+        .add_attribute("refund_amount", refund_amount.to_string())
+        .add_attribute("denom", subscription.denom.clone())
+        .add_attribute("paid_until", subscription.paid_until()?.to_string());
+    
+    Ok(Response::new()
+        .add_messages(send_msg(deps.storage, &info.sender, &subscription.denom, refund_amount)?)
+        .add_event(event)
+        .add_attribute("action", "cancel_subscription")
+        .add_attribute("tool_id", tool_id))
+}
+
+// Implementation of UpdateConfig functionality
+pub fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    registry_addr: Option<String>,
+    fee_bps: Option<u64>,
+    withdrawal_delay: Option<u64>,
+    expiry_bounds: Option<ExpiryBounds>,
+    keeper_reward_bps: Option<u64>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    
+    // Verify caller is the owner
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Only the parameters that were provided are changed and reported
+    let mut event = Event::new("wasm-toolpay.config_updated")
+        .add_attribute("updated_by", info.sender.to_string());
+    
+    if let Some(registry_addr) = registry_addr {
+        config.registry_addr = deps.api.addr_validate(&registry_addr)?;
+        event = event.add_attribute("registry_addr", registry_addr);
+    }
+    
+    if let Some(fee_bps) = fee_bps {
+        if fee_bps > MAX_FEE_BPS {
+            return Err(ContractError::InvalidFeeBps(fee_bps));
+        }
+        config.fee_bps = fee_bps;
+        event = event.add_attribute("fee_bps", fee_bps.to_string());
+    }
+    
+    if let Some(withdrawal_delay) = withdrawal_delay {
+        config.withdrawal_delay = withdrawal_delay;
+        event = event.add_attribute("withdrawal_delay", withdrawal_delay.to_string());
+    }
+    
+    if let Some(expiry_bounds) = expiry_bounds {
+        validate_expiry_bounds(&expiry_bounds)?;
+        event = event
+            .add_attribute("ttl_blocks", format!("{}-{}", expiry_bounds.min_blocks, expiry_bounds.max_blocks))
+            .add_attribute("ttl_seconds", format!("{}-{}", expiry_bounds.min_seconds, expiry_bounds.max_seconds));
+        config.expiry_bounds = expiry_bounds;
+    }
+    
+    if let Some(keeper_reward_bps) = keeper_reward_bps {
+        if keeper_reward_bps > MAX_KEEPER_REWARD_BPS {
+            return Err(ContractError::InvalidKeeperRewardBps(keeper_reward_bps));
+        }
+        config.keeper_reward_bps = keeper_reward_bps;
+        event = event.add_attribute("keeper_reward_bps", keeper_reward_bps.to_string());
+    }
+    
+    CONFIG.save(deps.storage, &config)?;
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("action", "update_config"))
+}
+
+// Implementation of SetFeeOverride functionality
+pub fn set_fee_override(
+    deps: DepsMut,
+    info: MessageInfo,
+    target: FeeOverrideTarget,
+    fee_bps: Option<u64>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Verify caller is the owner
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    if let Some(fee_bps) = fee_bps {
+        if fee_bps > MAX_FEE_BPS {
+            return Err(ContractError::InvalidFeeBps(fee_bps));
+        }
+    }
+    
+    let mut event = Event::new("wasm-toolpay.config_updated")
+        .add_attribute("updated_by", info.sender.to_string());
+    
+    match target {
+        FeeOverrideTarget::Provider { provider } => {
+            let provider = deps.api.addr_validate(&provider)?;
+            match fee_bps {
+                Some(fee_bps) => PROVIDER_FEES.save(deps.storage, &provider, &fee_bps)?,
+                None => PROVIDER_FEES.remove(deps.storage, &provider),
+            }
+            event = event.add_attribute("fee_override_provider", provider.to_string());
+        }
+        FeeOverrideTarget::Tool { tool_id } => {
+            match fee_bps {
+                Some(fee_bps) => TOOL_FEES.save(deps.storage, &tool_id, &fee_bps)?,
+                None => TOOL_FEES.remove(deps.storage, &tool_id),
+            }
+            event = event.add_attribute("fee_override_tool", tool_id);
+        }
+    }
+    
+    let event = event.add_attribute(
+        "fee_bps",
+        fee_bps.map_or_else(|| "none".to_string(), |fee_bps| fee_bps.to_string()),
+    );
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("action", "set_fee_override"))
+}
+
+// Implementation of ProposeOwner functionality
+pub fn propose_owner(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_owner: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Verify caller is the owner
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Record the proposal, replacing any earlier one
+    let new_owner = deps.api.addr_validate(&new_owner)?;
+    PENDING_OWNER.save(deps.storage, &new_owner)?;
+    
+    let event = Event::new("wasm-toolpay.config_updated")
+        .add_attribute("updated_by", info.sender.to_string())
+        .add_attribute("pending_owner", new_owner.to_string());
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("action", "propose_owner"))
+}
+
+// Implementation of AcceptOwner functionality
+pub fn accept_owner(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let pending_owner = PENDING_OWNER.may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingOwner {})?;
+    
+    // Only the proposed owner can complete the handoff
+    if info.sender != pending_owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    let mut config = CONFIG.load(deps.storage)?;
+    let previous_owner = config.owner;
+    config.owner = pending_owner;
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_OWNER.remove(deps.storage);
+    
+    let event = Event::new("wasm-toolpay.config_updated")
+        .add_attribute("updated_by", info.sender.to_string())
+        .add_attribute("previous_owner", previous_owner.to_string())
+        .add_attribute("owner", config.owner.to_string());
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("action", "accept_owner"))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
     match msg {
-        SudoMsg::Freeze {} => {
-            let mut config = CONFIG.load(deps.storage)?;
-            config.frozen = true;
-            CONFIG.save(deps.storage, &config)?;
-            
-            Ok(Response::new()
-                .add_attribute("action", "freeze")
-                .add_attribute("frozen", "true"))
-        }
+        SudoMsg::Freeze {} => set_pause(deps, Some(true), Some(true), Some(true)),
+        SudoMsg::Unfreeze {} => set_pause(deps, Some(false), Some(false), Some(false)),
+        SudoMsg::SetPause { locks, releases, fee_claims } => set_pause(deps, locks, releases, fee_claims),
+    }
+}
+
+// Updates the pause flags that were provided, for both the owner and sudo
+fn set_pause(
+    deps: DepsMut,
+    locks: Option<bool>,
+    releases: Option<bool>,
+    fee_claims: Option<bool>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let paused = &mut config.paused;
+    paused.locks = locks.unwrap_or(paused.locks);
+    paused.releases = releases.unwrap_or(paused.releases);
+    paused.fee_claims = fee_claims.unwrap_or(paused.fee_claims);
+    CONFIG.save(deps.storage, &config)?;
+    
+    let event = Event::new("wasm-toolpay.pause_updated")
+        .add_attribute("locks", config.paused.locks.to_string())
+        .add_attribute("releases", config.paused.releases.to_string())
+        .add_attribute("fee_claims", config.paused.fee_claims.to_string());
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("action", "set_pause"))
+}
+
+// Implementation of RecoverUnaccounted functionality
+pub fn recover_unaccounted(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Verify caller is the owner
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    let recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(&recipient)?,
+        None => config.owner,
+    };
+    
+    // Only funds beyond what the contract owes can be recovered
+    let unaccounted = query_unaccounted_balance(deps.as_ref(), env, denom.clone())?.unaccounted;
+    if unaccounted.is_zero() {
+        return Err(ContractError::NothingToRecover { denom });
     }
+    
+    let event = Event::new("wasm-toolpay.unaccounted_recovered")
+        .add_attribute("recipient", recipient.to_string())
+        .add_attribute("amount", unaccounted.to_string())
+        .add_attribute("denom", denom.clone());
+    
+    Ok(Response::new()
+        .add_messages(transfer_msg(&recipient, &denom, unaccounted)?)
+        .add_event(event)
+        .add_attribute("action", "recover_unaccounted"))
 }
 
 // Implementation of ClaimFees functionality
@@ -503,13 +2314,7 @@ pub fn claim_fees(
             for (fee_denom, amount) in &config.collected_fees {
                 if *fee_denom == denom {
                     // Add to messages
-                    messages.push(CosmosMsg::Bank(BankMsg::Send {
-                        to_address: config.owner.to_string(),
-                        amount: vec![Coin {
-                            denom: fee_denom.clone(),
-                            amount: *amount,
-                        }],
-                    }));
+                    messages.extend(send_msg(deps.storage, &config.owner, fee_denom, *amount)?);
                     
                     // Record claimed fee
                     claimed_fees.push((fee_denom.clone(), *amount));
@@ -528,13 +2333,7 @@ pub fn claim_fees(
             // Claim all fees
             for (fee_denom, amount) in &config.collected_fees {
                 // Add to messages
-                messages.push(CosmosMsg::Bank(BankMsg::Send {
-                    to_address: config.owner.to_string(),
-                    amount: vec![Coin {
-                        denom: fee_denom.clone(),
-                        amount: *amount,
-                    }],
-                }));
+                messages.extend(send_msg(deps.storage, &config.owner, fee_denom, *amount)?);
                 
                 // Record claimed fee
                 claimed_fees.push((fee_denom.clone(), *amount));
@@ -566,6 +2365,39 @@ pub fn claim_fees(
         .add_attributes(event_attributes))
 }
 
+// Implementation of PruneReceipts functionality
+pub fn prune_receipts(
+    deps: DepsMut,
+    info: MessageInfo,
+    escrow_ids: Vec<u64>,
+) -> Result<Response, ContractError> {
+    // Verify caller is the owner
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    let mut pruned = 0u64;
+    for escrow_id in escrow_ids {
+        // Already pruned or unknown escrows are skipped
+        let Some(escrow) = ESCROWS.may_load(deps.storage, escrow_id)? else {
+            continue;
+        };
+        
+        // Locked escrows still hold funds and must never be deleted
+        if escrow.status == EscrowStatus::Locked {
+            return Err(ContractError::EscrowNotSettled {});
+        }
+        
+        ESCROWS.remove(deps.storage, escrow_id)?;
+        pruned += 1;
+    }
+    
+    Ok(Response::new()
+        .add_attribute("action", "prune_receipts")
+        .add_attribute("pruned", pruned.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     // Main tests are in the tests/ directory
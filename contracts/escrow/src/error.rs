@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{OverflowError, StdError};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,11 +6,17 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
-    #[error("Contract is frozen")]
-    Frozen {},
+    #[error("Contract is paused for {operation}")]
+    Paused { operation: String },
+    
+    #[error("Emergency withdrawals are only available while releases are paused")]
+    EmergencyWithdrawUnavailable {},
     
     #[error("Tool not found or inactive")]
     ToolNotActive {},
@@ -24,24 +30,144 @@ pub enum ContractError {
     #[error("No funds provided with the required denomination")]
     NoDenomFunds { denom: String },
     
-    #[error("Escrow expiration too far in future: max {max_blocks} blocks, got {got_blocks} blocks")]
-    ExpirationTooLong { max_blocks: u64, got_blocks: u64 },
+    #[error("Escrow expiration too far in future: max {max} {unit}, got {got} {unit}")]
+    ExpirationTooLong { max: u64, got: u64, unit: String },
+    
+    #[error("Escrow expiration too soon: min {min} {unit}, got {got} {unit}")]
+    ExpirationTooSoon { min: u64, got: u64, unit: String },
+    
+    #[error("Escrow expiration has already passed")]
+    ExpirationInPast {},
+    
+    #[error("Expiry bounds must not have a minimum above the maximum")]
+    InvalidExpiryBounds {},
+    
+    #[error("Invalid auth commitment: expected a 32 byte sha256 hash, got {len} bytes")]
+    InvalidCommitment { len: usize },
     
     #[error("Escrow not found")]
     EscrowNotFound {},
     
+    #[error("Escrow is not locked")]
+    EscrowNotLocked {},
+    
+    #[error("Escrow has not been settled")]
+    EscrowNotSettled {},
+    
     #[error("Escrow already expired")]
     EscrowExpired {},
     
     #[error("Escrow not yet expired")]
     EscrowNotExpired {},
     
+    #[error("Escrow has already been charged; close it instead")]
+    EscrowCharged {},
+    
+    #[error("Escrow has already been acknowledged by the provider")]
+    EscrowAcknowledged {},
+    
+    #[error("Escrows for this tool cannot be cancelled")]
+    CancelNotAllowed {},
+    
+    #[error("Too many escrows in one batch: max {max}")]
+    BatchTooLarge { max: u32 },
+    
     #[error("Usage fee exceeds max fee: max {max_fee}, requested {requested_fee}")]
     FeeTooHigh { max_fee: String, requested_fee: String },
     
-    #[error("Invalid fee percentage: must be between 0 and 100, got {0}")]
-    InvalidFeePercentage(u64),
+    #[error("Max fee {max_fee} is below the tool price {price}")]
+    MaxFeeBelowPrice { price: String, max_fee: String },
+    
+    #[error("Tool price changed: expected {expected}, current {current}")]
+    PriceChanged { expected: String, current: String },
+    
+    #[error("Total usage fee exceeds the tool price at lock time: price {price}, requested {requested_fee}")]
+    FeeExceedsPrice { price: String, requested_fee: String },
+    
+    #[error("Charge amount must be greater than zero")]
+    ZeroCharge {},
+    
+    #[error("No funds attached")]
+    NoFunds {},
+    
+    #[error("Funds must not be attached when locking from a deposit")]
+    UnexpectedFunds {},
+    
+    #[error("Insufficient deposit: required {required}, available {available}")]
+    InsufficientDeposit { required: String, available: String },
+    
+    #[error("No pending withdrawal for denom {denom}")]
+    NoPendingWithdrawal { denom: String },
+    
+    #[error("Withdrawal not yet available: claimable at block {available_at}")]
+    WithdrawalNotReady { available_at: u64 },
+    
+    #[error("Channel not found")]
+    ChannelNotFound {},
+    
+    #[error("Channel is closed")]
+    ChannelClosed {},
+    
+    #[error("Invalid voucher signature")]
+    InvalidSignature {},
+    
+    #[error("Voucher amount {amount} must exceed the settled amount {settled}")]
+    VoucherNotIncreasing { amount: String, settled: String },
+    
+    #[error("Voucher amount {amount} exceeds the channel deposit {deposit}")]
+    VoucherExceedsDeposit { amount: String, deposit: String },
+    
+    #[error("Channel close has not been requested")]
+    CloseNotRequested {},
+    
+    #[error("Challenge period active: channel can be closed at block {closes_at}")]
+    ChallengePeriodActive { closes_at: u64 },
+    
+    #[error("Tool does not offer subscriptions")]
+    SubscriptionsNotOffered {},
+    
+    #[error("Number of periods must be greater than zero")]
+    ZeroPeriods {},
+    
+    #[error("Subscription not found")]
+    SubscriptionNotFound {},
+    
+    #[error("Subscription has ended")]
+    SubscriptionEnded {},
+    
+    #[error("Subscription terms changed to another denom, period or provider; subscribe again once the current one ends")]
+    SubscriptionTermsChanged {},
+    
+    #[error("No elapsed periods to claim, next one can be claimed at block {next_claim_at}")]
+    NothingToClaim { next_claim_at: u64 },
+    
+    #[error("No ownership transfer is pending")]
+    NoPendingOwner {},
+    
+    #[error("No unaccounted funds to recover in {denom}")]
+    NothingToRecover { denom: String },
+    
+    #[error("Invalid fee: must be between 0 and 10000 basis points, got {0}")]
+    InvalidFeeBps(u64),
+    
+    #[error("Invalid keeper reward: must be between 0 and 100 basis points, got {0}")]
+    InvalidKeeperRewardBps(u64),
     
     #[error("No fees to claim for denom {0}")]
     NoFeesToClaim(String),
+    
+    #[error("Cannot migrate from contract {found}, expected {expected}")]
+    InvalidContractName { expected: String, found: String },
+    
+    #[error("Cannot migrate from version {from} to older version {to}")]
+    CannotDowngrade { from: String, to: String },
+    
+    #[error("Semver parsing error: {0}")]
+    SemVer(String),
+}
+
+impl From<semver::Error> for ContractError {
+    fn from(err: semver::Error) -> Self {
+        Self::SemVer(err.to_string())
+    }
 }
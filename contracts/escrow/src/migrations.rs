@@ -0,0 +1,113 @@
+//! State migrations between released versions of the Escrow contract.
+//!
+//! Each `migrate_from_*` function upgrades storage written by an older release to the
+//! current layout. Legacy layouts are kept here so they can be read back exactly as
+//! they were stored.
+
+use cosmwasm_std::{Binary, DepsMut, Order, StdResult, Storage, Uint128};
+use sha2::{Digest, Sha256};
+
+use crate::contract::{DEFAULT_EXPIRY_BOUNDS, DEFAULT_WITHDRAWAL_DELAY};
+use crate::error::ContractError;
+use crate::state::{Config, Escrow, EscrowStatus, Expiration, PauseFlags, CONFIG, ESCROWS, LIABILITIES};
+
+/// Storage layout of the 0.1.x releases
+pub mod v0_1 {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{Addr, Uint128};
+    use cw_storage_plus::{Item, Map};
+
+    /// Escrow as stored by 0.1.x
+    #[cw_serde]
+    pub struct Escrow {
+        pub caller: Addr,
+        pub provider: Addr,
+        pub max_fee: Uint128,
+        pub denom: String,
+        pub auth_token: String,
+        pub expires: u64,
+    }
+
+    /// Config as stored by 0.1.x
+    #[cw_serde]
+    pub struct Config {
+        pub frozen: bool,
+        pub registry_addr: Addr,
+        pub owner: Addr,
+        pub fee_percentage: u64,
+        pub collected_fees: Vec<(String, Uint128)>,
+    }
+
+    pub const ESCROWS: Map<u64, Escrow> = Map::new("escrows");
+    pub const CONFIG: Item<Config> = Item::new("config");
+}
+
+/// Upgrades 0.1.x state by rewriting the config and every open escrow in the
+/// current layout, and records what the contract owes in the liability ledger.
+///
+/// Returns the number of migrated escrows.
+pub fn migrate_from_v0_1(deps: DepsMut) -> Result<usize, ContractError> {
+    let legacy_config = v0_1::CONFIG.load(deps.storage)?;
+    CONFIG.save(deps.storage, &Config {
+        // A frozen 0.1.x contract stays fully paused
+        paused: PauseFlags {
+            locks: legacy_config.frozen,
+            releases: legacy_config.frozen,
+            fee_claims: legacy_config.frozen,
+        },
+        registry_addr: legacy_config.registry_addr,
+        owner: legacy_config.owner,
+        // 0.1.x stored whole percentages
+        fee_bps: legacy_config.fee_percentage * 100,
+        collected_fees: legacy_config.collected_fees.clone(),
+        withdrawal_delay: DEFAULT_WITHDRAWAL_DELAY,
+        expiry_bounds: DEFAULT_EXPIRY_BOUNDS,
+        keeper_reward_bps: 0,
+    })?;
+
+    let escrows = v0_1::ESCROWS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    // 0.1.x only owed collected fees and locked escrows
+    for (denom, amount) in &legacy_config.collected_fees {
+        add_liability(deps.storage, denom, *amount)?;
+    }
+
+    for (escrow_id, legacy) in &escrows {
+        add_liability(deps.storage, &legacy.denom, legacy.max_fee)?;
+        let escrow = Escrow {
+            caller: legacy.caller.clone(),
+            provider: legacy.provider.clone(),
+            // 0.1.x did not record which tool an escrow was for
+            tool_id: String::new(),
+            max_fee: legacy.max_fee,
+            // 0.1.x did not record the price, so keep the full max fee releasable
+            price: legacy.max_fee,
+            denom: legacy.denom.clone(),
+            // Plaintext tokens are replaced by their commitment
+            auth_commitment: Binary::from(Sha256::digest(legacy.auth_token.as_bytes()).to_vec()),
+            // 0.1.x expirations were always block heights
+            expires: Expiration::AtHeight(legacy.expires),
+            // 0.1.x removed settled escrows, so every stored escrow is still locked
+            status: EscrowStatus::Locked,
+            charged: Uint128::zero(),
+            platform_fee: Uint128::zero(),
+            refunded: Uint128::zero(),
+            settled_at: None,
+            from_deposit: false,
+            cancellable: false,
+            acknowledged: false,
+        };
+        // Drop the legacy entry first so the indexed map does not try to read it back
+        v0_1::ESCROWS.remove(deps.storage, *escrow_id);
+        ESCROWS.save(deps.storage, *escrow_id, &escrow)?;
+    }
+
+    Ok(escrows.len())
+}
+
+fn add_liability(storage: &mut dyn Storage, denom: &str, amount: Uint128) -> StdResult<()> {
+    LIABILITIES.update(storage, denom, |owed| -> StdResult<_> { Ok(owed.unwrap_or_default() + amount) })?;
+    Ok(())
+}
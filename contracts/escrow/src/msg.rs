@@ -1,45 +1,247 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Binary, Uint128};
+use cw20::Cw20ReceiveMsg;
+
+use crate::state::{EscrowStatus, Expiration, ExpiryBounds, KeyType, PaidPeriods, PauseFlags, PendingWithdrawal};
 
 /// Message to instantiate the contract
 #[cw_serde]
 pub struct InstantiateMsg {
     /// Address of the Registry contract
     pub registry_addr: String,
-    /// Percentage of fees to collect (0-100)
-    pub fee_percentage: u64,
+    /// Platform fee in basis points (0-10000, 1 bps = 0.01%)
+    pub fee_bps: u64,
+    /// Blocks a deposit withdrawal must wait before it can be claimed (optional)
+    pub withdrawal_delay: Option<u64>,
+    /// Default escrow TTL bounds (optional, 0-50 blocks and 0-300 seconds if omitted)
+    pub expiry_bounds: Option<ExpiryBounds>,
+}
+
+/// Payload of a CW20 Send to the escrow
+#[cw_serde]
+pub enum ReceiveMsg {
+    /// Locks the sent tokens for a tool priced in this CW20 token
+    LockFunds {
+        /// The tool ID in the registry
+        tool_id: String,
+        /// The maximum fee the caller is willing to pay (must be ≥ the tool price)
+        max_fee: Uint128,
+        /// Price the caller was quoted; the lock fails if the tool price differs (optional)
+        expected_price: Option<Uint128>,
+        /// sha256 of the authentication token the caller will present to the tool
+        auth_commitment: Binary,
+        /// Block height or time after which this escrow expires; must lie within the
+        /// tool's TTL bounds
+        expires: Expiration,
+    },
+}
+
+/// Escrow and usage fee settled by a ReleaseBatch
+#[cw_serde]
+pub struct ReleaseItem {
+    /// The escrow ID to release funds from
+    pub escrow_id: u64,
+    /// The actual usage fee to charge (must be ≤ max_fee and ≤ the price at lock time)
+    pub usage_fee: Uint128,
 }
 
+/// Message to migrate the contract to a new code version
+#[cw_serde]
+pub struct MigrateMsg {}
+
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Locks funds for a tool provider with an authentication token
     LockFunds {
         /// The tool ID in the registry
         tool_id: String,
-        /// The maximum fee the caller is willing to pay
+        /// The maximum fee the caller is willing to pay (must be ≥ the tool price)
         max_fee: Uint128,
-        /// Authentication token for the tool to verify the escrow
-        auth_token: String,
-        /// Block height when this escrow expires
-        expires: u64,
+        /// Price the caller was quoted; the lock fails if the tool price differs (optional)
+        expected_price: Option<Uint128>,
+        /// sha256 of the authentication token the caller will present to the tool.
+        /// The token should include a random salt so it cannot be guessed from the commitment.
+        auth_commitment: Binary,
+        /// Block height or time after which this escrow expires; must lie within the
+        /// tool's TTL bounds
+        expires: Expiration,
+        /// Draw max_fee from the caller's deposit instead of attached funds (optional)
+        from_deposit: Option<bool>,
     },
+    /// Entry point for CW20 tokens sent with a ReceiveMsg payload
+    Receive(Cw20ReceiveMsg),
     /// Releases locked funds to the provider after tool usage
     Release {
         /// The escrow ID to release funds from
         escrow_id: u64,
-        /// The actual usage fee to charge (must be ≤ max_fee)
+        /// The actual usage fee to charge; together with earlier charges it must be
+        /// ≤ max_fee and ≤ the price at lock time
         usage_fee: Uint128,
     },
+    /// Releases several escrows of the sender in one transaction. Fails if any item
+    /// would fail as a single Release.
+    ReleaseBatch {
+        /// Escrows to release with their usage fees (max 30)
+        items: Vec<ReleaseItem>,
+    },
+    /// Charges part of an escrow while it is live; can be called repeatedly by the provider
+    Charge {
+        /// The escrow ID to charge
+        escrow_id: u64,
+        /// Amount to charge now (the total of all charges must be ≤ the price at lock
+        /// time and ≤ max_fee)
+        amount: Uint128,
+    },
+    /// Settles an escrow after one or more charges, refunding the uncharged remainder to the caller
+    Close {
+        /// The escrow ID to close
+        escrow_id: u64,
+    },
     /// Refunds locked funds to the caller if the escrow has expired
     RefundExpired {
         /// The escrow ID to refund
         escrow_id: u64,
     },
+    /// Refunds several expired escrows to their callers; anyone can call it.
+    /// For other callers' escrows, the keeper reward is kept out of the refund and paid to the sender.
+    /// Fails if any of the escrows is not locked or not yet expired.
+    RefundExpiredBatch {
+        /// The escrow IDs to refund (max 30)
+        escrow_ids: Vec<u64>,
+    },
+    /// Refunds the oldest expired escrows to their callers, less the keeper reward; anyone can call it
+    SweepExpired {
+        /// Maximum number of escrows to refund (default: 10, max: 30)
+        limit: Option<u32>,
+    },
+    /// Credits the attached funds to the sender's deposit balance
+    Deposit {},
+    /// Starts withdrawing part of the sender's deposit; claimable after the withdrawal delay
+    Withdraw {
+        denom: String,
+        amount: Uint128,
+    },
+    /// Pays out a pending withdrawal once its delay has passed
+    ClaimWithdrawal {
+        denom: String,
+    },
+    /// Opens a payment channel to a tool's provider, funded with the attached funds
+    OpenChannel {
+        /// The tool ID in the registry
+        tool_id: String,
+        /// Public key the caller will sign vouchers with
+        pubkey: Binary,
+        /// Signature scheme of the public key
+        key_type: KeyType,
+    },
+    /// Provider only: Pays out a signed voucher for a cumulative amount.
+    /// The voucher signs sha256("toolpay-voucher" || contract address || channel_id as
+    /// 8 byte big endian || amount as 16 byte big endian).
+    SettleChannel {
+        channel_id: u64,
+        /// Cumulative amount the caller has authorized so far
+        amount: Uint128,
+        /// Caller signature over the voucher digest
+        signature: Binary,
+    },
+    /// Caller only: Starts the challenge period after which the channel can be closed
+    RequestCloseChannel {
+        channel_id: u64,
+    },
+    /// Refunds the unsettled deposit to the caller. The provider can close at any time,
+    /// the caller only after the challenge period of a requested close.
+    CloseChannel {
+        channel_id: u64,
+    },
+    /// Prepays a number of periods of a tool's subscription at its current price, extending an
+    /// active one. Fails if the tool's denom, period or provider changed since the active one started.
+    Subscribe {
+        /// The tool ID in the registry
+        tool_id: String,
+        /// Number of periods to pay for
+        periods: u64,
+    },
+    /// Provider only: Claims the payment of every elapsed, unclaimed period
+    ClaimSubscription {
+        /// Subscribed caller
+        caller: String,
+        /// The tool ID in the registry
+        tool_id: String,
+    },
+    /// Caller only: Ends a subscription after the current period and refunds the unused periods
+    CancelSubscription {
+        /// The tool ID in the registry
+        tool_id: String,
+    },
+    /// Owner only: Updates the contract configuration; omitted fields are left unchanged
+    UpdateConfig {
+        /// New Registry contract address (optional)
+        registry_addr: Option<String>,
+        /// New default platform fee in basis points, 0-10000 (optional)
+        fee_bps: Option<u64>,
+        /// New number of blocks a deposit withdrawal waits (optional)
+        withdrawal_delay: Option<u64>,
+        /// New default escrow TTL bounds (optional)
+        expiry_bounds: Option<ExpiryBounds>,
+        /// New keeper reward in basis points of each swept refund, paid out of that refund, 0-100 (optional)
+        keeper_reward_bps: Option<u64>,
+    },
+    /// Owner only: Sets or removes a negotiated fee rate for a provider or a tool
+    SetFeeOverride {
+        target: FeeOverrideTarget,
+        /// Fee in basis points, None removes the override
+        fee_bps: Option<u64>,
+    },
+    /// Owner only: Proposes a new owner, who takes over once it accepts
+    ProposeOwner {
+        new_owner: String,
+    },
+    /// Accepts a pending ownership proposal, called by the proposed owner
+    AcceptOwner {},
+    /// Caller only: Returns the uncharged funds of a locked escrow while releases are paused
+    EmergencyWithdraw {
+        /// The escrow ID to withdraw from
+        escrow_id: u64,
+    },
+    /// Provider only: Refuses an escrow that has not been charged, refunding it in full
+    DeclineEscrow {
+        escrow_id: u64,
+    },
+    /// Provider only: Confirms the provider is serving the escrow, ending the caller's
+    /// right to cancel it
+    AcknowledgeEscrow {
+        escrow_id: u64,
+    },
+    /// Caller only: Cancels an escrow for a cancellable tool before the provider
+    /// acknowledges it, refunding it in full
+    CancelEscrow {
+        escrow_id: u64,
+    },
+    /// Owner only: Sets individual pause flags; omitted flags are left unchanged
+    SetPause {
+        /// Pause new escrows, deposits, channels and subscriptions (optional)
+        locks: Option<bool>,
+        /// Pause payouts to providers (optional)
+        releases: Option<bool>,
+        /// Pause fee claims (optional)
+        fee_claims: Option<bool>,
+    },
+    /// Owner only: Sends funds the contract holds beyond its liabilities in a denom
+    RecoverUnaccounted {
+        denom: String,
+        /// Address receiving the funds, defaults to the owner (optional)
+        recipient: Option<String>,
+    },
     /// Owner only: Claims the accumulated fee from the contract
     ClaimFees {
         /// Optional denom to claim, if None claims all denoms
         denom: Option<String>,
     },
+    /// Owner only: Deletes the receipts of settled escrows to free storage
+    PruneReceipts {
+        /// IDs of settled escrows to delete
+        escrow_ids: Vec<u64>,
+    },
 }
 
 #[cw_serde]
@@ -49,6 +251,50 @@ pub enum QueryMsg {
     #[returns(EscrowResponse)]
     GetEscrow { escrow_id: u64 },
     
+    /// Gets the settlement receipt of a released, refunded or cancelled escrow
+    #[returns(ReceiptResponse)]
+    GetReceipt { escrow_id: u64 },
+    
+    /// Checks a token presented to a tool against the escrow's commitment
+    #[returns(VerifyEscrowTokenResponse)]
+    VerifyEscrowToken { escrow_id: u64, token: String },
+    
+    /// Checks whether an escrow can pay for a call to a tool, for provider middleware
+    #[returns(VerifyEscrowResponse)]
+    VerifyEscrow {
+        escrow_id: u64,
+        /// Tool the call is for
+        tool_id: String,
+        /// Provider address serving the call
+        provider: String,
+        /// Minimum amount the escrow must still be able to pay
+        min_amount: Uint128,
+    },
+    
+    /// Gets a caller's deposit balance and pending withdrawal for a denom
+    #[returns(DepositResponse)]
+    GetDeposit { caller: String, denom: String },
+    
+    /// Gets details about a payment channel
+    #[returns(ChannelResponse)]
+    GetChannel { channel_id: u64 },
+    
+    /// Gets a caller's subscription to a tool, None if there is none
+    #[returns(Option<SubscriptionResponse>)]
+    GetSubscription { caller: String, tool_id: String },
+    
+    /// Gets the current contract configuration
+    #[returns(ConfigResponse)]
+    GetConfig {},
+    
+    /// Gets the fee rate that applies to a provider's tool and where it comes from
+    #[returns(EffectiveFeeResponse)]
+    GetEffectiveFee { provider: String, tool_id: String },
+    
+    /// Gets how much of the contract's balance in a denom is not owed to anyone
+    #[returns(UnaccountedBalanceResponse)]
+    GetUnaccountedBalance { denom: String },
+    
     /// Gets information about collected fees
     #[returns(CollectedFeesResponse)]
     GetCollectedFees {},
@@ -60,10 +306,16 @@ pub enum QueryMsg {
         caller: Option<String>,
         /// Filter by provider address (optional)
         provider: Option<String>,
+        /// Filter by registry tool ID (optional)
+        tool_id: Option<String>,
+        /// Filter by lifecycle status (optional)
+        status: Option<EscrowStatus>,
         /// Pagination cursor - start after this escrow ID (optional)
         start_after: Option<u64>,
         /// Maximum number of escrows to return (default: 30, max: 30)
         limit: Option<u32>,
+        /// Return the newest escrows first; start_after then pages towards older IDs (optional)
+        descending: Option<bool>,
     },
 }
 
@@ -73,16 +325,147 @@ pub struct EscrowResponse {
     pub escrow_id: u64,
     pub caller: Addr,
     pub provider: Addr,
+    pub tool_id: String,
     pub max_fee: Uint128,
+    pub price: Uint128,
+    pub denom: String,
+    pub expires: Expiration,
+    pub auth_commitment: Binary,
+    pub status: EscrowStatus,
+    pub charged: Uint128,
+    pub platform_fee: Uint128,
+    pub refunded: Uint128,
+    pub settled_at: Option<u64>,
+    /// Whether the caller may cancel the escrow until it is acknowledged
+    pub cancellable: bool,
+    pub acknowledged: bool,
+}
+
+/// Response type for GetReceipt query
+#[cw_serde]
+pub struct ReceiptResponse {
+    pub escrow_id: u64,
+    pub tool_id: String,
+    pub caller: Addr,
+    pub provider: Addr,
     pub denom: String,
-    pub expires: u64,
-    pub auth_token: String,
+    pub status: EscrowStatus,
+    /// Funds the caller locked
+    pub max_fee: Uint128,
+    /// Amount charged by the provider, including the platform fee
+    pub charged: Uint128,
+    /// Part of the charged amount kept as platform fee
+    pub platform_fee: Uint128,
+    /// Amount returned to the caller
+    pub refunded: Uint128,
+    /// Block height at which the escrow was settled
+    pub settled_at: u64,
+}
+
+/// Response type for VerifyEscrowToken query
+#[cw_serde]
+pub struct VerifyEscrowTokenResponse {
+    /// True if the token matches and the escrow is locked and not expired
+    pub valid: bool,
+    /// Amount still available to charge, at most the price at lock time, zero unless
+    /// the token is valid
+    pub remaining: Uint128,
+    /// Point after which the escrow expires
+    pub expires: Expiration,
+}
+
+/// Reason an escrow failed verification
+#[cw_serde]
+pub enum VerifyEscrowReason {
+    /// No escrow exists with this ID
+    NotFound,
+    /// The escrow has been released, refunded or cancelled
+    NotLocked,
+    /// The escrow has expired
+    Expired,
+    /// The escrow was locked for a different tool
+    WrongTool,
+    /// The escrow pays a different provider
+    WrongProvider,
+    /// The escrow cannot cover the minimum amount
+    Insufficient,
+    /// Releases are paused, so the escrow cannot be released
+    Frozen,
+}
+
+/// Response type for VerifyEscrow query
+#[cw_serde]
+pub struct VerifyEscrowResponse {
+    /// True if the escrow can pay for the call
+    pub valid: bool,
+    /// Why the escrow failed verification, None when valid
+    pub reason: Option<VerifyEscrowReason>,
+    /// Amount still available to charge, at most the price at lock time
+    pub remaining: Uint128,
+    /// Point after which the escrow expires, None if it does not exist
+    pub expires: Option<Expiration>,
+}
+
+/// Response type for GetDeposit query
+#[cw_serde]
+pub struct DepositResponse {
+    pub caller: Addr,
+    pub denom: String,
+    /// Balance available to lock
+    pub balance: Uint128,
+    /// Withdrawal waiting for its delay, if any
+    pub pending_withdrawal: Option<PendingWithdrawal>,
+}
+
+/// Response type for GetChannel query
+#[cw_serde]
+pub struct ChannelResponse {
+    pub channel_id: u64,
+    pub caller: Addr,
+    pub provider: Addr,
+    pub tool_id: String,
+    pub denom: String,
+    pub deposit: Uint128,
+    pub settled: Uint128,
+    pub platform_fee: Uint128,
+    pub pubkey: Binary,
+    pub key_type: KeyType,
+    pub closes_at: Option<u64>,
+    pub closed: bool,
+}
+
+/// Response type for GetSubscription query
+#[cw_serde]
+pub struct SubscriptionResponse {
+    pub caller: Addr,
+    pub tool_id: String,
+    pub provider: Addr,
+    pub denom: String,
+    /// Prices of the paid periods in the order they run
+    pub prices: Vec<PaidPeriods>,
+    pub period: u64,
+    pub start: u64,
+    pub periods: u64,
+    pub claimed: u64,
+    /// Block height at which the last paid period ends
+    pub paid_until: u64,
+    /// Whether the caller may currently use the tool
+    pub entitled: bool,
+}
+
+/// Response type for OpenChannel execute method
+/// This is encoded and returned in the response data field
+#[cw_serde]
+pub struct OpenChannelResponse {
+    pub channel_id: u64,
 }
 
 /// Response type for GetEscrows query
 #[cw_serde]
 pub struct EscrowsResponse {
     pub escrows: Vec<EscrowResponse>,
+    /// Cursor for the next page, None once all matching escrows were returned
+    pub next_start_after: Option<u64>,
 }
 
 /// Response type for LockFunds execute method
@@ -97,13 +480,75 @@ pub struct LockFundsResponse {
 #[cw_serde]
 pub struct CollectedFeesResponse {
     pub owner: Addr,
-    pub fee_percentage: u64,
+    /// Default platform fee in basis points
+    pub fee_bps: u64,
     pub collected_fees: Vec<(String, Uint128)>,
 }
 
+/// Response type for GetConfig query
+#[cw_serde]
+pub struct ConfigResponse {
+    pub registry_addr: Addr,
+    pub owner: Addr,
+    /// Proposed owner that has not accepted yet
+    pub pending_owner: Option<Addr>,
+    /// Default platform fee in basis points
+    pub fee_bps: u64,
+    pub withdrawal_delay: u64,
+    /// Default escrow TTL bounds
+    pub expiry_bounds: ExpiryBounds,
+    /// Keeper reward in basis points of each swept refund
+    pub keeper_reward_bps: u64,
+    pub paused: PauseFlags,
+}
+
+/// What a fee override applies to
+#[cw_serde]
+pub enum FeeOverrideTarget {
+    /// Every tool of a provider
+    Provider { provider: String },
+    /// A single tool, taking precedence over its provider's override
+    Tool { tool_id: String },
+}
+
+/// Origin of the fee rate applied to a charge
+#[cw_serde]
+pub enum FeeSource {
+    Tool,
+    Provider,
+    Default,
+}
+
+/// Response type for GetEffectiveFee query
+#[cw_serde]
+pub struct EffectiveFeeResponse {
+    pub fee_bps: u64,
+    pub source: FeeSource,
+}
+
+/// Response type for GetUnaccountedBalance query
+#[cw_serde]
+pub struct UnaccountedBalanceResponse {
+    pub denom: String,
+    /// Balance the contract holds
+    pub balance: Uint128,
+    /// Total the contract owes
+    pub liabilities: Uint128,
+    /// Balance beyond the liabilities, recoverable by the owner
+    pub unaccounted: Uint128,
+}
+
 /// Message type for sudo calls
 #[cw_serde]
 pub enum SudoMsg {
-    /// Freezes the contract, preventing any new escrows or releases
+    /// Pauses locks, releases and fee claims at once
     Freeze {},
+    /// Lifts every pause flag
+    Unfreeze {},
+    /// Sets individual pause flags; omitted flags are left unchanged
+    SetPause {
+        locks: Option<bool>,
+        releases: Option<bool>,
+        fee_claims: Option<bool>,
+    },
 }
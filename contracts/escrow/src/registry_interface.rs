@@ -1,6 +1,8 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_json_binary, Addr, QuerierWrapper, QueryRequest, StdResult, Uint128, WasmQuery};
 
+use crate::state::ExpiryBounds;
+
 // Response type from Registry contract for GetTool query
 #[cw_serde]
 pub struct ToolResponse {
@@ -10,6 +12,19 @@ pub struct ToolResponse {
     pub denom: String,
     pub is_active: bool,
     pub description: String,
+    #[serde(default)]
+    pub subscription: Option<SubscriptionTerms>,
+    #[serde(default)]
+    pub expiry_bounds: Option<ExpiryBounds>,
+    #[serde(default)]
+    pub cancellable: bool,
+}
+
+// Subscription pricing advertised by a tool in the Registry contract
+#[cw_serde]
+pub struct SubscriptionTerms {
+    pub price: Uint128,
+    pub period: u64,
 }
 
 // Query message for Registry contract
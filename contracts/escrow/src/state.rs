@@ -1,6 +1,72 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
-use cw_storage_plus::{Item, Map};
+use std::fmt;
+
+use cosmwasm_std::{Addr, Binary, BlockInfo, OverflowError, Timestamp, Uint128, Uint64};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+
+/// Lifecycle status of an escrow
+#[cw_serde]
+pub enum EscrowStatus {
+    /// Funds are locked and can be released or refunded
+    Locked,
+    /// The provider was paid and any remainder returned to the caller
+    Released,
+    /// All funds were returned to the caller after expiry
+    Refunded,
+    /// The escrow was called off before use (declined, cancelled or withdrawn) and all
+    /// uncharged funds were returned to the caller
+    Cancelled,
+}
+
+/// Point after which an escrow expires
+#[cw_serde]
+#[derive(Copy)]
+pub enum Expiration {
+    /// Expires once the chain is past this block height
+    AtHeight(u64),
+    /// Expires once the block time is past this timestamp
+    AtTime(Timestamp),
+}
+
+impl Expiration {
+    /// True once the given block is past the expiration point
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        match self {
+            Expiration::AtHeight(height) => block.height > *height,
+            Expiration::AtTime(time) => block.time > *time,
+        }
+    }
+
+    /// Key of the expiry index: heights sort before times, each in ascending order
+    pub fn index_key(&self) -> (u8, u64) {
+        match self {
+            Expiration::AtHeight(height) => (0, *height),
+            Expiration::AtTime(time) => (1, time.nanos()),
+        }
+    }
+}
+
+impl fmt::Display for Expiration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expiration::AtHeight(height) => write!(f, "height:{height}"),
+            Expiration::AtTime(time) => write!(f, "time:{time}"),
+        }
+    }
+}
+
+/// Limits on how far ahead an escrow may expire
+#[cw_serde]
+pub struct ExpiryBounds {
+    /// Minimum TTL of a height-based expiration, in blocks
+    pub min_blocks: u64,
+    /// Maximum TTL of a height-based expiration, in blocks
+    pub max_blocks: u64,
+    /// Minimum TTL of a time-based expiration, in seconds
+    pub min_seconds: u64,
+    /// Maximum TTL of a time-based expiration, in seconds
+    pub max_seconds: u64,
+}
 
 /// Escrow information for a locked fund
 #[cw_serde]
@@ -9,33 +75,132 @@ pub struct Escrow {
     pub caller: Addr,
     /// Tool provider who will receive the fee
     pub provider: Addr,
+    /// Registry tool the funds were locked for (empty for escrows locked before 0.2.0)
+    pub tool_id: String,
     /// Maximum fee the caller is willing to pay
     pub max_fee: Uint128,
+    /// Tool price recorded when the funds were locked
+    pub price: Uint128,
     /// Token denomination for the fee
     pub denom: String,
-    /// Authentication token for tool to verify the escrow
-    pub auth_token: String,
-    /// Block height at which this escrow expires
-    pub expires: u64,
+    /// sha256 commitment to the authentication token the caller presents to the tool
+    pub auth_commitment: Binary,
+    /// Point after which this escrow expires
+    pub expires: Expiration,
+    /// Current lifecycle status
+    pub status: EscrowStatus,
+    /// Amount charged by the provider, including the platform fee
+    pub charged: Uint128,
+    /// Part of the charged amount kept as platform fee
+    pub platform_fee: Uint128,
+    /// Amount returned to the caller
+    pub refunded: Uint128,
+    /// Block height at which the escrow was settled, None while locked
+    pub settled_at: Option<u64>,
+    /// If true, the funds were drawn from the caller's deposit and refunds go back to it
+    pub from_deposit: bool,
+    /// If true, the tool allowed the caller to cancel until the provider acknowledges
+    pub cancellable: bool,
+    /// Whether the provider acknowledged the escrow, explicitly or by charging it
+    pub acknowledged: bool,
+}
+
+impl Escrow {
+    /// Amount the provider can still charge, limited by both max_fee and the price at lock time
+    pub fn chargeable(&self) -> Uint128 {
+        self.price.min(self.max_fee).saturating_sub(self.charged)
+    }
 }
 
 /// Global contract configuration
 #[cw_serde]
 pub struct Config {
-    /// If true, contract operations are frozen
-    pub frozen: bool,
+    /// Operations that are currently paused
+    pub paused: PauseFlags,
     /// Address of the registry contract
     pub registry_addr: Addr,
     /// Contract owner who can claim fees
     pub owner: Addr,
-    /// Percentage of fees to collect (0-100)
-    pub fee_percentage: u64,
+    /// Default platform fee in basis points (0-10000), rounded down on each payout
+    pub fee_bps: u64,
     /// Accumulated fees by denom
     pub collected_fees: Vec<(String, Uint128)>,
+    /// Blocks a deposit withdrawal must wait before it can be claimed
+    pub withdrawal_delay: u64,
+    /// Default escrow TTL bounds, replaced by a tool's own bounds in the registry
+    pub expiry_bounds: ExpiryBounds,
+    /// Share of an expired escrow's refund paid to whoever sweeps another caller's
+    /// escrow instead of to the caller, in basis points, at most 100 (0 disables the reward)
+    pub keeper_reward_bps: u64,
+}
+
+/// Negotiated fee rates in basis points by provider
+pub const PROVIDER_FEES: Map<&Addr, u64> = Map::new("provider_fees");
+
+/// Negotiated fee rates in basis points by tool_id, taking precedence over provider rates
+pub const TOOL_FEES: Map<&str, u64> = Map::new("tool_fees");
+
+/// Pause switches for groups of operations. Refunds, emergency withdrawals and
+/// deposit withdrawals are never paused.
+#[cw_serde]
+#[derive(Default)]
+pub struct PauseFlags {
+    /// New escrows, deposits, channels and subscriptions
+    pub locks: bool,
+    /// Payouts to providers: releases, charges, channel settlements and subscription claims
+    pub releases: bool,
+    /// Owner fee claims
+    pub fee_claims: bool,
+}
+
+/// Address proposed as the next contract owner, waiting for it to accept
+pub const PENDING_OWNER: Item<Addr> = Item::new("pending_owner");
+
+/// Deposit withdrawal waiting for its delay to pass
+#[cw_serde]
+pub struct PendingWithdrawal {
+    /// Amount that will be paid out
+    pub amount: Uint128,
+    /// Block height from which the withdrawal can be claimed
+    pub available_at: u64,
+}
+
+/// Secondary indexes over escrows
+pub struct EscrowIndexes<'a> {
+    /// Escrows by caller address
+    pub caller: MultiIndex<'a, String, Escrow, u64>,
+    /// Escrows by provider address
+    pub provider: MultiIndex<'a, String, Escrow, u64>,
+    /// Escrows by registry tool ID
+    pub tool_id: MultiIndex<'a, String, Escrow, u64>,
+    /// Escrows by lock state (1 = locked, 0 = settled), then expiration, see [`Expiration::index_key`]
+    pub expires: MultiIndex<'a, (u8, u8, u64), Escrow, u64>,
+}
+
+impl IndexList<Escrow> for EscrowIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Escrow>> + '_> {
+        let v: Vec<&dyn Index<Escrow>> = vec![&self.caller, &self.provider, &self.tool_id, &self.expires];
+        Box::new(v.into_iter())
+    }
 }
 
 /// Map of escrow ID to escrow data
-pub const ESCROWS: Map<u64, Escrow> = Map::new("escrows");
+pub const ESCROWS: IndexedMap<u64, Escrow, EscrowIndexes> = IndexedMap::new(
+    "escrows",
+    EscrowIndexes {
+        caller: MultiIndex::new(|_pk, e| e.caller.to_string(), "escrows", "escrows__caller"),
+        provider: MultiIndex::new(|_pk, e| e.provider.to_string(), "escrows", "escrows__provider"),
+        tool_id: MultiIndex::new(|_pk, e| e.tool_id.clone(), "escrows", "escrows__tool_id"),
+        expires: MultiIndex::new(
+            |_pk, e| {
+                let (kind, at) = e.expires.index_key();
+                ((e.status == EscrowStatus::Locked) as u8, kind, at)
+            },
+            "escrows",
+            "escrows__expires",
+        ),
+    },
+);
 
 /// Counter to generate sequential escrow IDs
 pub const NEXT_ID: Item<u64> = Item::new("next_id");
@@ -43,3 +208,131 @@ pub const NEXT_ID: Item<u64> = Item::new("next_id");
 /// Global contract configuration
 pub const CONFIG: Item<Config> = Item::new("config");
 
+/// Prepaid caller balances by (caller, denom)
+pub const DEPOSITS: Map<(&Addr, &str), Uint128> = Map::new("deposits");
+
+/// Pending deposit withdrawals by (caller, denom)
+pub const WITHDRAWALS: Map<(&Addr, &str), PendingWithdrawal> = Map::new("withdrawals");
+
+/// Total the contract owes by denom: locked escrows, deposits, pending withdrawals,
+/// channels, subscriptions and collected fees. Anything the contract holds beyond
+/// this was sent to it by mistake.
+pub const LIABILITIES: Map<&str, Uint128> = Map::new("liabilities");
+
+/// Signature scheme of a channel's caller key
+#[cw_serde]
+pub enum KeyType {
+    Secp256k1,
+    Ed25519,
+}
+
+/// Unidirectional payment channel from a caller to a tool provider
+#[cw_serde]
+pub struct Channel {
+    /// Caller who funded the channel and signs vouchers
+    pub caller: Addr,
+    /// Tool provider who settles vouchers
+    pub provider: Addr,
+    /// Registry tool the channel pays for
+    pub tool_id: String,
+    /// Token denomination of the deposit
+    pub denom: String,
+    /// Total funds deposited into the channel
+    pub deposit: Uint128,
+    /// Cumulative amount settled to the provider, including the platform fee
+    pub settled: Uint128,
+    /// Part of the settled amount kept as platform fee
+    pub platform_fee: Uint128,
+    /// Public key vouchers must be signed with
+    pub pubkey: Binary,
+    /// Signature scheme of the public key
+    pub key_type: KeyType,
+    /// Block height from which the caller can close the channel, set once a close is requested
+    pub closes_at: Option<u64>,
+    /// True once the remaining deposit has been refunded
+    pub closed: bool,
+}
+
+/// Map of channel ID to channel data
+pub const CHANNELS: Map<u64, Channel> = Map::new("channels");
+
+/// Counter to generate sequential channel IDs
+pub const NEXT_CHANNEL_ID: Item<u64> = Item::new("next_channel_id");
+
+/// Consecutive subscription periods paid for at the same price
+#[cw_serde]
+pub struct PaidPeriods {
+    /// Number of periods
+    pub periods: u64,
+    /// Price of each period
+    pub price: Uint128,
+}
+
+/// Prepaid recurring subscription of a caller to a tool
+#[cw_serde]
+pub struct Subscription {
+    /// Provider receiving the period payments
+    pub provider: Addr,
+    /// Token denomination of the payments
+    pub denom: String,
+    /// Prices of the paid periods in the order they run; each extension is
+    /// paid at the tool's price at that time
+    pub prices: Vec<PaidPeriods>,
+    /// Length of one period in blocks
+    pub period: u64,
+    /// Block height at which the first period started
+    pub start: u64,
+    /// Number of periods paid for
+    pub periods: u64,
+    /// Number of elapsed periods the provider has claimed
+    pub claimed: u64,
+}
+
+impl Subscription {
+    /// Block height at which the last paid period ends
+    pub fn paid_until(&self) -> Result<u64, OverflowError> {
+        let length = Uint64::new(self.periods).checked_mul(Uint64::new(self.period))?;
+        Ok(length.checked_add(Uint64::new(self.start))?.u64())
+    }
+
+    /// Appends `periods` periods paid at `price`
+    pub fn extend(&mut self, periods: u64, price: Uint128) -> Result<(), OverflowError> {
+        self.periods = Uint64::new(self.periods).checked_add(Uint64::new(periods))?.u64();
+        match self.prices.last_mut() {
+            Some(last) if last.price == price => last.periods += periods,
+            _ => self.prices.push(PaidPeriods { periods, price }),
+        }
+        Ok(())
+    }
+
+    /// Drops every paid period after the first `periods`
+    pub fn truncate(&mut self, periods: u64) {
+        let mut kept = 0;
+        self.prices.retain_mut(|paid| {
+            paid.periods = paid.periods.min(periods - kept);
+            kept += paid.periods;
+            paid.periods > 0
+        });
+        self.periods = periods;
+    }
+
+    /// Total price of `count` paid periods following the first `skip`
+    pub fn cost(&self, mut skip: u64, mut count: u64) -> Result<Uint128, OverflowError> {
+        let mut total = Uint128::zero();
+        for paid in &self.prices {
+            let n = paid.periods.saturating_sub(skip).min(count);
+            skip = skip.saturating_sub(paid.periods);
+            count -= n;
+            total = total.checked_add(paid.price.checked_mul(Uint128::from(n))?)?;
+        }
+        Ok(total)
+    }
+
+    /// Number of paid periods that have fully elapsed at `height`
+    pub fn elapsed_periods(&self, height: u64) -> u64 {
+        (height.saturating_sub(self.start) / self.period).min(self.periods)
+    }
+}
+
+/// Map of (caller, tool_id) to the caller's subscription
+pub const SUBSCRIPTIONS: Map<(&Addr, &str), Subscription> = Map::new("subscriptions");
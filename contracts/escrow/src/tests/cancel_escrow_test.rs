@@ -0,0 +1,122 @@
+//! # Cancel Escrow Test
+//!
+//! This module tests ending escrows early by the provider or the caller in the Escrow contract.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. The provider can decline an uncharged escrow, refunding it in full
+//! 2. Callers can only cancel escrows for tools that opted in
+//! 3. Acknowledging or charging an escrow ends the caller's right to cancel it
+//! 4. Only the provider can decline or acknowledge, and only the caller can cancel
+
+use cosmwasm_std::{Addr, Uint128};
+use cw_multi_test::Executor;
+use registry::msg::ExecuteMsg as RegistryExecuteMsg;
+
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+use crate::state::EscrowStatus;
+use crate::tests::setup_contract::{
+    balance, execute_as, expect_error, lock_default, query_escrow, setup_contracts_with_tool, TestContracts,
+    DEFAULT_MAX_FEE, DEFAULT_TOOL_ID, DEFAULT_TTL, PROVIDER, UNAUTHORIZED, USER,
+};
+
+fn set_cancellable(contracts: &mut TestContracts, cancellable: bool) {
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &RegistryExecuteMsg::UpdateCancellable {
+            tool_id: DEFAULT_TOOL_ID.to_string(),
+            cancellable,
+        },
+        &[],
+    ).unwrap();
+}
+
+/// # Test: Provider Declines an Escrow
+///
+/// ## Test Steps:
+///
+/// 1. Lock two escrows and charge one of them
+/// 2. Verify only the provider can decline
+/// 3. Decline the uncharged escrow and verify the caller is refunded in full
+/// 4. Verify the charged escrow cannot be declined
+#[test]
+fn test_decline_escrow() {
+    let mut contracts = setup_contracts_with_tool(0);
+    let declined = lock_default(&mut contracts, USER, DEFAULT_TTL);
+    let charged = lock_default(&mut contracts, USER, DEFAULT_TTL);
+    execute_as(&mut contracts, PROVIDER, &ExecuteMsg::Charge { escrow_id: charged, amount: Uint128::new(10) }, &[])
+        .unwrap();
+
+    expect_error(execute_as(&mut contracts, USER, &ExecuteMsg::DeclineEscrow { escrow_id: declined }, &[]), |e| {
+        matches!(e, ContractError::Unauthorized {})
+    });
+
+    let user_before = balance(&contracts, USER);
+    execute_as(&mut contracts, PROVIDER, &ExecuteMsg::DeclineEscrow { escrow_id: declined }, &[]).unwrap();
+    assert_eq!(user_before + DEFAULT_MAX_FEE, balance(&contracts, USER));
+    let escrow = query_escrow(&contracts, declined).unwrap();
+    assert_eq!(EscrowStatus::Cancelled, escrow.status);
+    assert_eq!(Uint128::new(DEFAULT_MAX_FEE), escrow.refunded);
+
+    expect_error(execute_as(&mut contracts, PROVIDER, &ExecuteMsg::DeclineEscrow { escrow_id: charged }, &[]), |e| {
+        matches!(e, ContractError::EscrowCharged {})
+    });
+}
+
+/// # Test: Caller Cancels Before Acknowledgement
+///
+/// ## Test Steps:
+///
+/// 1. Verify an escrow for a tool that did not opt in cannot be cancelled
+/// 2. Opt the tool in and lock three escrows
+/// 3. Verify only the caller can cancel, and cancel the first for a full refund
+/// 4. Acknowledge the second and verify it can no longer be cancelled or acknowledged again
+/// 5. Charge the third and verify it can no longer be cancelled
+#[test]
+fn test_cancel_escrow() {
+    let mut contracts = setup_contracts_with_tool(0);
+    let locked_before_opt_in = lock_default(&mut contracts, USER, DEFAULT_TTL);
+    assert!(!query_escrow(&contracts, locked_before_opt_in).unwrap().cancellable);
+    expect_error(
+        execute_as(&mut contracts, USER, &ExecuteMsg::CancelEscrow { escrow_id: locked_before_opt_in }, &[]),
+        |e| matches!(e, ContractError::CancelNotAllowed {}),
+    );
+
+    set_cancellable(&mut contracts, true);
+    let cancelled = lock_default(&mut contracts, USER, DEFAULT_TTL);
+    let acknowledged = lock_default(&mut contracts, USER, DEFAULT_TTL);
+    let charged = lock_default(&mut contracts, USER, DEFAULT_TTL);
+
+    expect_error(
+        execute_as(&mut contracts, UNAUTHORIZED, &ExecuteMsg::CancelEscrow { escrow_id: cancelled }, &[]),
+        |e| matches!(e, ContractError::Unauthorized {}),
+    );
+    let user_before = balance(&contracts, USER);
+    execute_as(&mut contracts, USER, &ExecuteMsg::CancelEscrow { escrow_id: cancelled }, &[]).unwrap();
+    assert_eq!(user_before + DEFAULT_MAX_FEE, balance(&contracts, USER));
+    assert_eq!(EscrowStatus::Cancelled, query_escrow(&contracts, cancelled).unwrap().status);
+
+    expect_error(
+        execute_as(&mut contracts, USER, &ExecuteMsg::AcknowledgeEscrow { escrow_id: acknowledged }, &[]),
+        |e| matches!(e, ContractError::Unauthorized {}),
+    );
+    execute_as(&mut contracts, PROVIDER, &ExecuteMsg::AcknowledgeEscrow { escrow_id: acknowledged }, &[]).unwrap();
+    assert!(query_escrow(&contracts, acknowledged).unwrap().acknowledged);
+    expect_error(
+        execute_as(&mut contracts, PROVIDER, &ExecuteMsg::AcknowledgeEscrow { escrow_id: acknowledged }, &[]),
+        |e| matches!(e, ContractError::EscrowAcknowledged {}),
+    );
+    expect_error(execute_as(&mut contracts, USER, &ExecuteMsg::CancelEscrow { escrow_id: acknowledged }, &[]), |e| {
+        matches!(e, ContractError::EscrowAcknowledged {})
+    });
+
+    execute_as(&mut contracts, PROVIDER, &ExecuteMsg::Charge { escrow_id: charged, amount: Uint128::new(10) }, &[])
+        .unwrap();
+    expect_error(execute_as(&mut contracts, USER, &ExecuteMsg::CancelEscrow { escrow_id: charged }, &[]), |e| {
+        matches!(e, ContractError::EscrowAcknowledged {})
+    });
+}
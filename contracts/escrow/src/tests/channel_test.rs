@@ -0,0 +1,265 @@
+//! # Payment Channel Test
+//!
+//! This module tests unidirectional payment channels in the Escrow contract.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Providers can settle cumulative vouchers signed with secp256k1 or ed25519 keys
+//! 2. Stale, oversized or wrongly signed vouchers are rejected
+//! 3. Platform fees are taken from each settlement
+//! 4. Callers can only close a channel after the challenge period, providers at any time
+
+use cosmwasm_std::{Addr, Binary, Uint128};
+use cw_multi_test::error::AnyResult;
+use cw_multi_test::AppResponse;
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+
+use crate::contract::{voucher_digest, CHANNEL_CHALLENGE_BLOCKS};
+use crate::error::ContractError;
+use crate::msg::{ChannelResponse, ExecuteMsg, QueryMsg};
+use crate::state::KeyType;
+use crate::tests::setup_contract::{
+    balance, execute_as, expect_error, neutron, register_tool, setup_contracts, setup_contracts_with_fee, TestContracts,
+    DEFAULT_MAX_FEE, DEFAULT_TOOL_ID, PROVIDER, UNAUTHORIZED, USER,
+};
+
+const CHANNEL_DEPOSIT: u128 = 1000;
+const SECRET: [u8; 32] = [7u8; 32];
+
+enum TestKey {
+    Secp256k1(k256::ecdsa::SigningKey),
+    Ed25519(ed25519_zebra::SigningKey),
+}
+
+impl TestKey {
+    fn secp256k1() -> Self {
+        TestKey::Secp256k1(k256::ecdsa::SigningKey::from_bytes(&SECRET.into()).unwrap())
+    }
+
+    fn ed25519() -> Self {
+        TestKey::Ed25519(ed25519_zebra::SigningKey::from(SECRET))
+    }
+
+    fn key_type(&self) -> KeyType {
+        match self {
+            TestKey::Secp256k1(_) => KeyType::Secp256k1,
+            TestKey::Ed25519(_) => KeyType::Ed25519,
+        }
+    }
+
+    fn pubkey(&self) -> Binary {
+        match self {
+            TestKey::Secp256k1(key) => Binary::from(key.verifying_key().to_encoded_point(true).as_bytes()),
+            TestKey::Ed25519(key) => {
+                let bytes: [u8; 32] = ed25519_zebra::VerificationKey::from(key).into();
+                Binary::from(bytes)
+            }
+        }
+    }
+
+    fn sign(&self, digest: &[u8]) -> Binary {
+        match self {
+            TestKey::Secp256k1(key) => {
+                let signature: k256::ecdsa::Signature = key.sign_prehash(digest).unwrap();
+                Binary::from(signature.to_bytes().as_slice())
+            }
+            TestKey::Ed25519(key) => Binary::from(key.sign(digest).to_bytes()),
+        }
+    }
+}
+
+fn open_channel(contracts: &mut TestContracts, key: &TestKey) -> AnyResult<AppResponse> {
+    let msg = ExecuteMsg::OpenChannel {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+        pubkey: key.pubkey(),
+        key_type: key.key_type(),
+    };
+    execute_as(contracts, USER, &msg, &neutron(CHANNEL_DEPOSIT))
+}
+
+fn settle(contracts: &mut TestContracts, key: &TestKey, channel_id: u64, amount: u128) -> AnyResult<AppResponse> {
+    let contract_addr = Addr::unchecked(&contracts.escrow_addr);
+    let signature = key.sign(&voucher_digest(&contract_addr, channel_id, Uint128::new(amount)));
+    let msg = ExecuteMsg::SettleChannel {
+        channel_id,
+        amount: Uint128::new(amount),
+        signature,
+    };
+    execute_as(contracts, PROVIDER, &msg, &[])
+}
+
+fn query_channel(contracts: &TestContracts, channel_id: u64) -> ChannelResponse {
+    contracts.app.wrap().query_wasm_smart(&contracts.escrow_addr, &QueryMsg::GetChannel { channel_id }).unwrap()
+}
+
+fn setup_channel(mut contracts: TestContracts, key: &TestKey) -> TestContracts {
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+    open_channel(&mut contracts, key).unwrap();
+    contracts
+}
+
+fn assert_settles_vouchers(key: TestKey) {
+    let mut contracts = setup_channel(setup_contracts(), &key);
+    let provider_start = balance(&contracts, PROVIDER);
+
+    let channel = query_channel(&contracts, 1);
+    assert_eq!(Uint128::new(CHANNEL_DEPOSIT), channel.deposit);
+    assert_eq!(key.pubkey(), channel.pubkey);
+
+    settle(&mut contracts, &key, 1, 100).unwrap();
+    settle(&mut contracts, &key, 1, 250).unwrap();
+    assert_eq!(provider_start + 250, balance(&contracts, PROVIDER));
+    assert_eq!(Uint128::new(250), query_channel(&contracts, 1).settled);
+
+    // Replaying an older voucher is rejected
+    expect_error(settle(&mut contracts, &key, 1, 100), |e| {
+        matches!(e, ContractError::VoucherNotIncreasing { .. })
+    });
+}
+
+/// # Test: Settling secp256k1 Vouchers
+///
+/// ## Test Steps:
+///
+/// 1. Open a channel with a secp256k1 key
+/// 2. Settle two increasing vouchers and verify the provider receives the delta each time
+/// 3. Verify replaying an older voucher fails
+#[test]
+fn test_settle_secp256k1_vouchers() {
+    assert_settles_vouchers(TestKey::secp256k1());
+}
+
+/// # Test: Settling ed25519 Vouchers
+///
+/// ## Test Steps:
+///
+/// 1. Open a channel with an ed25519 key
+/// 2. Settle two increasing vouchers and verify the provider receives the delta each time
+/// 3. Verify replaying an older voucher fails
+#[test]
+fn test_settle_ed25519_vouchers() {
+    assert_settles_vouchers(TestKey::ed25519());
+}
+
+/// # Test: Rejecting Invalid Vouchers
+///
+/// ## Test Steps:
+///
+/// 1. Verify a voucher above the deposit fails
+/// 2. Verify a voucher signed for a different amount fails
+/// 3. Verify a voucher signed by another key fails
+/// 4. Verify only the provider can settle
+#[test]
+fn test_invalid_vouchers() {
+    let key = TestKey::secp256k1();
+    let mut contracts = setup_channel(setup_contracts(), &key);
+
+    expect_error(settle(&mut contracts, &key, 1, CHANNEL_DEPOSIT + 1), |e| {
+        matches!(e, ContractError::VoucherExceedsDeposit { .. })
+    });
+
+    let contract_addr = Addr::unchecked(&contracts.escrow_addr);
+    let signature = key.sign(&voucher_digest(&contract_addr, 1, Uint128::new(10)));
+    let msg = ExecuteMsg::SettleChannel {
+        channel_id: 1,
+        amount: Uint128::new(500),
+        signature: signature.clone(),
+    };
+    expect_error(execute_as(&mut contracts, PROVIDER, &msg, &[]), |e| {
+        matches!(e, ContractError::InvalidSignature {})
+    });
+
+    expect_error(settle(&mut contracts, &TestKey::ed25519(), 1, 10), |e| {
+        matches!(e, ContractError::InvalidSignature {})
+    });
+
+    let msg = ExecuteMsg::SettleChannel {
+        channel_id: 1,
+        amount: Uint128::new(10),
+        signature,
+    };
+    expect_error(execute_as(&mut contracts, UNAUTHORIZED, &msg, &[]), |e| {
+        matches!(e, ContractError::Unauthorized {})
+    });
+}
+
+/// # Test: Platform Fee on Settlements
+///
+/// ## Test Steps:
+///
+/// 1. Open a channel on a contract with a 10% fee
+/// 2. Settle a voucher and verify the provider receives the amount minus the fee
+/// 3. Verify the fee is recorded on the channel
+#[test]
+fn test_settle_with_platform_fee() {
+    let key = TestKey::ed25519();
+    let mut contracts = setup_channel(setup_contracts_with_fee(1_000), &key);
+    let provider_start = balance(&contracts, PROVIDER);
+
+    settle(&mut contracts, &key, 1, 200).unwrap();
+    assert_eq!(provider_start + 180, balance(&contracts, PROVIDER));
+    assert_eq!(Uint128::new(20), query_channel(&contracts, 1).platform_fee);
+}
+
+/// # Test: Closing a Channel
+///
+/// ## Test Steps:
+///
+/// 1. Verify the caller cannot close without requesting a close
+/// 2. Request a close and verify closing during the challenge period fails
+/// 3. Settle a voucher during the challenge period
+/// 4. Close after the challenge period and verify the caller gets the unsettled deposit back
+/// 5. Verify the closed channel can no longer be settled
+#[test]
+fn test_caller_close_after_challenge() {
+    let key = TestKey::secp256k1();
+    let mut contracts = setup_channel(setup_contracts(), &key);
+    let user_start = balance(&contracts, USER);
+    let close = ExecuteMsg::CloseChannel { channel_id: 1 };
+
+    expect_error(execute_as(&mut contracts, USER, &close, &[]), |e| {
+        matches!(e, ContractError::CloseNotRequested {})
+    });
+
+    execute_as(&mut contracts, USER, &ExecuteMsg::RequestCloseChannel { channel_id: 1 }, &[]).unwrap();
+    let closes_at = contracts.app.block_info().height + CHANNEL_CHALLENGE_BLOCKS;
+    assert_eq!(Some(closes_at), query_channel(&contracts, 1).closes_at);
+    expect_error(execute_as(&mut contracts, USER, &close, &[]), |e| {
+        matches!(e, ContractError::ChallengePeriodActive { .. })
+    });
+
+    settle(&mut contracts, &key, 1, 300).unwrap();
+
+    contracts.app.update_block(|block| block.height += CHANNEL_CHALLENGE_BLOCKS);
+    execute_as(&mut contracts, USER, &close, &[]).unwrap();
+    assert_eq!(user_start + CHANNEL_DEPOSIT - 300, balance(&contracts, USER));
+    assert!(query_channel(&contracts, 1).closed);
+
+    expect_error(settle(&mut contracts, &key, 1, 400), |e| {
+        matches!(e, ContractError::ChannelClosed {})
+    });
+}
+
+/// # Test: Provider Closing a Channel
+///
+/// ## Test Steps:
+///
+/// 1. Settle a voucher
+/// 2. Verify a third party cannot close the channel
+/// 3. Close as the provider without a challenge period and verify the caller is refunded
+#[test]
+fn test_provider_close() {
+    let key = TestKey::ed25519();
+    let mut contracts = setup_channel(setup_contracts(), &key);
+    let user_start = balance(&contracts, USER);
+    let close = ExecuteMsg::CloseChannel { channel_id: 1 };
+
+    settle(&mut contracts, &key, 1, 400).unwrap();
+    expect_error(execute_as(&mut contracts, UNAUTHORIZED, &close, &[]), |e| {
+        matches!(e, ContractError::Unauthorized {})
+    });
+
+    execute_as(&mut contracts, PROVIDER, &close, &[]).unwrap();
+    assert_eq!(user_start + CHANNEL_DEPOSIT - 400, balance(&contracts, USER));
+}
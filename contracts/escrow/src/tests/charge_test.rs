@@ -0,0 +1,212 @@
+//! # Incremental Charge Test
+//!
+//! This module tests charging an escrow several times before closing it.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. The provider can charge a live escrow repeatedly and is paid on every charge
+//! 2. Close refunds the uncharged remainder to the caller
+//! 3. Platform fees collected across several charges equal the fee of a single release
+//! 4. Release and RefundExpired settle the remainder after earlier charges
+//! 5. Charges by other addresses, of zero, beyond the remaining balance or after settlement fail
+//! 6. The total charged can never exceed the tool price at lock time, however it is split
+
+use cosmwasm_std::{Coin, Uint128};
+
+use crate::error::ContractError;
+use crate::msg::{CollectedFeesResponse, ExecuteMsg, QueryMsg};
+use crate::state::EscrowStatus;
+use crate::tests::setup_contract::{
+    balance, execute_as, expect_error, lock_funds, query_escrow, refund_expired, register_tool, release_funds,
+    setup_contracts_with_fee, TestContracts, DEFAULT_MAX_FEE, DEFAULT_TOOL_ID, DEFAULT_TTL, NEUTRON, PROVIDER, USER,
+};
+
+fn setup_escrow() -> TestContracts {
+    let mut contracts = setup_contracts_with_fee(1_000);
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+    lock_funds(
+        &mut contracts,
+        DEFAULT_TOOL_ID,
+        DEFAULT_MAX_FEE,
+        DEFAULT_TTL,
+        "charge_token".to_string(),
+        USER,
+        &[Coin {
+            denom: NEUTRON.to_string(),
+            amount: Uint128::new(DEFAULT_MAX_FEE),
+        }],
+    ).unwrap();
+    contracts
+}
+
+fn charge_msg(amount: u128) -> ExecuteMsg {
+    ExecuteMsg::Charge {
+        escrow_id: 1,
+        amount: Uint128::new(amount),
+    }
+}
+
+fn collected_fees(contracts: &TestContracts) -> Vec<(String, Uint128)> {
+    let res: CollectedFeesResponse = contracts.app.wrap().query_wasm_smart(
+        &contracts.escrow_addr,
+        &QueryMsg::GetCollectedFees {},
+    ).unwrap();
+    res.collected_fees
+}
+
+/// # Test: Charging Several Times and Closing
+///
+/// ## Test Steps:
+///
+/// 1. Lock 100 with a 10% platform fee
+/// 2. Charge 15 three times and verify the provider is paid each time
+/// 3. Verify the collected platform fee is the fee of 45, not three times the fee of 15
+/// 4. Close the escrow and verify the caller gets the remaining 55 back
+/// 5. Verify further charges fail
+#[test]
+fn test_charge_then_close() {
+    let mut contracts = setup_escrow();
+    let provider_start = balance(&contracts, PROVIDER);
+    let user_start = balance(&contracts, USER);
+
+    for _ in 0..3 {
+        execute_as(&mut contracts, PROVIDER, &charge_msg(15), &[]).unwrap();
+    }
+
+    // fee(15) = 1, fee(30) = 3, fee(45) = 4
+    assert_eq!(vec![(NEUTRON.to_string(), Uint128::new(4))], collected_fees(&contracts));
+    assert_eq!(provider_start + 41, balance(&contracts, PROVIDER));
+
+    let escrow = query_escrow(&contracts, 1).unwrap();
+    assert_eq!(EscrowStatus::Locked, escrow.status);
+    assert_eq!(Uint128::new(45), escrow.charged);
+    assert_eq!(Uint128::new(4), escrow.platform_fee);
+
+    execute_as(&mut contracts, PROVIDER, &ExecuteMsg::Close { escrow_id: 1 }, &[]).unwrap();
+    assert_eq!(user_start + 55, balance(&contracts, USER));
+
+    let escrow = query_escrow(&contracts, 1).unwrap();
+    assert_eq!(EscrowStatus::Released, escrow.status);
+    assert_eq!(Uint128::new(55), escrow.refunded);
+
+    expect_error(execute_as(&mut contracts, PROVIDER, &charge_msg(1), &[]), |e| {
+        matches!(e, ContractError::EscrowNotLocked {})
+    });
+}
+
+/// # Test: Invalid Charges
+///
+/// ## Test Steps:
+///
+/// 1. Lock 100
+/// 2. Verify charges by the caller, of zero and beyond the remaining balance fail
+/// 3. Verify the caller cannot close the escrow
+#[test]
+fn test_invalid_charges() {
+    let mut contracts = setup_escrow();
+
+    expect_error(execute_as(&mut contracts, USER, &charge_msg(10), &[]), |e| {
+        matches!(e, ContractError::Unauthorized {})
+    });
+    expect_error(execute_as(&mut contracts, PROVIDER, &charge_msg(0), &[]), |e| {
+        matches!(e, ContractError::ZeroCharge {})
+    });
+
+    execute_as(&mut contracts, PROVIDER, &charge_msg(70), &[]).unwrap();
+    expect_error(execute_as(&mut contracts, PROVIDER, &charge_msg(31), &[]), |e| {
+        matches!(e, ContractError::FeeTooHigh { max_fee, .. } if max_fee == "30")
+    });
+
+    expect_error(execute_as(&mut contracts, USER, &ExecuteMsg::Close { escrow_id: 1 }, &[]), |e| {
+        matches!(e, ContractError::Unauthorized {})
+    });
+}
+
+/// # Test: Release and Refund After Charges
+///
+/// ## Test Steps:
+///
+/// 1. Charge 30, then release 20 and verify the caller gets 50 back and the fee is that of 50
+/// 2. On a second escrow, charge 40, let it expire and verify the refund is the remaining 60
+#[test]
+fn test_release_and_refund_after_charges() {
+    let mut contracts = setup_escrow();
+    let user_start = balance(&contracts, USER);
+
+    execute_as(&mut contracts, PROVIDER, &charge_msg(30), &[]).unwrap();
+    release_funds(&mut contracts, 1, 20, PROVIDER).unwrap();
+
+    assert_eq!(user_start + 50, balance(&contracts, USER));
+    assert_eq!(vec![(NEUTRON.to_string(), Uint128::new(5))], collected_fees(&contracts));
+    let escrow = query_escrow(&contracts, 1).unwrap();
+    assert_eq!(Uint128::new(50), escrow.charged);
+    assert_eq!(Uint128::new(50), escrow.refunded);
+
+    let escrow_id = lock_funds(
+        &mut contracts,
+        DEFAULT_TOOL_ID,
+        DEFAULT_MAX_FEE,
+        DEFAULT_TTL,
+        "charge_token_2".to_string(),
+        USER,
+        &[Coin {
+            denom: NEUTRON.to_string(),
+            amount: Uint128::new(DEFAULT_MAX_FEE),
+        }],
+    ).unwrap();
+    execute_as(&mut contracts, PROVIDER, &ExecuteMsg::Charge { escrow_id, amount: Uint128::new(40) }, &[]).unwrap();
+
+    let before_refund = balance(&contracts, USER);
+    contracts.app.update_block(|block| block.height += DEFAULT_TTL + 1);
+    refund_expired(&mut contracts, escrow_id, USER).unwrap();
+    assert_eq!(before_refund + 60, balance(&contracts, USER));
+
+    let escrow = query_escrow(&contracts, escrow_id).unwrap();
+    assert_eq!(EscrowStatus::Refunded, escrow.status);
+    assert_eq!(Uint128::new(60), escrow.refunded);
+}
+
+/// # Test: Charges Are Capped at the Price in Total
+///
+/// ## Test Steps:
+///
+/// 1. Lock a max fee of 100 for a tool priced at 10
+/// 2. Charge the full price in two charges and verify the charged events report what is left of the price
+/// 3. Verify a further charge or a release with a usage fee both exceed the price
+/// 4. Release without a further fee and verify the caller gets the other 90 back
+#[test]
+fn test_charges_capped_at_price() {
+    let mut contracts = setup_contracts_with_fee(1_000);
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, 10, PROVIDER).unwrap();
+    let escrow_id = lock_funds(
+        &mut contracts,
+        DEFAULT_TOOL_ID,
+        DEFAULT_MAX_FEE,
+        DEFAULT_TTL,
+        "metered_token".to_string(),
+        USER,
+        &[Coin {
+            denom: NEUTRON.to_string(),
+            amount: Uint128::new(DEFAULT_MAX_FEE),
+        }],
+    ).unwrap();
+    let user_start = balance(&contracts, USER);
+
+    for (amount, remaining) in [(6, "4"), (4, "0")] {
+        let res = execute_as(&mut contracts, PROVIDER, &charge_msg(amount), &[]).unwrap();
+        let charged = res.events.iter().find(|e| e.ty == "wasm-wasm-toolpay.charged").unwrap();
+        assert!(charged.attributes.iter().any(|a| a.key == "remaining" && a.value == remaining));
+    }
+    expect_error(execute_as(&mut contracts, PROVIDER, &charge_msg(1), &[]), |e| {
+        matches!(e, ContractError::FeeExceedsPrice { requested_fee, .. } if requested_fee == "11")
+    });
+    let release = ExecuteMsg::Release { escrow_id, usage_fee: Uint128::new(10) };
+    expect_error(execute_as(&mut contracts, PROVIDER, &release, &[]), |e| {
+        matches!(e, ContractError::FeeExceedsPrice { price, .. } if price == "10")
+    });
+
+    release_funds(&mut contracts, escrow_id, 0, PROVIDER).unwrap();
+    assert_eq!(user_start + 90, balance(&contracts, USER));
+    assert_eq!(Uint128::new(10), query_escrow(&contracts, escrow_id).unwrap().charged);
+}
@@ -0,0 +1,161 @@
+//! # Config Update Test
+//!
+//! This module tests owner-managed configuration updates in the Escrow contract.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. GetConfig returns all current parameters
+//! 2. Only the owner can update the configuration, and invalid fees are rejected
+//! 3. Updates emit a `wasm-toolpay.config_updated` event
+//! 4. Ownership moves through a ProposeOwner/AcceptOwner handoff
+
+use cosmwasm_std::{Coin, Uint128};
+
+use crate::contract::DEFAULT_WITHDRAWAL_DELAY;
+use crate::error::ContractError;
+use crate::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
+use crate::state::PauseFlags;
+use crate::tests::setup_contract::{
+    execute_as, expect_error, lock_funds, query_escrow, register_tool, release_funds, setup_contracts, TestContracts,
+    DEFAULT_MAX_FEE, DEFAULT_TOOL_ID, DEFAULT_TTL, NEUTRON, OWNER, PROVIDER, UNAUTHORIZED, USER,
+};
+
+fn query_config(contracts: &TestContracts) -> ConfigResponse {
+    contracts.app.wrap().query_wasm_smart(&contracts.escrow_addr, &QueryMsg::GetConfig {}).unwrap()
+}
+
+/// # Test: Updating the Configuration
+///
+/// ## Test Steps:
+///
+/// 1. Verify GetConfig returns the instantiation parameters
+/// 2. Verify a non-owner cannot update the configuration
+/// 3. Verify a fee above 10000 basis points is rejected
+/// 4. Update the fee and withdrawal delay and verify the event
+/// 5. Verify fields that were not provided are unchanged
+#[test]
+fn test_update_config() {
+    let mut contracts = setup_contracts();
+    let config = query_config(&contracts);
+    assert_eq!(contracts.registry_addr, config.registry_addr.to_string());
+    assert_eq!(contracts.app.api().addr_make(OWNER), config.owner);
+    assert_eq!(None, config.pending_owner);
+    assert_eq!(0, config.fee_bps);
+    assert_eq!(DEFAULT_WITHDRAWAL_DELAY, config.withdrawal_delay);
+    assert_eq!(PauseFlags::default(), config.paused);
+
+    let update = ExecuteMsg::UpdateConfig {
+        registry_addr: None,
+        fee_bps: Some(500),
+        withdrawal_delay: Some(10),
+        expiry_bounds: None,
+        keeper_reward_bps: None,
+    };
+    expect_error(execute_as(&mut contracts, UNAUTHORIZED, &update, &[]), |e| {
+        matches!(e, ContractError::Unauthorized {})
+    });
+    let invalid = ExecuteMsg::UpdateConfig {
+        registry_addr: None,
+        fee_bps: Some(10_001),
+        withdrawal_delay: None,
+        expiry_bounds: None,
+        keeper_reward_bps: None,
+    };
+    expect_error(execute_as(&mut contracts, OWNER, &invalid, &[]), |e| {
+        matches!(e, ContractError::InvalidFeeBps(10_001))
+    });
+
+    let res = execute_as(&mut contracts, OWNER, &update, &[]).unwrap();
+    let event = res.events.iter().find(|e| e.ty == "wasm-wasm-toolpay.config_updated").unwrap();
+    assert!(event.attributes.iter().any(|a| a.key == "fee_bps" && a.value == "500"));
+    assert!(!event.attributes.iter().any(|a| a.key == "registry_addr"));
+
+    let config = query_config(&contracts);
+    assert_eq!(500, config.fee_bps);
+    assert_eq!(10, config.withdrawal_delay);
+    assert_eq!(contracts.registry_addr, config.registry_addr.to_string());
+}
+
+/// # Test: Fee Change Between Charges
+///
+/// ## Test Steps:
+///
+/// 1. Lock funds without a platform fee
+/// 2. Raise the fee to 10%
+/// 3. Release the escrow and verify the new fee applies
+#[test]
+fn test_fee_change_applies_to_open_escrows() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+    let funds = vec![Coin {
+        denom: NEUTRON.to_string(),
+        amount: Uint128::new(DEFAULT_MAX_FEE),
+    }];
+    let escrow_id = lock_funds(
+        &mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, DEFAULT_TTL, "config_token".to_string(), USER, &funds,
+    ).unwrap();
+
+    let update = ExecuteMsg::UpdateConfig {
+        registry_addr: None,
+        fee_bps: Some(1_000),
+        withdrawal_delay: None,
+        expiry_bounds: None,
+        keeper_reward_bps: None,
+    };
+    execute_as(&mut contracts, OWNER, &update, &[]).unwrap();
+
+    release_funds(&mut contracts, escrow_id, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+    let escrow = query_escrow(&contracts, escrow_id).unwrap();
+    assert_eq!(Uint128::new(10), escrow.platform_fee);
+}
+
+/// # Test: Two-Step Ownership Transfer
+///
+/// ## Test Steps:
+///
+/// 1. Verify accepting without a proposal fails
+/// 2. Verify a non-owner cannot propose a new owner
+/// 3. Propose a new owner and verify it is pending while the old owner stays in charge
+/// 4. Verify only the proposed owner can accept
+/// 5. Accept and verify the new owner can update the config and the old one cannot
+#[test]
+fn test_ownership_transfer() {
+    let mut contracts = setup_contracts();
+    let new_owner = contracts.app.api().addr_make(USER);
+
+    expect_error(execute_as(&mut contracts, USER, &ExecuteMsg::AcceptOwner {}, &[]), |e| {
+        matches!(e, ContractError::NoPendingOwner {})
+    });
+
+    let propose = ExecuteMsg::ProposeOwner {
+        new_owner: new_owner.to_string(),
+    };
+    expect_error(execute_as(&mut contracts, UNAUTHORIZED, &propose, &[]), |e| {
+        matches!(e, ContractError::Unauthorized {})
+    });
+    execute_as(&mut contracts, OWNER, &propose, &[]).unwrap();
+    let config = query_config(&contracts);
+    assert_eq!(Some(new_owner.clone()), config.pending_owner);
+    assert_eq!(contracts.app.api().addr_make(OWNER), config.owner);
+
+    expect_error(execute_as(&mut contracts, UNAUTHORIZED, &ExecuteMsg::AcceptOwner {}, &[]), |e| {
+        matches!(e, ContractError::Unauthorized {})
+    });
+    execute_as(&mut contracts, USER, &ExecuteMsg::AcceptOwner {}, &[]).unwrap();
+    let config = query_config(&contracts);
+    assert_eq!(new_owner, config.owner);
+    assert_eq!(None, config.pending_owner);
+
+    let update = ExecuteMsg::UpdateConfig {
+        registry_addr: None,
+        fee_bps: Some(100),
+        withdrawal_delay: None,
+        expiry_bounds: None,
+        keeper_reward_bps: None,
+    };
+    expect_error(execute_as(&mut contracts, OWNER, &update, &[]), |e| {
+        matches!(e, ContractError::Unauthorized {})
+    });
+    execute_as(&mut contracts, USER, &update, &[]).unwrap();
+}
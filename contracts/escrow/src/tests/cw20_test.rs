@@ -0,0 +1,181 @@
+//! # CW20 Payment Test
+//!
+//! This module tests escrows funded with CW20 tokens in the Escrow contract.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. CW20 tokens sent with an embedded LockFunds create an escrow in the "cw20:<token>" denom
+//! 2. Tokens from a contract other than the tool's CW20 token are rejected
+//! 3. Release, RefundExpired and ClaimFees pay out CW20 escrows with token transfers
+
+use cosmwasm_std::{to_json_binary, Addr, Empty, Uint128};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw_multi_test::error::AnyResult;
+use cw_multi_test::{AppResponse, Contract, ContractWrapper, Executor};
+use registry::msg::ExecuteMsg as RegistryExecuteMsg;
+
+use crate::contract::CW20_DENOM_PREFIX;
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, ReceiveMsg};
+use crate::state::Expiration;
+use crate::tests::setup_contract::{
+    auth_commitment, query_escrow, refund_expired, release_funds, setup_contracts, setup_contracts_with_fee,
+    TestContracts, DEFAULT_MAX_FEE, DEFAULT_TOOL_ID, DEFAULT_TTL, DEFAULT_USAGE_FEE, OWNER, PROVIDER, USER,
+};
+
+const INITIAL_TOKENS: u128 = 10_000;
+
+fn cw20_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn instantiate_token(contracts: &mut TestContracts, symbol: &str) -> Addr {
+    let code_id = contracts.app.store_code(cw20_contract());
+    let owner_addr = contracts.app.api().addr_make(OWNER);
+    let user_addr = contracts.app.api().addr_make(USER);
+    contracts.app.instantiate_contract(
+        code_id,
+        owner_addr,
+        &cw20_base::msg::InstantiateMsg {
+            name: format!("{} token", symbol),
+            symbol: symbol.to_string(),
+            decimals: 6,
+            initial_balances: vec![Cw20Coin {
+                address: user_addr.to_string(),
+                amount: Uint128::new(INITIAL_TOKENS),
+            }],
+            mint: None,
+            marketing: None,
+        },
+        &[],
+        symbol,
+        None,
+    ).unwrap()
+}
+
+fn register_cw20_tool(contracts: &mut TestContracts, token: &Addr) {
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &RegistryExecuteMsg::RegisterTool {
+            tool_id: DEFAULT_TOOL_ID.to_string(),
+            price: Uint128::new(DEFAULT_MAX_FEE),
+            denom: Some(format!("{}{}", CW20_DENOM_PREFIX, token)),
+            description: "Tool priced in a CW20 token".to_string(),
+            endpoint: "https://api.example.com/cw20".to_string(),
+        },
+        &[],
+    ).unwrap();
+}
+
+fn send_lock(contracts: &mut TestContracts, token: &Addr, amount: u128) -> AnyResult<AppResponse> {
+    let user_addr = contracts.app.api().addr_make(USER);
+    let lock = ReceiveMsg::LockFunds {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+        max_fee: Uint128::new(DEFAULT_MAX_FEE),
+        expected_price: None,
+        auth_commitment: auth_commitment("cw20_token"),
+        expires: Expiration::AtHeight(contracts.app.block_info().height + DEFAULT_TTL),
+    };
+    contracts.app.execute_contract(
+        user_addr,
+        token.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: contracts.escrow_addr.clone(),
+            amount: Uint128::new(amount),
+            msg: to_json_binary(&lock).unwrap(),
+        },
+        &[],
+    )
+}
+
+fn token_balance(contracts: &TestContracts, token: &Addr, who: &str) -> u128 {
+    let address = contracts.app.api().addr_make(who).to_string();
+    let res: BalanceResponse = contracts.app.wrap()
+        .query_wasm_smart(token, &Cw20QueryMsg::Balance { address })
+        .unwrap();
+    res.balance.u128()
+}
+
+fn setup_cw20(mut contracts: TestContracts) -> (TestContracts, Addr) {
+    let token = instantiate_token(&mut contracts, "TKN");
+    register_cw20_tool(&mut contracts, &token);
+    (contracts, token)
+}
+
+/// # Test: Locking and Releasing CW20 Tokens
+///
+/// ## Test Steps:
+///
+/// 1. Register a tool priced in a CW20 token on a contract with a 10% fee (1000 bps)
+/// 2. Send tokens to the escrow with an embedded LockFunds
+/// 3. Verify the escrow records the CW20 denom
+/// 4. Release the escrow and verify the provider and caller token balances
+/// 5. Claim the platform fee and verify the owner receives tokens
+#[test]
+fn test_cw20_lock_and_release() {
+    let (mut contracts, token) = setup_cw20(setup_contracts_with_fee(1_000));
+
+    send_lock(&mut contracts, &token, DEFAULT_MAX_FEE).unwrap();
+    let escrow = query_escrow(&contracts, 1).unwrap();
+    assert_eq!(format!("cw20:{}", token), escrow.denom);
+    assert_eq!(INITIAL_TOKENS - DEFAULT_MAX_FEE, token_balance(&contracts, &token, USER));
+
+    release_funds(&mut contracts, 1, DEFAULT_USAGE_FEE, PROVIDER).unwrap();
+    assert_eq!(45, token_balance(&contracts, &token, PROVIDER));
+    assert_eq!(INITIAL_TOKENS - DEFAULT_USAGE_FEE, token_balance(&contracts, &token, USER));
+
+    let owner_addr = contracts.app.api().addr_make(OWNER);
+    contracts.app.execute_contract(
+        owner_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::ClaimFees { denom: None },
+        &[],
+    ).unwrap();
+    assert_eq!(5, token_balance(&contracts, &token, OWNER));
+}
+
+/// # Test: Refunding Expired CW20 Escrows
+///
+/// ## Test Steps:
+///
+/// 1. Lock CW20 tokens in an escrow
+/// 2. Let the escrow expire and refund it
+/// 3. Verify the caller gets all tokens back
+#[test]
+fn test_cw20_refund_expired() {
+    let (mut contracts, token) = setup_cw20(setup_contracts());
+
+    send_lock(&mut contracts, &token, DEFAULT_MAX_FEE).unwrap();
+    contracts.app.update_block(|block| block.height += DEFAULT_TTL + 1);
+    refund_expired(&mut contracts, 1, USER).unwrap();
+    assert_eq!(INITIAL_TOKENS, token_balance(&contracts, &token, USER));
+}
+
+/// # Test: Rejecting Other CW20 Tokens
+///
+/// ## Test Steps:
+///
+/// 1. Register a tool priced in one CW20 token
+/// 2. Send a different CW20 token with an embedded LockFunds
+/// 3. Verify the lock fails because no funds in the tool's denom were received
+#[test]
+fn test_cw20_wrong_token() {
+    let (mut contracts, _token) = setup_cw20(setup_contracts());
+    let other = instantiate_token(&mut contracts, "OTH");
+
+    let result = send_lock(&mut contracts, &other, DEFAULT_MAX_FEE);
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::NoDenomFunds { .. }) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+    assert_eq!(INITIAL_TOKENS, token_balance(&contracts, &other, USER));
+}
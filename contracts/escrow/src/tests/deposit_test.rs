@@ -0,0 +1,135 @@
+//! # Caller Deposit Test
+//!
+//! This module tests prepaid caller deposits in the Escrow contract.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Deposits are credited per caller and denom
+//! 2. LockFunds can draw from the deposit instead of attached funds
+//! 3. Uncharged funds of deposit-backed escrows return to the deposit, not the caller's account
+//! 4. Withdrawals only pay out after the withdrawal delay
+
+use cosmwasm_std::{Coin, Uint128};
+use cw_multi_test::error::AnyResult;
+use cw_multi_test::AppResponse;
+
+use crate::contract::DEFAULT_WITHDRAWAL_DELAY;
+use crate::error::ContractError;
+use crate::msg::{DepositResponse, ExecuteMsg, QueryMsg};
+use crate::state::Expiration;
+use crate::tests::setup_contract::{
+    auth_commitment, balance, execute_as, expect_error, neutron, refund_expired, register_tool, release_funds,
+    setup_contracts, TestContracts, DEFAULT_MAX_FEE, DEFAULT_TOOL_ID, DEFAULT_TTL, DEFAULT_USAGE_FEE, NEUTRON, PROVIDER,
+    USER,
+};
+
+const DEPOSIT: u128 = 500;
+
+fn lock_from_deposit(contracts: &mut TestContracts, max_fee: u128, funds: &[Coin]) -> AnyResult<AppResponse> {
+    let msg = ExecuteMsg::LockFunds {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+        max_fee: Uint128::new(max_fee),
+        expected_price: None,
+        auth_commitment: auth_commitment("deposit_token"),
+        expires: Expiration::AtHeight(contracts.app.block_info().height + DEFAULT_TTL),
+        from_deposit: Some(true),
+    };
+    execute_as(contracts, USER, &msg, funds)
+}
+
+fn query_deposit(contracts: &TestContracts) -> DepositResponse {
+    contracts.app.wrap().query_wasm_smart(
+        &contracts.escrow_addr,
+        &QueryMsg::GetDeposit {
+            caller: contracts.app.api().addr_make(USER).to_string(),
+            denom: NEUTRON.to_string(),
+        },
+    ).unwrap()
+}
+
+fn setup_deposit() -> TestContracts {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+    execute_as(&mut contracts, USER, &ExecuteMsg::Deposit {}, &neutron(DEPOSIT)).unwrap();
+    contracts
+}
+
+/// # Test: Locking From a Deposit
+///
+/// ## Test Steps:
+///
+/// 1. Deposit 500 and verify the balance
+/// 2. Verify locking from the deposit with attached funds or beyond the balance fails
+/// 3. Lock from the deposit and verify the balance drops by max_fee
+/// 4. Release part of the escrow and verify the remainder returns to the deposit
+/// 5. Let a second escrow expire, refund it and verify it returns to the deposit
+#[test]
+fn test_lock_from_deposit() {
+    let mut contracts = setup_deposit();
+    assert_eq!(Uint128::new(DEPOSIT), query_deposit(&contracts).balance);
+    let bank_start = balance(&contracts, USER);
+
+    expect_error(lock_from_deposit(&mut contracts, DEFAULT_MAX_FEE, &neutron(DEFAULT_MAX_FEE)), |e| {
+        matches!(e, ContractError::UnexpectedFunds {})
+    });
+    expect_error(lock_from_deposit(&mut contracts, DEPOSIT + 1, &[]), |e| {
+        matches!(e, ContractError::InsufficientDeposit { .. })
+    });
+
+    lock_from_deposit(&mut contracts, DEFAULT_MAX_FEE, &[]).unwrap();
+    assert_eq!(Uint128::new(DEPOSIT - DEFAULT_MAX_FEE), query_deposit(&contracts).balance);
+
+    release_funds(&mut contracts, 1, DEFAULT_USAGE_FEE, PROVIDER).unwrap();
+    assert_eq!(Uint128::new(DEPOSIT - DEFAULT_USAGE_FEE), query_deposit(&contracts).balance);
+
+    lock_from_deposit(&mut contracts, DEFAULT_MAX_FEE, &[]).unwrap();
+    contracts.app.update_block(|block| block.height += DEFAULT_TTL + 1);
+    refund_expired(&mut contracts, 2, USER).unwrap();
+    assert_eq!(Uint128::new(DEPOSIT - DEFAULT_USAGE_FEE), query_deposit(&contracts).balance);
+
+    // Nothing was paid out to or taken from the caller's account along the way
+    assert_eq!(bank_start, balance(&contracts, USER));
+}
+
+/// # Test: Delayed Withdrawals
+///
+/// ## Test Steps:
+///
+/// 1. Deposit 500 and request a withdrawal of 200
+/// 2. Verify the balance and pending withdrawal
+/// 3. Verify claiming before the delay fails
+/// 4. Claim after the delay and verify the payout
+/// 5. Verify the withdrawal cannot be claimed twice
+#[test]
+fn test_withdrawal_delay() {
+    let mut contracts = setup_deposit();
+    let start_height = contracts.app.block_info().height;
+    let bank_start = balance(&contracts, USER);
+
+    expect_error(
+        execute_as(&mut contracts, USER, &ExecuteMsg::Withdraw { denom: NEUTRON.to_string(), amount: Uint128::new(DEPOSIT + 1) }, &[]),
+        |e| matches!(e, ContractError::InsufficientDeposit { .. }),
+    );
+    execute_as(&mut contracts, USER, &ExecuteMsg::Withdraw { denom: NEUTRON.to_string(), amount: Uint128::new(200) }, &[]).unwrap();
+
+    let deposit = query_deposit(&contracts);
+    assert_eq!(Uint128::new(300), deposit.balance);
+    let pending = deposit.pending_withdrawal.unwrap();
+    assert_eq!(Uint128::new(200), pending.amount);
+    assert_eq!(start_height + DEFAULT_WITHDRAWAL_DELAY, pending.available_at);
+
+    let claim = ExecuteMsg::ClaimWithdrawal { denom: NEUTRON.to_string() };
+    expect_error(execute_as(&mut contracts, USER, &claim, &[]), |e| {
+        matches!(e, ContractError::WithdrawalNotReady { .. })
+    });
+
+    contracts.app.update_block(|block| block.height += DEFAULT_WITHDRAWAL_DELAY);
+    execute_as(&mut contracts, USER, &claim, &[]).unwrap();
+    assert_eq!(bank_start + 200, balance(&contracts, USER));
+    assert!(query_deposit(&contracts).pending_withdrawal.is_none());
+
+    expect_error(execute_as(&mut contracts, USER, &claim, &[]), |e| {
+        matches!(e, ContractError::NoPendingWithdrawal { .. })
+    });
+}
@@ -0,0 +1,203 @@
+//! # Expiry Test
+//!
+//! This module tests height and time based expirations and their TTL bounds in the Escrow contract.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Escrows can expire at a block time, and are settled or refunded accordingly
+//! 2. Expirations that have already passed are rejected for both heights and times
+//! 3. The owner can change the default TTL bounds, which are enforced in blocks and seconds
+//! 4. A tool's bounds in the registry replace the contract defaults
+
+use cosmwasm_std::{Addr, Coin, Uint128};
+use cw_multi_test::error::AnyResult;
+use cw_multi_test::{AppResponse, Executor};
+use registry::msg::ExecuteMsg as RegistryExecuteMsg;
+use registry::state::ExpiryBounds as RegistryExpiryBounds;
+
+use crate::error::ContractError;
+use crate::msg::{ConfigResponse, EscrowResponse, ExecuteMsg, QueryMsg};
+use crate::state::{EscrowStatus, Expiration, ExpiryBounds};
+use crate::tests::setup_contract::{
+    auth_commitment, expect_error, query_escrow, refund_expired, release_funds, setup_contracts_with_tool,
+    TestContracts, DEFAULT_MAX_FEE, DEFAULT_TOOL_ID, DEFAULT_USAGE_FEE, NEUTRON, OWNER, PROVIDER, USER,
+};
+
+fn lock_until(contracts: &mut TestContracts, expires: Expiration) -> AnyResult<AppResponse> {
+    let user_addr = contracts.app.api().addr_make(USER);
+    contracts.app.execute_contract(
+        user_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::LockFunds {
+            tool_id: DEFAULT_TOOL_ID.to_string(),
+            max_fee: Uint128::new(DEFAULT_MAX_FEE),
+            expected_price: None,
+            auth_commitment: auth_commitment("expiry_token"),
+            expires,
+            from_deposit: None,
+        },
+        &[Coin {
+            denom: NEUTRON.to_string(),
+            amount: Uint128::new(DEFAULT_MAX_FEE),
+        }],
+    )
+}
+
+fn in_blocks(contracts: &TestContracts, blocks: u64) -> Expiration {
+    Expiration::AtHeight(contracts.app.block_info().height + blocks)
+}
+
+fn in_seconds(contracts: &TestContracts, seconds: u64) -> Expiration {
+    Expiration::AtTime(contracts.app.block_info().time.plus_seconds(seconds))
+}
+
+fn update_bounds(contracts: &mut TestContracts, expiry_bounds: ExpiryBounds) -> AnyResult<AppResponse> {
+    let owner_addr = contracts.app.api().addr_make(OWNER);
+    contracts.app.execute_contract(
+        owner_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::UpdateConfig {
+            registry_addr: None,
+            fee_bps: None,
+            withdrawal_delay: None,
+            expiry_bounds: Some(expiry_bounds),
+            keeper_reward_bps: None,
+        },
+        &[],
+    )
+}
+
+/// # Test: Time-Based Expiration
+///
+/// ## Test Steps:
+///
+/// 1. Lock two escrows expiring in 60 seconds
+/// 2. Release the first while the deadline has not passed, even after many blocks
+/// 3. Move the clock past the deadline without producing a block
+/// 4. Verify the second can no longer be released but can be refunded
+#[test]
+fn test_time_expiration() {
+    let mut contracts = setup_contracts_with_tool(0);
+
+    let expires = in_seconds(&contracts, 60);
+    lock_until(&mut contracts, expires).unwrap();
+    lock_until(&mut contracts, expires).unwrap();
+    let escrow: EscrowResponse = query_escrow(&contracts, 1).unwrap();
+    assert_eq!(expires, escrow.expires);
+
+    // Height alone never expires a time-based escrow
+    contracts.app.update_block(|block| block.height += 1_000);
+    release_funds(&mut contracts, 1, DEFAULT_USAGE_FEE, PROVIDER).unwrap();
+    assert!(refund_expired(&mut contracts, 2, USER).is_err());
+
+    contracts.app.update_block(|block| block.time = block.time.plus_seconds(61));
+    assert!(release_funds(&mut contracts, 2, DEFAULT_USAGE_FEE, PROVIDER).is_err());
+    refund_expired(&mut contracts, 2, USER).unwrap();
+    assert_eq!(EscrowStatus::Refunded, query_escrow(&contracts, 2).unwrap().status);
+}
+
+/// # Test: Rejecting Past Expirations
+///
+/// ## Test Steps:
+///
+/// 1. Try to lock with a height before the current block
+/// 2. Try to lock with a time before the current block time
+/// 3. Verify both are rejected, while the current height is still accepted
+#[test]
+fn test_past_expiration_rejected() {
+    let mut contracts = setup_contracts_with_tool(0);
+    let block = contracts.app.block_info();
+
+    expect_error(lock_until(&mut contracts, Expiration::AtHeight(block.height - 1)), |e| {
+        matches!(e, ContractError::ExpirationInPast {})
+    });
+    expect_error(lock_until(&mut contracts, Expiration::AtTime(block.time.minus_seconds(1))), |e| {
+        matches!(e, ContractError::ExpirationInPast {})
+    });
+
+    lock_until(&mut contracts, Expiration::AtHeight(block.height)).unwrap();
+}
+
+/// # Test: Default TTL Bounds
+///
+/// ## Test Steps:
+///
+/// 1. Verify the default bounds are reported by GetConfig and enforced for times
+/// 2. Verify bounds with a minimum above the maximum are rejected
+/// 3. Set new bounds as the owner
+/// 4. Verify TTLs below the minimum and above the maximum are rejected in both units
+#[test]
+fn test_default_bounds() {
+    let mut contracts = setup_contracts_with_tool(0);
+
+    let config: ConfigResponse = contracts.app.wrap()
+        .query_wasm_smart(&contracts.escrow_addr, &QueryMsg::GetConfig {})
+        .unwrap();
+    assert_eq!(300, config.expiry_bounds.max_seconds);
+    let expires = in_seconds(&contracts, 301);
+    expect_error(lock_until(&mut contracts, expires), |e| {
+        matches!(e, ContractError::ExpirationTooLong { max: 300, got: 301, .. })
+    });
+
+    let invalid = ExpiryBounds { min_blocks: 10, max_blocks: 5, min_seconds: 0, max_seconds: 60 };
+    expect_error(update_bounds(&mut contracts, invalid), |e| {
+        matches!(e, ContractError::InvalidExpiryBounds {})
+    });
+
+    update_bounds(&mut contracts, ExpiryBounds { min_blocks: 5, max_blocks: 20, min_seconds: 30, max_seconds: 120 })
+        .unwrap();
+
+    let expires = in_blocks(&contracts, 4);
+    expect_error(lock_until(&mut contracts, expires), |e| {
+        matches!(e, ContractError::ExpirationTooSoon { min: 5, got: 4, .. })
+    });
+    let expires = in_blocks(&contracts, 21);
+    expect_error(lock_until(&mut contracts, expires), |e| {
+        matches!(e, ContractError::ExpirationTooLong { max: 20, got: 21, .. })
+    });
+    let expires = in_seconds(&contracts, 29);
+    expect_error(lock_until(&mut contracts, expires), |e| {
+        matches!(e, ContractError::ExpirationTooSoon { min: 30, got: 29, .. })
+    });
+
+    let expires = in_blocks(&contracts, 20);
+    lock_until(&mut contracts, expires).unwrap();
+    let expires = in_seconds(&contracts, 120);
+    lock_until(&mut contracts, expires).unwrap();
+}
+
+/// # Test: Tool TTL Bounds
+///
+/// ## Test Steps:
+///
+/// 1. Verify a TTL of 100 blocks is above the default maximum
+/// 2. Set bounds of up to 200 blocks for the tool in the registry
+/// 3. Verify the same lock now succeeds
+#[test]
+fn test_tool_bounds_override_defaults() {
+    let mut contracts = setup_contracts_with_tool(0);
+
+    let expires = in_blocks(&contracts, 100);
+    expect_error(lock_until(&mut contracts, expires), |e| {
+        matches!(e, ContractError::ExpirationTooLong { max: 50, got: 100, .. })
+    });
+
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &RegistryExecuteMsg::UpdateExpiryBounds {
+            tool_id: DEFAULT_TOOL_ID.to_string(),
+            expiry_bounds: Some(RegistryExpiryBounds {
+                min_blocks: 0,
+                max_blocks: 200,
+                min_seconds: 0,
+                max_seconds: 1_200,
+            }),
+        },
+        &[],
+    ).unwrap();
+
+    lock_until(&mut contracts, expires).unwrap();
+}
@@ -7,7 +7,7 @@
 //!
 //! This test verifies that:
 //! 1. The contract correctly collects and distributes protocol fees to the owner
-//! 2. Fees are calculated as a percentage of the usage fee
+//! 2. Fees are calculated in basis points of the usage fee
 //! 3. Fees can be claimed by the contract owner
 //! 4. Multi-denomination (e.g., ATOM, NEUTRON) fee collection is supported
 //! 5. Unauthorized users cannot claim fees
@@ -20,7 +20,8 @@ use cosmwasm_std::{Addr, Coin, Uint128};
 use cw_multi_test::Executor;
 
 use crate::msg::{ExecuteMsg, CollectedFeesResponse, QueryMsg};
-use crate::tests::setup_contract::{NEUTRON, DEFAULT_MAX_FEE, DEFAULT_TTL, DEFAULT_TOOL_ID, OWNER, PROVIDER, USER, UNAUTHORIZED, setup_contracts_with_fee, register_tool, lock_funds, release_funds};
+use crate::state::Expiration;
+use crate::tests::setup_contract::{NEUTRON, DEFAULT_MAX_FEE, DEFAULT_TTL, DEFAULT_TOOL_ID, OWNER, PROVIDER, USER, UNAUTHORIZED, setup_contracts_with_fee, register_tool, lock_funds, release_funds, auth_commitment};
 
 // Define a secondary token for multi-denom tests
 pub const ATOM: &str = "uatom";
@@ -57,10 +58,10 @@ fn query_collected_fees(
 
 #[test]
 fn test_fee_collection_flow() {
-    let fee_percentage = 10; // 10% fee
+    let fee_bps = 1_000; // 10% fee
     
-    // Setup contracts with fee percentage
-    let mut contracts = setup_contracts_with_fee(fee_percentage);
+    // Setup contracts with a fee in basis points
+    let mut contracts = setup_contracts_with_fee(fee_bps);
     
     // Register a tool
     register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
@@ -86,10 +87,10 @@ fn test_fee_collection_flow() {
     let fees_response = query_collected_fees(&contracts).unwrap();
     
     // Calculate expected fee (10% of 100 = 10)
-    let expected_fee = Uint128::new(DEFAULT_MAX_FEE) * Uint128::new(fee_percentage as u128) / Uint128::new(100);
+    let expected_fee = Uint128::new(DEFAULT_MAX_FEE) * Uint128::new(fee_bps as u128) / Uint128::new(10_000);
     
-    // Verify fee percentage and owner address
-    assert_eq!(fees_response.fee_percentage, fee_percentage);
+    // Verify fee rate and owner address
+    assert_eq!(fees_response.fee_bps, fee_bps);
     
     // Get owner address
     let owner_addr = contracts.app.api().addr_make(OWNER);
@@ -122,9 +123,9 @@ fn test_fee_collection_flow() {
 
 #[test]
 fn test_unauthorized_claim_fees() {
-    // Setup contracts with fee percentage
-    let fee_percentage = 10;
-    let mut contracts = setup_contracts_with_fee(fee_percentage);
+    // Setup contracts with a fee in basis points
+    let fee_bps = 1_000;
+    let mut contracts = setup_contracts_with_fee(fee_bps);
     
     // Register a tool
     register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
@@ -157,10 +158,10 @@ fn test_unauthorized_claim_fees() {
 
 #[test]
 fn test_fee_calculation_multiple_releases() {
-    let fee_percentage = 5; // 5% fee
+    let fee_bps = 500; // 5% fee
     
-    // Setup contracts with fee percentage
-    let mut contracts = setup_contracts_with_fee(fee_percentage);
+    // Setup contracts with a fee in basis points
+    let mut contracts = setup_contracts_with_fee(fee_bps);
     
     // Register a tool
     register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
@@ -203,10 +204,10 @@ fn test_fee_calculation_multiple_releases() {
     let fees_response = query_collected_fees(&contracts).unwrap();
     
     // Calculate expected fee (5% of 100 * 2 = 10)
-    let expected_fee = Uint128::new(DEFAULT_MAX_FEE) * Uint128::new(fee_percentage as u128) / Uint128::new(100) * Uint128::new(2);
+    let expected_fee = Uint128::new(DEFAULT_MAX_FEE) * Uint128::new(fee_bps as u128) / Uint128::new(10_000) * Uint128::new(2);
     
-    // Verify fee percentage and owner address
-    assert_eq!(fees_response.fee_percentage, fee_percentage);
+    // Verify fee rate and owner address
+    assert_eq!(fees_response.fee_bps, fee_bps);
     
     // Verify collected fees
     assert_eq!(fees_response.collected_fees.len(), 1);
@@ -233,7 +234,7 @@ fn test_fee_calculation_multiple_releases() {
 }
 
 #[test]
-fn test_invalid_fee_percentage() {
+fn test_invalid_fee_bps() {
     // Setup app
     let mut app = cw_multi_test::App::default();
     
@@ -249,20 +250,22 @@ fn test_invalid_fee_percentage() {
         .instantiate_contract(
             registry_code_id,
             owner_addr.clone(),
-            &registry::msg::InstantiateMsg {},
+            &registry::msg::InstantiateMsg { admin: None },
             &[],
             "registry",
             None,
         )
         .unwrap();
     
-    // Try to instantiate escrow contract with invalid fee percentage (>100)
+    // Try to instantiate escrow contract with an invalid fee (>10000 bps)
     let result = app.instantiate_contract(
         escrow_code_id,
         owner_addr.clone(),
         &crate::msg::InstantiateMsg {
             registry_addr: registry_addr.to_string(),
-            fee_percentage: 101, // Invalid: >100%
+            fee_bps: 10_001, // Invalid: >100%
+            withdrawal_delay: None,
+            expiry_bounds: None,
         },
         &[],
         "escrow",
@@ -273,7 +276,7 @@ fn test_invalid_fee_percentage() {
     assert!(result.is_err());
     let err = result.err().unwrap();
     let err_string = format!("{:?}", err);
-    assert!(err_string.contains("Invalid fee percentage: must be between 0 and 100"));
+    assert!(err_string.contains("Invalid fee: must be between 0 and 10000 basis points"));
 }
 
 #[test]
@@ -348,15 +351,15 @@ fn test_specific_denom_claim() {
         .instantiate_contract(
             registry_code_id,
             owner_addr.clone(),
-            &registry::msg::InstantiateMsg {},
+            &registry::msg::InstantiateMsg { admin: None },
             &[],
             "registry",
             None,
         )
         .unwrap();
     
-    // Fee percentage for this test
-    let fee_percentage = 10; // 10% fee
+    // Fee rate for this test
+    let fee_bps = 1_000; // 10% fee
     
     // Instantiate escrow contract
     let escrow_addr = app
@@ -365,7 +368,9 @@ fn test_specific_denom_claim() {
             owner_addr.clone(),
             &crate::msg::InstantiateMsg {
                 registry_addr: registry_addr.to_string(),
-                fee_percentage,
+                fee_bps,
+                withdrawal_delay: None,
+                expiry_bounds: None,
             },
             &[],
             "escrow",
@@ -418,8 +423,10 @@ fn test_specific_denom_claim() {
         &ExecuteMsg::LockFunds {
             tool_id: "neutron-tool".to_string(),
             max_fee: Uint128::new(DEFAULT_MAX_FEE),
-            expires: current_height + DEFAULT_TTL,
-            auth_token: "neutron-token".to_string(),
+            expected_price: None,
+            expires: Expiration::AtHeight(current_height + DEFAULT_TTL),
+            from_deposit: None,
+            auth_commitment: auth_commitment("neutron-token"),
         },
         &[Coin {
             denom: NEUTRON.to_string(),
@@ -437,8 +444,10 @@ fn test_specific_denom_claim() {
         &ExecuteMsg::LockFunds {
             tool_id: "atom-tool".to_string(),
             max_fee: Uint128::new(DEFAULT_MAX_FEE),
-            expires: current_height + DEFAULT_TTL,
-            auth_token: "atom-token".to_string(),
+            expected_price: None,
+            expires: Expiration::AtHeight(current_height + DEFAULT_TTL),
+            from_deposit: None,
+            auth_commitment: auth_commitment("atom-token"),
         },
         &[Coin {
             denom: ATOM.to_string(),
@@ -481,7 +490,7 @@ fn test_specific_denom_claim() {
     assert_eq!(fees_response.collected_fees.len(), 2);
     
     // Calculate expected fee (10% of max fee)
-    let expected_fee = Uint128::new(DEFAULT_MAX_FEE) * Uint128::new(fee_percentage as u128) / Uint128::new(100);
+    let expected_fee = Uint128::new(DEFAULT_MAX_FEE) * Uint128::new(fee_bps as u128) / Uint128::new(10_000);
     
     // Check initial balances
     let initial_neutron_balance = contracts.app.wrap().query_balance(owner_addr.to_string(), NEUTRON).unwrap().amount;
@@ -593,8 +602,8 @@ fn extract_escrow_id_from_response(res: &cw_multi_test::AppResponse) -> u64 {
 
 #[test]
 fn test_partial_fee_usage() {
-    let fee_percentage = 10; // 10% fee
-    let mut contracts = setup_contracts_with_fee(fee_percentage);
+    let fee_bps = 1_000; // 10% fee
+    let mut contracts = setup_contracts_with_fee(fee_bps);
     
     // Register a tool
     register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
@@ -623,7 +632,7 @@ fn test_partial_fee_usage() {
     let fees_response = query_collected_fees(&contracts).unwrap();
     
     // Calculate expected fee (10% of 50 = 5)
-    let expected_fee = Uint128::new(usage_fee) * Uint128::new(fee_percentage as u128) / Uint128::new(100);
+    let expected_fee = Uint128::new(usage_fee) * Uint128::new(fee_bps as u128) / Uint128::new(10_000);
     
     // Verify collected fees
     assert_eq!(fees_response.collected_fees.len(), 1);
@@ -0,0 +1,139 @@
+//! # Fee Override Test
+//!
+//! This module tests basis-point fees and negotiated fee overrides in the Escrow contract.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Fractional percentages are supported and fees are rounded down
+//! 2. A provider override replaces the default fee for all of the provider's tools
+//! 3. A tool override takes precedence over a provider override
+//! 4. Only the owner can set overrides, and removing one restores the fallback rate
+
+use cosmwasm_std::{Coin, Uint128};
+
+use crate::error::ContractError;
+use crate::msg::{EffectiveFeeResponse, ExecuteMsg, FeeOverrideTarget, FeeSource, QueryMsg};
+use crate::tests::setup_contract::{
+    execute_as, lock_funds, query_escrow, register_tool, release_funds, setup_contracts_with_fee, TestContracts,
+    DEFAULT_TOOL_ID, DEFAULT_TTL, NEUTRON, OWNER, PROVIDER, UNAUTHORIZED, USER,
+};
+
+const PRICE: u128 = 1_000;
+
+fn set_override(contracts: &mut TestContracts, target: FeeOverrideTarget, fee_bps: Option<u64>) {
+    execute_as(contracts, OWNER, &ExecuteMsg::SetFeeOverride { target, fee_bps }, &[]).unwrap();
+}
+
+fn provider_target(contracts: &TestContracts) -> FeeOverrideTarget {
+    FeeOverrideTarget::Provider {
+        provider: contracts.app.api().addr_make(PROVIDER).to_string(),
+    }
+}
+
+fn tool_target() -> FeeOverrideTarget {
+    FeeOverrideTarget::Tool {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+    }
+}
+
+fn effective_fee(contracts: &TestContracts) -> EffectiveFeeResponse {
+    contracts.app.wrap().query_wasm_smart(
+        &contracts.escrow_addr,
+        &QueryMsg::GetEffectiveFee {
+            provider: contracts.app.api().addr_make(PROVIDER).to_string(),
+            tool_id: DEFAULT_TOOL_ID.to_string(),
+        },
+    ).unwrap()
+}
+
+// Locks and releases an escrow for `usage_fee`, returning the platform fee taken
+fn platform_fee_on(contracts: &mut TestContracts, usage_fee: u128) -> u128 {
+    let funds = vec![Coin {
+        denom: NEUTRON.to_string(),
+        amount: Uint128::new(PRICE),
+    }];
+    let escrow_id = lock_funds(contracts, DEFAULT_TOOL_ID, PRICE, DEFAULT_TTL, "fee_token".to_string(), USER, &funds)
+        .unwrap();
+    release_funds(contracts, escrow_id, usage_fee, PROVIDER).unwrap();
+    query_escrow(contracts, escrow_id).unwrap().platform_fee.u128()
+}
+
+fn setup_fees(fee_bps: u64) -> TestContracts {
+    let mut contracts = setup_contracts_with_fee(fee_bps);
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, PRICE, PROVIDER).unwrap();
+    contracts
+}
+
+/// # Test: Basis-Point Rounding
+///
+/// ## Test Steps:
+///
+/// 1. Setup the contracts with a 0.5% fee
+/// 2. Release 1000 and verify a fee of 5
+/// 3. Release 399 and verify the fee of 1.995 is rounded down to 1
+#[test]
+fn test_fractional_fee_rounds_down() {
+    let mut contracts = setup_fees(50);
+    assert_eq!(5, platform_fee_on(&mut contracts, 1_000));
+    assert_eq!(1, platform_fee_on(&mut contracts, 399));
+}
+
+/// # Test: Override Precedence
+///
+/// ## Test Steps:
+///
+/// 1. Setup the contracts with a 2.25% default fee and verify it applies
+/// 2. Set a 1% provider override and verify it applies
+/// 3. Set a 0.5% tool override and verify it wins over the provider override
+/// 4. Remove the tool override and verify the provider override applies again
+#[test]
+fn test_override_precedence() {
+    let mut contracts = setup_fees(225);
+    assert_eq!(EffectiveFeeResponse { fee_bps: 225, source: FeeSource::Default }, effective_fee(&contracts));
+    assert_eq!(22, platform_fee_on(&mut contracts, 1_000));
+
+    let provider = provider_target(&contracts);
+    set_override(&mut contracts, provider, Some(100));
+    assert_eq!(EffectiveFeeResponse { fee_bps: 100, source: FeeSource::Provider }, effective_fee(&contracts));
+    assert_eq!(10, platform_fee_on(&mut contracts, 1_000));
+
+    set_override(&mut contracts, tool_target(), Some(50));
+    assert_eq!(EffectiveFeeResponse { fee_bps: 50, source: FeeSource::Tool }, effective_fee(&contracts));
+    assert_eq!(5, platform_fee_on(&mut contracts, 1_000));
+
+    set_override(&mut contracts, tool_target(), None);
+    assert_eq!(FeeSource::Provider, effective_fee(&contracts).source);
+    assert_eq!(10, platform_fee_on(&mut contracts, 1_000));
+}
+
+/// # Test: Override Authorization and Validation
+///
+/// ## Test Steps:
+///
+/// 1. Verify a non-owner cannot set an override
+/// 2. Verify an override above 10000 basis points is rejected
+#[test]
+fn test_override_validation() {
+    let mut contracts = setup_fees(100);
+
+    let msg = ExecuteMsg::SetFeeOverride {
+        target: tool_target(),
+        fee_bps: Some(0),
+    };
+    match execute_as(&mut contracts, UNAUTHORIZED, &msg, &[]).unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::Unauthorized {}) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    let msg = ExecuteMsg::SetFeeOverride {
+        target: tool_target(),
+        fee_bps: Some(10_001),
+    };
+    match execute_as(&mut contracts, OWNER, &msg, &[]).unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::InvalidFeeBps(10_001)) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+}
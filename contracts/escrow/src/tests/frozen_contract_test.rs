@@ -1,36 +1,39 @@
 //! # Frozen Contract Test
 //! 
 //! This module tests that the Escrow contract properly rejects operations
-//! when the contract is in a frozen state.
+//! when the contract is frozen or individual operations are paused.
 //! 
 //! ## Test Coverage
 //! 
 //! This test verifies that:
-//! 1. When the contract is frozen via sudo, operations are rejected
-//! 2. LockFunds, Release, and RefundExpired cannot be executed when frozen
-//! 3. The correct error is returned for operations on a frozen contract
+//! 1. When the contract is frozen via sudo, LockFunds and Release are rejected
+//! 2. RefundExpired keeps working on a frozen contract
+//! 3. The correct error is returned for paused operations
+//! 4. Pause flags can be set individually by the owner or sudo, and lifted with Unfreeze
+//! 5. Callers can emergency-withdraw locked escrows only while releases are paused
 
 use cosmwasm_std::{Addr, Coin, Uint128, to_json_binary};
 use cw_multi_test::{Executor, SudoMsg as CwSudoMsg};
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, SudoMsg};
+use crate::msg::{ConfigResponse, ExecuteMsg, QueryMsg, SudoMsg};
+use crate::state::{EscrowStatus, Expiration, PauseFlags};
 use crate::tests::setup_contract::{
-    setup_contracts, register_tool, lock_funds, NEUTRON, DEFAULT_TOOL_ID, 
-    DEFAULT_MAX_FEE, USER, PROVIDER, DEFAULT_TTL,
+    setup_contracts, register_tool, lock_funds, auth_commitment, query_escrow, execute_as, TestContracts, NEUTRON,
+    DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, OWNER, USER, PROVIDER, UNAUTHORIZED, DEFAULT_TTL,
 };
 
 /// # Test: Contract Operations When Frozen
 /// 
-/// This test ensures that the Escrow contract correctly rejects all operations
-/// when the contract has been frozen by the admin.
+/// This test ensures that the Escrow contract correctly rejects locks and releases
+/// when the contract has been frozen by the admin, while refunds stay open.
 /// 
 /// ## Test Steps:
 /// 
 /// 1. Set up Registry and Escrow contracts
 /// 2. Register a tool and lock funds in an escrow
 /// 3. Freeze the contract using sudo
-/// 4. Try various operations (lock funds, release, refund)
-/// 5. Verify all operations fail with the correct error
+/// 4. Verify lock funds and release fail with the correct error
+/// 5. Verify the expired escrow can still be refunded
 #[test]
 fn test_frozen_contract() {
     // Set up the contracts
@@ -75,8 +78,10 @@ fn test_frozen_contract() {
         &ExecuteMsg::LockFunds {
             tool_id: DEFAULT_TOOL_ID.to_string(),
             max_fee: Uint128::new(DEFAULT_MAX_FEE),
-            expires: contracts.app.block_info().height + DEFAULT_TTL,
-            auth_token: "another_token".into(),
+            expected_price: None,
+            expires: Expiration::AtHeight(contracts.app.block_info().height + DEFAULT_TTL),
+            from_deposit: None,
+            auth_commitment: auth_commitment("another_token"),
         },
         &[Coin {
             denom: NEUTRON.to_string(),
@@ -84,10 +89,10 @@ fn test_frozen_contract() {
         }],
     );
     
-    // Verify operation failed with Frozen error
+    // Verify operation failed with Paused error
     assert!(result.is_err());
     match result.unwrap_err().downcast::<ContractError>() {
-        Ok(ContractError::Frozen {}) => {}, // Expected error
+        Ok(ContractError::Paused { .. }) => {}, // Expected error
         Ok(err) => panic!("Unexpected error: {:?}", err),
         Err(err) => panic!("Wrong error type: {:?}", err),
     }
@@ -104,10 +109,10 @@ fn test_frozen_contract() {
         &[],
     );
     
-    // Verify operation failed with Frozen error
+    // Verify operation failed with Paused error
     assert!(result.is_err());
     match result.unwrap_err().downcast::<ContractError>() {
-        Ok(ContractError::Frozen {}) => {}, // Expected error
+        Ok(ContractError::Paused { .. }) => {}, // Expected error
         Ok(err) => panic!("Unexpected error: {:?}", err),
         Err(err) => panic!("Wrong error type: {:?}", err),
     }
@@ -127,11 +132,122 @@ fn test_frozen_contract() {
         &[],
     );
     
-    // Verify operation failed with Frozen error
-    assert!(result.is_err());
-    match result.unwrap_err().downcast::<ContractError>() {
-        Ok(ContractError::Frozen {}) => {}, // Expected error
+    // Refunds are never paused
+    result.unwrap();
+}
+
+// Sends a sudo message to the escrow contract
+fn sudo(contracts: &mut TestContracts, msg: &SudoMsg) {
+    contracts.app.sudo(
+        CwSudoMsg::Wasm(cw_multi_test::WasmSudo {
+            contract_addr: Addr::unchecked(&contracts.escrow_addr),
+            message: to_json_binary(msg).unwrap(),
+        }),
+    ).unwrap();
+}
+
+// Executes a message on the escrow contract without funds
+// Locks DEFAULT_MAX_FEE for the default tool and returns the escrow ID
+fn lock_default(contracts: &mut TestContracts) -> Result<u64, Box<dyn std::error::Error>> {
+    lock_funds(
+        contracts,
+        DEFAULT_TOOL_ID,
+        DEFAULT_MAX_FEE,
+        DEFAULT_TTL,
+        "pause_token".to_string(),
+        USER,
+        &[Coin {
+            denom: NEUTRON.to_string(),
+            amount: Uint128::new(DEFAULT_MAX_FEE),
+        }],
+    )
+}
+
+fn query_paused(contracts: &TestContracts) -> PauseFlags {
+    let config: ConfigResponse = contracts.app.wrap()
+        .query_wasm_smart(&contracts.escrow_addr, &QueryMsg::GetConfig {})
+        .unwrap();
+    config.paused
+}
+
+/// # Test: Granular Pause Flags
+/// 
+/// ## Test Steps:
+/// 
+/// 1. Verify a non-owner cannot set pause flags
+/// 2. Pause only releases as the owner
+/// 3. Verify new escrows can still be locked but not released
+/// 4. Pause locks through sudo and verify locking fails
+/// 5. Unfreeze through sudo and verify every flag is cleared and releases work again
+#[test]
+fn test_granular_pause() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+
+    let pause_releases = ExecuteMsg::SetPause {
+        locks: None,
+        releases: Some(true),
+        fee_claims: None,
+    };
+    match execute_as(&mut contracts, UNAUTHORIZED, &pause_releases, &[]).unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::Unauthorized {}) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+    execute_as(&mut contracts, OWNER, &pause_releases, &[]).unwrap();
+    assert_eq!(PauseFlags { locks: false, releases: true, fee_claims: false }, query_paused(&contracts));
+
+    let escrow_id = lock_default(&mut contracts).unwrap();
+    let release = ExecuteMsg::Release {
+        escrow_id,
+        usage_fee: Uint128::new(DEFAULT_MAX_FEE),
+    };
+    match execute_as(&mut contracts, PROVIDER, &release, &[]).unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::Paused { operation }) => assert_eq!("releases", operation),
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    sudo(&mut contracts, &SudoMsg::SetPause { locks: Some(true), releases: None, fee_claims: None });
+    assert!(lock_default(&mut contracts).is_err());
+
+    sudo(&mut contracts, &SudoMsg::Unfreeze {});
+    assert_eq!(PauseFlags::default(), query_paused(&contracts));
+    execute_as(&mut contracts, PROVIDER, &release, &[]).unwrap();
+}
+
+/// # Test: Emergency Withdraw
+/// 
+/// ## Test Steps:
+/// 
+/// 1. Lock funds and verify the caller cannot emergency-withdraw while releases work
+/// 2. Freeze the contract
+/// 3. Verify only the caller can emergency-withdraw
+/// 4. Emergency-withdraw and verify the caller is refunded and the escrow is cancelled
+#[test]
+fn test_emergency_withdraw() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+    let escrow_id = lock_default(&mut contracts).unwrap();
+    let user_addr = contracts.app.api().addr_make(USER);
+    let balance_before = contracts.app.wrap().query_balance(&user_addr, NEUTRON).unwrap().amount;
+
+    let withdraw = ExecuteMsg::EmergencyWithdraw { escrow_id };
+    match execute_as(&mut contracts, USER, &withdraw, &[]).unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::EmergencyWithdrawUnavailable {}) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    sudo(&mut contracts, &SudoMsg::Freeze {});
+    match execute_as(&mut contracts, PROVIDER, &withdraw, &[]).unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::Unauthorized {}) => {}
         Ok(err) => panic!("Unexpected error: {:?}", err),
         Err(err) => panic!("Wrong error type: {:?}", err),
     }
+
+    execute_as(&mut contracts, USER, &withdraw, &[]).unwrap();
+    let balance_after = contracts.app.wrap().query_balance(&user_addr, NEUTRON).unwrap().amount;
+    assert_eq!(Uint128::new(DEFAULT_MAX_FEE), balance_after - balance_before);
+    assert_eq!(EscrowStatus::Cancelled, query_escrow(&contracts, escrow_id).unwrap().status);
 }
@@ -5,7 +5,7 @@
 use cosmwasm_std::{Coin, Uint128};
 use crate::msg::EscrowResponse;
 use super::setup_contract::{
-    setup_contracts, register_tool, lock_funds,
+    setup_contracts, register_tool, lock_funds, auth_commitment,
     NEUTRON, DEFAULT_MAX_FEE, DEFAULT_TTL, DEFAULT_TOOL_ID,
     PROVIDER, USER,
 };
@@ -48,7 +48,7 @@ fn test_lock_funds_success() {
     assert_eq!(escrow.caller, user_addr);
     assert_eq!(escrow.provider, provider_addr);
     assert_eq!(escrow.max_fee.u128(), DEFAULT_MAX_FEE);
-    assert_eq!(escrow.auth_token, auth_token);
+    assert_eq!(escrow.auth_commitment, auth_commitment(&auth_token));
     
     // Verify that funds were transferred from the user to the escrow contract
     let user_balance = contracts.app.wrap().query_balance(user_addr, NEUTRON).unwrap();
@@ -0,0 +1,262 @@
+//! # Migration Integration Test
+//!
+//! This module tests upgrading deployed Registry and Escrow contracts from the 0.1.0
+//! storage layout:
+//! 1. Instantiate both contracts and rewrite their storage in the 0.1.0 layout
+//! 2. Migrate both contracts to the current code
+//! 3. Verify migrated tools and escrows keep working
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. The migrate entry points upgrade 0.1.0 state and bump the stored cw2 version
+//! 2. Escrows locked under 0.1.0 can still be queried and released after migration
+//! 3. Registry tools stored under 0.1.0 are indexed and usable for new escrows
+//! 4. Downgrades and migrations from a different contract are refused
+
+use cosmwasm_std::{Addr, Coin, Empty, Uint128};
+use cw_multi_test::{App, Executor};
+use cw_storage_plus::Item;
+use registry::migrations::v0_1 as registry_v0_1;
+use registry::msg::{
+    InstantiateMsg as RegistryInstantiateMsg, MigrateMsg as RegistryMigrateMsg,
+    QueryMsg as RegistryQueryMsg, ToolsResponse,
+};
+
+use crate::error::ContractError;
+use crate::migrations::v0_1;
+use crate::msg::{CollectedFeesResponse, InstantiateMsg, MigrateMsg, QueryMsg, VerifyEscrowTokenResponse};
+use crate::tests::setup_contract::{
+    escrow_contract, lock_funds, mock_app, query_escrow, registry_contract, release_funds,
+    TestContracts, DEFAULT_MAX_FEE, DEFAULT_TTL, DEFAULT_USAGE_FEE, NEUTRON, OWNER, PROVIDER, USER,
+};
+
+const LEGACY_TOOL_ID: &str = "legacytool";
+const LEGACY_ESCROW_ID: u64 = 7;
+
+/// Instantiates both contracts with the owner as wasm admin and rewrites their
+/// storage as a 0.1.0 deployment would have left it
+fn setup_legacy_contracts() -> (TestContracts, u64, u64) {
+    let mut app: App = mock_app();
+    let registry_code_id = app.store_code(registry_contract());
+    let escrow_code_id = app.store_code(escrow_contract());
+    let owner = app.api().addr_make(OWNER);
+    let provider = app.api().addr_make(PROVIDER);
+    let user = app.api().addr_make(USER);
+
+    let registry_addr = app
+        .instantiate_contract(
+            registry_code_id,
+            owner.clone(),
+            &RegistryInstantiateMsg { admin: None },
+            &[],
+            "registry",
+            Some(owner.to_string()),
+        )
+        .unwrap();
+    let escrow_addr = app
+        .instantiate_contract(
+            escrow_code_id,
+            owner.clone(),
+            &InstantiateMsg {
+                registry_addr: registry_addr.to_string(),
+                fee_bps: 1_000,
+                withdrawal_delay: None,
+                expiry_bounds: None,
+            },
+            &[],
+            "escrow",
+            Some(owner.to_string()),
+        )
+        .unwrap();
+
+    // Registry 0.1.0 had no config and stored tools in a plain map
+    {
+        let mut storage = app.contract_storage_mut(&registry_addr);
+        Item::<Empty>::new("config").remove(storage.as_mut());
+        registry_v0_1::TOOLS
+            .save(storage.as_mut(), LEGACY_TOOL_ID, &registry_v0_1::ToolMeta {
+                provider: provider.clone(),
+                price: Uint128::new(DEFAULT_MAX_FEE),
+                denom: NEUTRON.to_string(),
+                is_active: true,
+                description: "Legacy tool".to_string(),
+                endpoint: "https://api.example.com/legacy".to_string(),
+            })
+            .unwrap();
+        cw2::set_contract_version(storage.as_mut(), "crates.io:registry", "0.1.0").unwrap();
+    }
+
+    // Escrow 0.1.0 with one open escrow whose funds are held by the contract
+    let expires = app.block_info().height + DEFAULT_TTL;
+    {
+        let mut storage = app.contract_storage_mut(&escrow_addr);
+        v0_1::CONFIG
+            .save(storage.as_mut(), &v0_1::Config {
+                frozen: false,
+                registry_addr: registry_addr.clone(),
+                owner: owner.clone(),
+                fee_percentage: 10,
+                collected_fees: vec![(NEUTRON.to_string(), Uint128::new(5))],
+            })
+            .unwrap();
+        v0_1::ESCROWS
+            .save(storage.as_mut(), LEGACY_ESCROW_ID, &v0_1::Escrow {
+                caller: user.clone(),
+                provider: provider.clone(),
+                max_fee: Uint128::new(DEFAULT_MAX_FEE),
+                denom: NEUTRON.to_string(),
+                auth_token: "legacy_token".to_string(),
+                expires,
+            })
+            .unwrap();
+        Item::<u64>::new("next_id").save(storage.as_mut(), &(LEGACY_ESCROW_ID + 1)).unwrap();
+        cw2::set_contract_version(storage.as_mut(), "crates.io:escrow", "0.1.0").unwrap();
+    }
+    app.send_tokens(
+        user,
+        escrow_addr.clone(),
+        &[Coin {
+            denom: NEUTRON.to_string(),
+            amount: Uint128::new(DEFAULT_MAX_FEE + 5),
+        }],
+    )
+    .unwrap();
+
+    let contracts = TestContracts {
+        app,
+        registry_addr: registry_addr.to_string(),
+        escrow_addr: escrow_addr.to_string(),
+    };
+    (contracts, registry_code_id, escrow_code_id)
+}
+
+/// # Test: Migrating Both Contracts from 0.1.0
+///
+/// ## Test Steps:
+///
+/// 1. Set up contracts holding 0.1.0 state
+/// 2. Migrate the registry and the escrow to the current code
+/// 3. Verify stored versions, indexes, config and the legacy escrow
+/// 4. Release the legacy escrow and lock a new escrow for the legacy tool
+#[test]
+fn test_migrate_from_v0_1() {
+    let (mut contracts, registry_code_id, escrow_code_id) = setup_legacy_contracts();
+    let owner = contracts.app.api().addr_make(OWNER);
+    let provider = contracts.app.api().addr_make(PROVIDER);
+    let registry_addr = Addr::unchecked(&contracts.registry_addr);
+    let escrow_addr = Addr::unchecked(&contracts.escrow_addr);
+
+    contracts.app
+        .migrate_contract(
+            owner.clone(),
+            registry_addr.clone(),
+            &RegistryMigrateMsg { admin: Some(owner.to_string()) },
+            registry_code_id,
+        )
+        .unwrap();
+    contracts.app
+        .migrate_contract(owner.clone(), escrow_addr.clone(), &MigrateMsg {}, escrow_code_id)
+        .unwrap();
+
+    let registry_version = cw2::query_contract_info(&contracts.app.wrap(), registry_addr.to_string()).unwrap();
+    assert_eq!("0.2.0", registry_version.version);
+    let escrow_version = cw2::query_contract_info(&contracts.app.wrap(), escrow_addr.to_string()).unwrap();
+    assert_eq!(env!("CARGO_PKG_VERSION"), escrow_version.version);
+
+    // Legacy tools are now reachable through the provider index
+    let tools: ToolsResponse = contracts.app
+        .wrap()
+        .query_wasm_smart(
+            &registry_addr,
+            &RegistryQueryMsg::ListToolsByProvider {
+                provider: provider.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(1, tools.tools.len());
+    assert_eq!(LEGACY_TOOL_ID, tools.tools[0].tool_id);
+
+    // Escrow config and the open escrow survived the migration
+    let fees: CollectedFeesResponse = contracts.app
+        .wrap()
+        .query_wasm_smart(&escrow_addr, &QueryMsg::GetCollectedFees {})
+        .unwrap();
+    assert_eq!(owner, fees.owner);
+    // The legacy 10% fee is now expressed in basis points
+    assert_eq!(1_000, fees.fee_bps);
+    assert_eq!(vec![(NEUTRON.to_string(), Uint128::new(5))], fees.collected_fees);
+
+    let escrow = query_escrow(&contracts, LEGACY_ESCROW_ID).unwrap();
+    assert_eq!(provider, escrow.provider);
+    assert_eq!(Uint128::new(DEFAULT_MAX_FEE), escrow.max_fee);
+    assert_eq!("", escrow.tool_id);
+
+    // Legacy plaintext tokens were replaced by their commitment
+    let verified: VerifyEscrowTokenResponse = contracts.app
+        .wrap()
+        .query_wasm_smart(
+            &escrow_addr,
+            &QueryMsg::VerifyEscrowToken { escrow_id: LEGACY_ESCROW_ID, token: "legacy_token".to_string() },
+        )
+        .unwrap();
+    assert!(verified.valid);
+    assert_eq!(crate::tests::setup_contract::auth_commitment("legacy_token"), escrow.auth_commitment);
+
+    let before = contracts.app.wrap().query_balance(provider.to_string(), NEUTRON).unwrap().amount;
+    release_funds(&mut contracts, LEGACY_ESCROW_ID, DEFAULT_USAGE_FEE, PROVIDER).unwrap();
+    let after = contracts.app.wrap().query_balance(provider.to_string(), NEUTRON).unwrap().amount;
+    assert_eq!(before + Uint128::new(DEFAULT_USAGE_FEE - DEFAULT_USAGE_FEE / 10), after);
+
+    // New escrows continue from the stored counter
+    let escrow_id = lock_funds(
+        &mut contracts,
+        LEGACY_TOOL_ID,
+        DEFAULT_MAX_FEE,
+        DEFAULT_TTL,
+        "new_token".to_string(),
+        USER,
+        &[Coin {
+            denom: NEUTRON.to_string(),
+            amount: Uint128::new(DEFAULT_MAX_FEE),
+        }],
+    )
+    .unwrap();
+    assert_eq!(LEGACY_ESCROW_ID + 1, escrow_id);
+    assert_eq!(LEGACY_TOOL_ID, query_escrow(&contracts, escrow_id).unwrap().tool_id);
+}
+
+/// # Test: Migration Refuses Downgrades and Foreign State
+///
+/// ## Test Steps:
+///
+/// 1. Store a newer version in the escrow and verify migrate fails
+/// 2. Store another contract name in the escrow and verify migrate fails
+#[test]
+fn test_migrate_rejects_downgrade_and_wrong_contract() {
+    let (mut contracts, _, escrow_code_id) = setup_legacy_contracts();
+    let owner = contracts.app.api().addr_make(OWNER);
+    let escrow_addr = Addr::unchecked(&contracts.escrow_addr);
+
+    cw2::set_contract_version(contracts.app.contract_storage_mut(&escrow_addr).as_mut(), "crates.io:escrow", "9.0.0").unwrap();
+    let err = contracts.app
+        .migrate_contract(owner.clone(), escrow_addr.clone(), &MigrateMsg {}, escrow_code_id)
+        .unwrap_err();
+    match err.downcast::<ContractError>() {
+        Ok(ContractError::CannotDowngrade { from, .. }) => assert_eq!("9.0.0", from),
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    cw2::set_contract_version(contracts.app.contract_storage_mut(&escrow_addr).as_mut(), "crates.io:registry", "0.1.0").unwrap();
+    let err = contracts.app
+        .migrate_contract(owner, escrow_addr, &MigrateMsg {}, escrow_code_id)
+        .unwrap_err();
+    match err.downcast::<ContractError>() {
+        Ok(ContractError::InvalidContractName { found, .. }) => assert_eq!("crates.io:registry", found),
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+}
@@ -18,4 +18,24 @@ mod non_expired_refund_test;
 mod frozen_contract_test;
 mod multi_denom_test;
 mod fee_collection_test;
+mod tool_transfer_test;
+mod migrate_test;
+mod price_enforcement_test;
+mod query_by_tool_test;
+mod escrow_pagination_test;
+mod receipt_test;
+mod verify_token_test;
+mod verify_escrow_test;
+mod charge_test;
+mod deposit_test;
+mod channel_test;
+mod subscription_test;
+mod cw20_test;
+mod config_test;
+mod fee_override_test;
+mod unaccounted_test;
+mod expiry_test;
+mod sweep_test;
+mod release_batch_test;
+mod cancel_escrow_test;
 // Import individual test modules below as they're implemented
@@ -15,7 +15,8 @@ use cosmwasm_std::{coins, Addr, Coin, Uint128};
 use cw_multi_test::Executor;
 use registry::msg::ExecuteMsg as RegistryExecuteMsg;
 
-use crate::tests::setup_contract::{TestContracts, setup_contracts, PROVIDER, USER};
+use crate::state::Expiration;
+use crate::tests::setup_contract::{TestContracts, setup_contracts, auth_commitment, PROVIDER, USER};
 
 const NATIVE_DENOM: &str = "untrn";
 const IBC_DENOM: &str = "ibc/ABCDEF0123456789";
@@ -264,7 +265,7 @@ fn lock_funds_with_denom(contracts: &mut TestContracts, denom: &str) -> Result<u
     
     let env = mock_env();
     let block_time = env.block.height;
-    let expires = block_time + 10;
+    let expires = Expiration::AtHeight(block_time + 10);
     
     let user_addr = Addr::unchecked(contracts.app.api().addr_make(USER));
     match contracts.app.execute_contract(
@@ -273,8 +274,10 @@ fn lock_funds_with_denom(contracts: &mut TestContracts, denom: &str) -> Result<u
         &crate::msg::ExecuteMsg::LockFunds {
             tool_id: TOOL_ID.to_string(),
             max_fee: Uint128::new(MAX_FEE),
-            auth_token: AUTH_TOKEN.to_string(),
+            expected_price: None,
+            auth_commitment: auth_commitment(AUTH_TOKEN),
             expires,
+            from_deposit: None,
         },
         &coins(MAX_FEE, denom),
     ) {
@@ -299,7 +302,7 @@ fn lock_funds_custom_tool(contracts: &mut TestContracts, denom: &str, tool_id: &
     
     let env = mock_env();
     let block_time = env.block.height;
-    let expires = block_time + 10;
+    let expires = Expiration::AtHeight(block_time + 10);
     
     let user_addr = Addr::unchecked(contracts.app.api().addr_make(USER));
     match contracts.app.execute_contract(
@@ -308,8 +311,10 @@ fn lock_funds_custom_tool(contracts: &mut TestContracts, denom: &str, tool_id: &
         &crate::msg::ExecuteMsg::LockFunds {
             tool_id: tool_id.to_string(),
             max_fee: Uint128::new(MAX_FEE),
-            auth_token: AUTH_TOKEN.to_string(),
+            expected_price: None,
+            auth_commitment: auth_commitment(AUTH_TOKEN),
             expires,
+            from_deposit: None,
         },
         &coins(MAX_FEE, denom),
     ) {
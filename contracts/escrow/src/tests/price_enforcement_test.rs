@@ -0,0 +1,145 @@
+//! # Price Enforcement Test
+//!
+//! This module tests that the Escrow contract enforces the Registry price of a tool.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Locks with a max_fee below the tool price are rejected
+//! 2. Locks with an expected_price that differs from the current price are rejected
+//! 3. The price at lock time is stored on the escrow
+//! 4. Release cannot charge more than the price recorded at lock time, even after a price change
+
+use cosmwasm_std::{Addr, Coin, Uint128};
+use cw_multi_test::Executor;
+use registry::msg::ExecuteMsg as RegistryExecuteMsg;
+
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+use crate::state::Expiration;
+use crate::tests::setup_contract::{
+    auth_commitment, query_escrow, register_tool, release_funds, setup_contracts, DEFAULT_MAX_FEE, DEFAULT_TOOL_ID,
+    DEFAULT_TTL, NEUTRON, PROVIDER, USER,
+};
+
+const PRICE: u128 = 60;
+
+fn lock_msg(contracts: &cw_multi_test::App, max_fee: u128, expected_price: Option<u128>) -> ExecuteMsg {
+    ExecuteMsg::LockFunds {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+        max_fee: Uint128::new(max_fee),
+        expected_price: expected_price.map(Uint128::new),
+        auth_commitment: auth_commitment("price_test"),
+        expires: Expiration::AtHeight(contracts.block_info().height + DEFAULT_TTL),
+        from_deposit: None,
+    }
+}
+
+/// # Test: Locking Below the Tool Price or at a Stale Quote
+///
+/// ## Test Steps:
+///
+/// 1. Register a tool priced at 60
+/// 2. Attempt to lock with a max_fee of 59 and verify MaxFeeBelowPrice
+/// 3. Attempt to lock with an expected_price of 50 and verify PriceChanged
+/// 4. Lock with the matching expected_price and verify the stored price
+#[test]
+fn test_lock_requires_tool_price() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, PRICE, PROVIDER).unwrap();
+    let user_addr = contracts.app.api().addr_make(USER);
+    let funds = [Coin {
+        denom: NEUTRON.to_string(),
+        amount: Uint128::new(DEFAULT_MAX_FEE),
+    }];
+
+    let msg = lock_msg(&contracts.app, PRICE - 1, None);
+    let result = contracts.app.execute_contract(user_addr.clone(), Addr::unchecked(&contracts.escrow_addr), &msg, &funds);
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::MaxFeeBelowPrice { price, max_fee }) => {
+            assert_eq!(PRICE.to_string(), price);
+            assert_eq!((PRICE - 1).to_string(), max_fee);
+        }
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    let msg = lock_msg(&contracts.app, DEFAULT_MAX_FEE, Some(50));
+    let result = contracts.app.execute_contract(user_addr.clone(), Addr::unchecked(&contracts.escrow_addr), &msg, &funds);
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::PriceChanged { expected, current }) => {
+            assert_eq!("50", expected);
+            assert_eq!(PRICE.to_string(), current);
+        }
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    let msg = lock_msg(&contracts.app, DEFAULT_MAX_FEE, Some(PRICE));
+    contracts.app.execute_contract(user_addr, Addr::unchecked(&contracts.escrow_addr), &msg, &funds).unwrap();
+
+    let escrow = query_escrow(&contracts, 1).unwrap();
+    assert_eq!(Uint128::new(PRICE), escrow.price);
+    assert_eq!(Uint128::new(DEFAULT_MAX_FEE), escrow.max_fee);
+}
+
+/// # Test: Release Is Bounded by the Price at Lock Time
+///
+/// ## Test Steps:
+///
+/// 1. Register a tool priced at 60 and lock 100
+/// 2. Raise the tool price in the Registry to 100
+/// 3. Attempt to release 61 and verify FeeExceedsPrice
+/// 4. Release exactly the locked price and verify the caller is refunded the rest
+#[test]
+fn test_release_bounded_by_locked_price() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, PRICE, PROVIDER).unwrap();
+    let user_addr = contracts.app.api().addr_make(USER);
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+
+    let msg = lock_msg(&contracts.app, DEFAULT_MAX_FEE, None);
+    contracts.app.execute_contract(
+        user_addr.clone(),
+        Addr::unchecked(&contracts.escrow_addr),
+        &msg,
+        &[Coin {
+            denom: NEUTRON.to_string(),
+            amount: Uint128::new(DEFAULT_MAX_FEE),
+        }],
+    ).unwrap();
+
+    // A later price increase does not apply to the existing escrow
+    contracts.app.execute_contract(
+        provider_addr.clone(),
+        Addr::unchecked(&contracts.registry_addr),
+        &RegistryExecuteMsg::UpdatePrice {
+            tool_id: DEFAULT_TOOL_ID.to_string(),
+            price: Uint128::new(DEFAULT_MAX_FEE),
+        },
+        &[],
+    ).unwrap();
+
+    let result = contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::Release {
+            escrow_id: 1,
+            usage_fee: Uint128::new(PRICE + 1),
+        },
+        &[],
+    );
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::FeeExceedsPrice { price, requested_fee }) => {
+            assert_eq!(PRICE.to_string(), price);
+            assert_eq!((PRICE + 1).to_string(), requested_fee);
+        }
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    let before = contracts.app.wrap().query_balance(user_addr.to_string(), NEUTRON).unwrap().amount;
+    release_funds(&mut contracts, 1, PRICE, PROVIDER).unwrap();
+    let after = contracts.app.wrap().query_balance(user_addr.to_string(), NEUTRON).unwrap().amount;
+    assert_eq!(before + Uint128::new(DEFAULT_MAX_FEE - PRICE), after);
+}
@@ -0,0 +1,92 @@
+//! # Escrows by Tool Query Test
+//!
+//! This module tests that escrows record the tool they were locked for and can be
+//! listed per tool.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. The tool ID passed to LockFunds is stored and returned by GetEscrow
+//! 2. GetEscrows with a tool_id filter only returns escrows for that tool
+//! 3. The tool filter combines with pagination and the caller filter
+
+use cosmwasm_std::{Coin, Uint128};
+
+use crate::msg::{EscrowsResponse, QueryMsg};
+use crate::tests::setup_contract::{
+    lock_funds, query_escrow, register_tool, setup_contracts, TestContracts, DEFAULT_MAX_FEE,
+    DEFAULT_TTL, NEUTRON, PROVIDER, USER,
+};
+
+const TOOL_A: &str = "tool_a";
+const TOOL_B: &str = "tool_b";
+
+fn query_by_tool(
+    contracts: &TestContracts,
+    tool_id: &str,
+    caller: Option<String>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Vec<u64> {
+    let res: EscrowsResponse = contracts.app.wrap().query_wasm_smart(
+        &contracts.escrow_addr,
+        &QueryMsg::GetEscrows {
+            caller,
+            provider: None,
+            tool_id: Some(tool_id.to_string()),
+            status: None,
+            start_after,
+            limit,
+            descending: None,
+        },
+    ).unwrap();
+    res.escrows.into_iter().map(|e| e.escrow_id).collect()
+}
+
+/// # Test: Filtering Escrows by Tool
+///
+/// ## Test Steps:
+///
+/// 1. Register two tools from the same provider
+/// 2. Lock escrows alternating between the tools
+/// 3. Verify GetEscrow returns the tool ID
+/// 4. Verify GetEscrows filtered by each tool, with and without pagination
+#[test]
+fn test_query_escrows_by_tool() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, TOOL_A, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+    register_tool(&mut contracts, TOOL_B, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+
+    let funds = [Coin {
+        denom: NEUTRON.to_string(),
+        amount: Uint128::new(DEFAULT_MAX_FEE),
+    }];
+    for (i, tool_id) in [TOOL_A, TOOL_B, TOOL_A, TOOL_B, TOOL_A].iter().enumerate() {
+        lock_funds(
+            &mut contracts,
+            tool_id,
+            DEFAULT_MAX_FEE,
+            DEFAULT_TTL,
+            format!("tool_token_{}", i),
+            USER,
+            &funds,
+        ).unwrap();
+    }
+
+    assert_eq!(TOOL_A, query_escrow(&contracts, 1).unwrap().tool_id);
+    assert_eq!(TOOL_B, query_escrow(&contracts, 2).unwrap().tool_id);
+
+    assert_eq!(vec![1, 3, 5], query_by_tool(&contracts, TOOL_A, None, None, None));
+    assert_eq!(vec![2, 4], query_by_tool(&contracts, TOOL_B, None, None, None));
+    assert!(query_by_tool(&contracts, "unknown", None, None, None).is_empty());
+
+    // Pagination continues within the tool
+    assert_eq!(vec![1, 3], query_by_tool(&contracts, TOOL_A, None, None, Some(2)));
+    assert_eq!(vec![5], query_by_tool(&contracts, TOOL_A, None, Some(3), Some(2)));
+
+    // The tool filter combines with the caller filter
+    let user_addr = contracts.app.api().addr_make(USER);
+    let other_addr = contracts.app.api().addr_make("other");
+    assert_eq!(vec![2, 4], query_by_tool(&contracts, TOOL_B, Some(user_addr.to_string()), None, None));
+    assert!(query_by_tool(&contracts, TOOL_B, Some(other_addr.to_string()), None, None).is_empty());
+}
@@ -14,8 +14,9 @@
 
 use cosmwasm_std::{Coin, Uint128};
 use crate::msg::{EscrowResponse, QueryMsg};
+use crate::state::Expiration;
 use crate::tests::setup_contract::{
-    setup_contracts, register_tool, lock_funds, NEUTRON, DEFAULT_TOOL_ID,
+    setup_contracts, register_tool, lock_funds, auth_commitment, NEUTRON, DEFAULT_TOOL_ID,
     PROVIDER, USER, DEFAULT_MAX_FEE, DEFAULT_TTL,
 };
 
@@ -48,7 +49,7 @@ fn test_query_endpoints() {
     let auth_token = "query_test_auth".to_string();
     let auth_token_str = auth_token.clone(); // No conversion needed, already a String
     let current_height = contracts.app.block_info().height;
-    let expires = current_height + DEFAULT_TTL;
+    let expires = Expiration::AtHeight(current_height + DEFAULT_TTL);
     
     let escrow_id = lock_funds(
         &mut contracts,
@@ -83,7 +84,7 @@ fn test_query_endpoints() {
     assert_eq!(expected_provider_address.as_str(), query_res.provider.as_str());
     assert_eq!(Uint128::new(DEFAULT_MAX_FEE), query_res.max_fee);
     assert_eq!(expires, query_res.expires);
-    assert_eq!(auth_token_str, query_res.auth_token);
+    assert_eq!(auth_commitment(&auth_token_str), query_res.auth_commitment);
 }
 
 /// # Test: GetEscrows Query Returns Multiple Escrows
@@ -153,8 +154,11 @@ fn test_get_escrows_query() {
             &QueryMsg::GetEscrows {
                 caller: None,
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -173,8 +177,11 @@ fn test_get_escrows_query() {
             &QueryMsg::GetEscrows {
                 caller: Some(user_address.to_string()),
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -183,7 +190,7 @@ fn test_get_escrows_query() {
     assert_eq!(1, user_escrows.escrows.len());
     assert_eq!(escrow_id1, user_escrows.escrows[0].escrow_id);
     assert_eq!(user_address, user_escrows.escrows[0].caller);
-    assert_eq!(auth_token1, user_escrows.escrows[0].auth_token);
+    assert_eq!(auth_commitment(&auth_token1), user_escrows.escrows[0].auth_commitment);
     
     // Step 5: Query escrows filtered by provider
     let provider_address = contracts.app.api().addr_make(PROVIDER);
@@ -194,8 +201,11 @@ fn test_get_escrows_query() {
             &QueryMsg::GetEscrows {
                 caller: None,
                 provider: Some(provider_address.to_string()),
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -211,8 +221,11 @@ fn test_get_escrows_query() {
             &QueryMsg::GetEscrows {
                 caller: None,
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: Some(1),
+                descending: None,
             },
         )
         .unwrap();
@@ -307,8 +320,11 @@ fn test_get_escrows_advanced_filtering_pagination() {
             &QueryMsg::GetEscrows {
                 caller: Some(user_address.to_string()),
                 provider: Some(provider2_address.to_string()),
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -327,8 +343,11 @@ fn test_get_escrows_advanced_filtering_pagination() {
             &QueryMsg::GetEscrows {
                 caller: None,
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: Some(escrow_id1), // Start after first escrow
                 limit: Some(2),
+                descending: None,
             },
         )
         .unwrap();
@@ -346,8 +365,11 @@ fn test_get_escrows_advanced_filtering_pagination() {
             &QueryMsg::GetEscrows {
                 caller: Some(user_address.to_string()),
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: Some(escrow_id1), // Start after first escrow
                 limit: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -398,8 +420,11 @@ fn test_get_escrows_edge_cases() {
             &QueryMsg::GetEscrows {
                 caller: None,
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: Some(0),
+                descending: None,
             },
         )
         .unwrap();
@@ -414,8 +439,11 @@ fn test_get_escrows_edge_cases() {
             &QueryMsg::GetEscrows {
                 caller: None,
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: Some(1000), // Very large limit
+                descending: None,
             },
         )
         .unwrap();
@@ -432,8 +460,11 @@ fn test_get_escrows_edge_cases() {
             &QueryMsg::GetEscrows {
                 caller: None,
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: Some(9999), // Non-existent escrow ID
                 limit: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -448,8 +479,11 @@ fn test_get_escrows_edge_cases() {
             &QueryMsg::GetEscrows {
                 caller: None,
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: Some(escrow_id), // Start after the only escrow
                 limit: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -483,8 +517,11 @@ fn test_get_escrows_empty_results() {
             &QueryMsg::GetEscrows {
                 caller: None,
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -514,8 +551,11 @@ fn test_get_escrows_empty_results() {
             &QueryMsg::GetEscrows {
                 caller: Some(contracts.app.api().addr_make("nonexistent").to_string()),
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -530,8 +570,11 @@ fn test_get_escrows_empty_results() {
             &QueryMsg::GetEscrows {
                 caller: None,
                 provider: Some(contracts.app.api().addr_make("nonexistent_provider").to_string()),
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -546,8 +589,11 @@ fn test_get_escrows_empty_results() {
             &QueryMsg::GetEscrows {
                 caller: Some(contracts.app.api().addr_make("nonexistent").to_string()),
                 provider: Some(contracts.app.api().addr_make("nonexistent_provider").to_string()),
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -579,8 +625,11 @@ fn test_get_escrows_invalid_parameters() {
             &QueryMsg::GetEscrows {
                 caller: Some("invalid_address_format".to_string()),
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: None,
+                descending: None,
             },
         );
     
@@ -595,8 +644,11 @@ fn test_get_escrows_invalid_parameters() {
             &QueryMsg::GetEscrows {
                 caller: None,
                 provider: Some("invalid_provider_format".to_string()),
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: None,
+                descending: None,
             },
         );
     
@@ -611,8 +663,11 @@ fn test_get_escrows_invalid_parameters() {
             &QueryMsg::GetEscrows {
                 caller: Some("invalid_caller".to_string()),
                 provider: Some("invalid_provider".to_string()),
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: None,
+                descending: None,
             },
         );
     
@@ -645,7 +700,7 @@ fn test_get_escrows_data_integrity() {
     
     // Create first escrow and capture its expiration time
     let current_height1 = contracts.app.block_info().height;
-    let expires1 = current_height1 + DEFAULT_TTL;
+    let expires1 = Expiration::AtHeight(current_height1 + DEFAULT_TTL);
     let escrow_id1 = lock_funds(
         &mut contracts,
         DEFAULT_TOOL_ID,
@@ -664,7 +719,7 @@ fn test_get_escrows_data_integrity() {
     
     // Create second escrow and capture its expiration time
     let current_height2 = contracts.app.block_info().height;
-    let expires2 = current_height2 + DEFAULT_TTL + 5;
+    let expires2 = Expiration::AtHeight(current_height2 + DEFAULT_TTL + 5);
     let escrow_id2 = lock_funds(
         &mut contracts,
         DEFAULT_TOOL_ID,
@@ -683,7 +738,7 @@ fn test_get_escrows_data_integrity() {
     
     // Create third escrow and capture its expiration time
     let current_height3 = contracts.app.block_info().height;
-    let expires3 = current_height3 + DEFAULT_TTL + 10;
+    let expires3 = Expiration::AtHeight(current_height3 + DEFAULT_TTL + 10);
     let escrow_id3 = lock_funds(
         &mut contracts,
         DEFAULT_TOOL_ID,
@@ -705,8 +760,11 @@ fn test_get_escrows_data_integrity() {
             &QueryMsg::GetEscrows {
                 caller: None,
                 provider: None,
+                tool_id: None,
+                status: None,
                 start_after: None,
                 limit: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -729,7 +787,7 @@ fn test_get_escrows_data_integrity() {
     assert_eq!(Uint128::new(DEFAULT_MAX_FEE), all_escrows.escrows[0].max_fee);
     assert_eq!(NEUTRON, all_escrows.escrows[0].denom);
     assert_eq!(expires1, all_escrows.escrows[0].expires);
-    assert_eq!(auth_token1, all_escrows.escrows[0].auth_token);
+    assert_eq!(auth_commitment(&auth_token1), all_escrows.escrows[0].auth_commitment);
     
     // Check second escrow
     assert_eq!(user_address, all_escrows.escrows[1].caller);
@@ -737,7 +795,7 @@ fn test_get_escrows_data_integrity() {
     assert_eq!(Uint128::new(DEFAULT_MAX_FEE + 100), all_escrows.escrows[1].max_fee);
     assert_eq!(NEUTRON, all_escrows.escrows[1].denom);
     assert_eq!(expires2, all_escrows.escrows[1].expires);
-    assert_eq!(auth_token2, all_escrows.escrows[1].auth_token);
+    assert_eq!(auth_commitment(&auth_token2), all_escrows.escrows[1].auth_commitment);
     
     // Check third escrow
     assert_eq!(user_address, all_escrows.escrows[2].caller);
@@ -745,5 +803,5 @@ fn test_get_escrows_data_integrity() {
     assert_eq!(Uint128::new(DEFAULT_MAX_FEE + 200), all_escrows.escrows[2].max_fee);
     assert_eq!(NEUTRON, all_escrows.escrows[2].denom);
     assert_eq!(expires3, all_escrows.escrows[2].expires);
-    assert_eq!(auth_token3, all_escrows.escrows[2].auth_token);
+    assert_eq!(auth_commitment(&auth_token3), all_escrows.escrows[2].auth_commitment);
 }
@@ -0,0 +1,173 @@
+//! # Escrow Receipt Test
+//!
+//! This module tests that settled escrows are kept as receipts.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Released and refunded escrows stay queryable with their settlement amounts and height
+//! 2. Settled escrows cannot be released or refunded again
+//! 3. GetReceipt refuses escrows that are still locked
+//! 4. GetEscrows can filter by status
+//! 5. Only the owner can prune receipts, and locked escrows can never be pruned
+
+use cosmwasm_std::{Addr, Coin, Uint128};
+use cw_multi_test::Executor;
+
+use crate::error::ContractError;
+use crate::msg::{EscrowsResponse, ExecuteMsg, QueryMsg, ReceiptResponse};
+use crate::state::EscrowStatus;
+use crate::tests::setup_contract::{
+    lock_funds, query_escrow, refund_expired, register_tool, release_funds, setup_contracts_with_fee,
+    TestContracts, DEFAULT_MAX_FEE, DEFAULT_TOOL_ID, DEFAULT_TTL, DEFAULT_USAGE_FEE, NEUTRON, OWNER,
+    PROVIDER, USER,
+};
+
+fn query_receipt(contracts: &TestContracts, escrow_id: u64) -> cosmwasm_std::StdResult<ReceiptResponse> {
+    contracts.app.wrap().query_wasm_smart(&contracts.escrow_addr, &QueryMsg::GetReceipt { escrow_id })
+}
+
+// Registers the default tool with a 10% platform fee and locks three escrows
+fn setup_escrows() -> TestContracts {
+    let mut contracts = setup_contracts_with_fee(1_000);
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+    for i in 0..3 {
+        lock_funds(
+            &mut contracts,
+            DEFAULT_TOOL_ID,
+            DEFAULT_MAX_FEE,
+            DEFAULT_TTL,
+            format!("receipt_token_{}", i),
+            USER,
+            &[Coin {
+                denom: NEUTRON.to_string(),
+                amount: Uint128::new(DEFAULT_MAX_FEE),
+            }],
+        ).unwrap();
+    }
+    contracts
+}
+
+/// # Test: Released and Refunded Escrows Become Receipts
+///
+/// ## Test Steps:
+///
+/// 1. Lock three escrows
+/// 2. Verify GetReceipt fails while they are locked
+/// 3. Release escrow 1 and verify its receipt and that a second release fails
+/// 4. Let escrow 2 expire, refund it and verify its receipt
+/// 5. Filter GetEscrows by status
+#[test]
+fn test_settled_escrows_keep_receipts() {
+    let mut contracts = setup_escrows();
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+
+    assert!(query_receipt(&contracts, 1).is_err());
+
+    let release_height = contracts.app.block_info().height;
+    release_funds(&mut contracts, 1, DEFAULT_USAGE_FEE, PROVIDER).unwrap();
+
+    let receipt = query_receipt(&contracts, 1).unwrap();
+    assert_eq!(EscrowStatus::Released, receipt.status);
+    assert_eq!(DEFAULT_TOOL_ID, receipt.tool_id);
+    assert_eq!(Uint128::new(DEFAULT_USAGE_FEE), receipt.charged);
+    assert_eq!(Uint128::new(DEFAULT_USAGE_FEE / 10), receipt.platform_fee);
+    assert_eq!(Uint128::new(DEFAULT_MAX_FEE - DEFAULT_USAGE_FEE), receipt.refunded);
+    assert_eq!(release_height, receipt.settled_at);
+
+    let result = contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::Release {
+            escrow_id: 1,
+            usage_fee: Uint128::new(DEFAULT_USAGE_FEE),
+        },
+        &[],
+    );
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::EscrowNotLocked {}) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    contracts.app.update_block(|block| block.height += DEFAULT_TTL + 1);
+    let refund_height = contracts.app.block_info().height;
+    refund_expired(&mut contracts, 2, USER).unwrap();
+
+    let receipt = query_receipt(&contracts, 2).unwrap();
+    assert_eq!(EscrowStatus::Refunded, receipt.status);
+    assert_eq!(Uint128::zero(), receipt.charged);
+    assert_eq!(Uint128::new(DEFAULT_MAX_FEE), receipt.refunded);
+    assert_eq!(refund_height, receipt.settled_at);
+    assert!(refund_expired(&mut contracts, 2, USER).is_err());
+
+    // The full escrow view reflects the settlement too
+    let escrow = query_escrow(&contracts, 2).unwrap();
+    assert_eq!(EscrowStatus::Refunded, escrow.status);
+    assert_eq!(Some(refund_height), escrow.settled_at);
+
+    let locked: EscrowsResponse = contracts.app.wrap().query_wasm_smart(
+        &contracts.escrow_addr,
+        &QueryMsg::GetEscrows {
+            caller: None,
+            provider: None,
+            tool_id: None,
+            status: Some(EscrowStatus::Locked),
+            start_after: None,
+            limit: None,
+            descending: None,
+        },
+    ).unwrap();
+    assert_eq!(vec![3], locked.escrows.iter().map(|e| e.escrow_id).collect::<Vec<_>>());
+}
+
+/// # Test: Pruning Receipts
+///
+/// ## Test Steps:
+///
+/// 1. Lock three escrows and release the first one
+/// 2. Attempt to prune as the provider and verify it fails
+/// 3. Attempt to prune a locked escrow and verify it fails
+/// 4. Prune the receipt as owner and verify it is gone
+#[test]
+fn test_prune_receipts() {
+    let mut contracts = setup_escrows();
+    let owner_addr = contracts.app.api().addr_make(OWNER);
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+    release_funds(&mut contracts, 1, DEFAULT_USAGE_FEE, PROVIDER).unwrap();
+
+    let result = contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::PruneReceipts { escrow_ids: vec![1] },
+        &[],
+    );
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::Unauthorized {}) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    let result = contracts.app.execute_contract(
+        owner_addr.clone(),
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::PruneReceipts { escrow_ids: vec![1, 2] },
+        &[],
+    );
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::EscrowNotSettled {}) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    let res = contracts.app.execute_contract(
+        owner_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::PruneReceipts { escrow_ids: vec![1, 99] },
+        &[],
+    ).unwrap();
+    assert!(res.events.iter().any(|e| e.attributes.iter().any(|a| a.key == "pruned" && a.value == "1")));
+
+    assert!(query_escrow(&contracts, 1).is_err());
+    assert!(query_escrow(&contracts, 2).is_ok());
+}
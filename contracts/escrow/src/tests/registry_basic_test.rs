@@ -14,11 +14,18 @@
 //! 3. Tools can be paused and resumed correctly
 //! 4. Query functionality returns proper data for existing tools
 //! 5. Authorization checks are properly enforced for all operations
+//! 6. Tools of a provider blocked by the registry admin can no longer be locked
 
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Coin, Uint128};
 use cw_multi_test::Executor;
 use registry::msg::{ExecuteMsg as RegistryExecuteMsg, QueryMsg as RegistryQueryMsg, ToolResponse};
-use crate::tests::setup_contract::{setup_contracts, PROVIDER, UNAUTHORIZED};
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+use crate::state::Expiration;
+use crate::tests::setup_contract::{
+    auth_commitment, execute_as, expect_error, register_tool, setup_contracts, DEFAULT_MAX_FEE, DEFAULT_TOOL_ID,
+    DEFAULT_TTL, NEUTRON, OWNER, PROVIDER, UNAUTHORIZED, USER,
+};
 
 /// # Test: Registry Contract Basic Functionality
 /// 
@@ -182,3 +189,43 @@ fn test_registry_basic_functionality() {
     // Verify unauthorized operation fails
     assert!(result.is_err());
 }
+
+/// # Test: Blocked Provider's Tools Cannot Be Locked
+///
+/// ## Test Steps:
+///
+/// 1. Register a tool as the provider
+/// 2. Block the provider as the registry admin
+/// 3. Verify locking funds for the existing tool fails because it is no longer active
+#[test]
+fn test_blocked_provider_tool_cannot_be_locked() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+
+    let owner_addr = contracts.app.api().addr_make(OWNER);
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+    contracts.app.execute_contract(
+        owner_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &RegistryExecuteMsg::BlockProvider {
+            provider: provider_addr.to_string(),
+            reason: Some("fraud".to_string()),
+        },
+        &[],
+    ).unwrap();
+
+    let expires = Expiration::AtHeight(contracts.app.block_info().height + DEFAULT_TTL);
+    let lock = ExecuteMsg::LockFunds {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+        max_fee: Uint128::new(DEFAULT_MAX_FEE),
+        expected_price: None,
+        auth_commitment: auth_commitment("blocked_token"),
+        expires,
+        from_deposit: None,
+    };
+    let funds = [Coin {
+        denom: NEUTRON.to_string(),
+        amount: Uint128::new(DEFAULT_MAX_FEE),
+    }];
+    expect_error(execute_as(&mut contracts, USER, &lock, &funds), |e| matches!(e, ContractError::ToolNotActive {}));
+}
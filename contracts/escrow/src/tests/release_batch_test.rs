@@ -0,0 +1,120 @@
+//! # Release Batch Test
+//!
+//! This module tests releasing many escrows in one transaction in the Escrow contract.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Every escrow in the batch is released with the same accounting as a single Release
+//! 2. Payouts are merged into one transfer per recipient and denom
+//! 3. One summary event and one event per escrow are emitted
+//! 4. A single invalid item fails the whole batch
+
+use cosmwasm_std::Uint128;
+use cw_multi_test::AppResponse;
+
+use crate::error::ContractError;
+use crate::msg::{CollectedFeesResponse, ExecuteMsg, QueryMsg, ReleaseItem};
+use crate::state::EscrowStatus;
+use crate::tests::setup_contract::{
+    balance, execute_as, expect_error, lock_default, query_escrow, setup_contracts_with_tool, TestContracts,
+    DEFAULT_TTL, DEFAULT_USAGE_FEE, NEUTRON, PROVIDER, UNAUTHORIZED, USER,
+};
+
+fn item(escrow_id: u64, usage_fee: u128) -> ReleaseItem {
+    ReleaseItem {
+        escrow_id,
+        usage_fee: Uint128::new(usage_fee),
+    }
+}
+
+// Amounts of the bank transfers made to `who` in a response
+fn transfers_to(contracts: &TestContracts, res: &AppResponse, who: &str) -> Vec<String> {
+    let addr = contracts.app.api().addr_make(who).to_string();
+    res.events
+        .iter()
+        .filter(|e| e.ty == "transfer" && e.attributes.iter().any(|a| a.key == "recipient" && a.value == addr))
+        .flat_map(|e| e.attributes.iter().filter(|a| a.key == "amount").map(|a| a.value.clone()))
+        .collect()
+}
+
+/// # Test: Releasing a Batch
+///
+/// ## Test Steps:
+///
+/// 1. Lock two escrows for one caller and one for another
+/// 2. Release all three in one batch with different usage fees
+/// 3. Verify each escrow is released and the fees are collected once per denom
+/// 4. Verify the provider and each caller received a single merged transfer
+/// 5. Verify the per-escrow and summary events
+#[test]
+fn test_release_batch() {
+    let mut contracts = setup_contracts_with_tool(1_000);
+    let first = lock_default(&mut contracts, USER, DEFAULT_TTL);
+    let second = lock_default(&mut contracts, USER, DEFAULT_TTL);
+    let third = lock_default(&mut contracts, UNAUTHORIZED, DEFAULT_TTL);
+
+    let provider_before = balance(&contracts, PROVIDER);
+    let res = execute_as(&mut contracts, PROVIDER, &ExecuteMsg::ReleaseBatch {
+        items: vec![item(first, 50), item(second, 30), item(third, 20)],
+    }, &[]).unwrap();
+
+    for escrow_id in [first, second, third] {
+        assert_eq!(EscrowStatus::Released, query_escrow(&contracts, escrow_id).unwrap().status);
+    }
+
+    // 10% of 50, 30 and 20
+    let fees: CollectedFeesResponse = contracts.app.wrap()
+        .query_wasm_smart(&contracts.escrow_addr, &QueryMsg::GetCollectedFees {})
+        .unwrap();
+    assert_eq!(vec![(NEUTRON.to_string(), Uint128::new(10))], fees.collected_fees);
+    assert_eq!(provider_before + 90, balance(&contracts, PROVIDER));
+
+    assert_eq!(vec![format!("90{NEUTRON}")], transfers_to(&contracts, &res, PROVIDER));
+    assert_eq!(vec![format!("120{NEUTRON}")], transfers_to(&contracts, &res, USER));
+    assert_eq!(vec![format!("80{NEUTRON}")], transfers_to(&contracts, &res, UNAUTHORIZED));
+
+    let released = res.events.iter().filter(|e| e.ty == "wasm-wasm-toolpay.released").count();
+    assert_eq!(3, released);
+    let summary = res.events.iter().find(|e| e.ty == "wasm-wasm-toolpay.batch_released").unwrap();
+    assert!(summary.attributes.iter().any(|a| a.key == "count" && a.value == "3"));
+    assert!(summary.attributes.iter().any(|a| a.key == "platform_fees" && a.value == format!("10{NEUTRON}")));
+}
+
+/// # Test: Invalid Items Fail the Batch
+///
+/// ## Test Steps:
+///
+/// 1. Lock two escrows
+/// 2. Verify a batch with a fee above the price fails and releases nothing
+/// 3. Verify a batch listing the same escrow twice fails
+/// 4. Verify another address cannot release the batch
+#[test]
+fn test_release_batch_is_atomic() {
+    let mut contracts = setup_contracts_with_tool(1_000);
+    let first = lock_default(&mut contracts, USER, DEFAULT_TTL);
+    let second = lock_default(&mut contracts, USER, DEFAULT_TTL);
+
+    expect_error(
+        execute_as(&mut contracts, PROVIDER, &ExecuteMsg::ReleaseBatch {
+            items: vec![item(first, 50), item(second, DEFAULT_USAGE_FEE + 1)],
+        }, &[]),
+        |e| matches!(e, ContractError::FeeExceedsPrice { .. }),
+    );
+    assert_eq!(EscrowStatus::Locked, query_escrow(&contracts, first).unwrap().status);
+
+    expect_error(
+        execute_as(&mut contracts, PROVIDER, &ExecuteMsg::ReleaseBatch {
+            items: vec![item(first, 50), item(first, 50)],
+        }, &[]),
+        |e| matches!(e, ContractError::EscrowNotLocked {}),
+    );
+
+    expect_error(
+        execute_as(&mut contracts, UNAUTHORIZED, &ExecuteMsg::ReleaseBatch {
+            items: vec![item(first, 50)],
+        }, &[]),
+        |e| matches!(e, ContractError::Unauthorized {}),
+    );
+    assert_eq!(EscrowStatus::Locked, query_escrow(&contracts, first).unwrap().status);
+}
@@ -6,11 +6,15 @@
 //! 
 //! The module leverages cw-multi-test for contract integration testing.
 
-use cosmwasm_std::{Addr, Coin, Empty, Uint128};
-use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use cosmwasm_std::{Addr, Binary, Coin, Empty, Uint128};
+use cw_multi_test::error::AnyResult;
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+use sha2::{Digest, Sha256};
 
-use crate::contract::{execute, instantiate, query, sudo};
+use crate::contract::{execute, instantiate, migrate, query, sudo};
+use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::Expiration;
 use registry::msg::{ExecuteMsg as RegistryExecuteMsg, InstantiateMsg as RegistryInstantiateMsg};
 
 // Define constants for testing
@@ -29,7 +33,8 @@ pub const UNAUTHORIZED: &str = "unauthorized";
 /// Sets up the Escrow contract for cw-multi-test
 pub fn escrow_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(execute, instantiate, query)
-        .with_sudo(sudo);
+        .with_sudo(sudo)
+        .with_migrate(migrate);
     Box::new(contract)
 }
 
@@ -39,7 +44,8 @@ pub fn registry_contract() -> Box<dyn Contract<Empty>> {
         registry::contract::execute,
         registry::contract::instantiate,
         registry::contract::query,
-    );
+    )
+    .with_migrate(registry::contract::migrate);
     Box::new(contract)
 }
 
@@ -104,11 +110,11 @@ pub struct TestContracts {
 
 /// Instantiates both Registry and Escrow contracts for testing
 pub fn setup_contracts() -> TestContracts {
-    setup_contracts_with_fee(0) // Default fee percentage is 0
+    setup_contracts_with_fee(0) // Default fee is 0
 }
 
-/// Instantiates both Registry and Escrow contracts for testing with a specific fee percentage
-pub fn setup_contracts_with_fee(fee_percentage: u64) -> TestContracts {
+/// Instantiates both Registry and Escrow contracts for testing with a specific fee in basis points
+pub fn setup_contracts_with_fee(fee_bps: u64) -> TestContracts {
     let mut app = mock_app();
     
     // Store contract codes
@@ -123,7 +129,7 @@ pub fn setup_contracts_with_fee(fee_percentage: u64) -> TestContracts {
         .instantiate_contract(
             registry_code_id,
             owner_addr.clone(),
-            &RegistryInstantiateMsg {},
+            &RegistryInstantiateMsg { admin: None },
             &[],
             "registry",
             None,
@@ -137,7 +143,9 @@ pub fn setup_contracts_with_fee(fee_percentage: u64) -> TestContracts {
             owner_addr,
             &InstantiateMsg {
                 registry_addr: registry_addr.to_string(),
-                fee_percentage,
+                fee_bps,
+                withdrawal_delay: None,
+                expiry_bounds: None,
             },
             &[],
             "escrow",
@@ -178,6 +186,18 @@ pub fn register_tool(
     Ok(())
 }
 
+/// Instantiates both contracts with a specific fee and registers the default tool at the default usage fee
+pub fn setup_contracts_with_tool(fee_bps: u64) -> TestContracts {
+    let mut contracts = setup_contracts_with_fee(fee_bps);
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_USAGE_FEE, PROVIDER).unwrap();
+    contracts
+}
+
+/// Helper function to compute the sha256 commitment to an auth token
+pub fn auth_commitment(auth_token: &str) -> Binary {
+    Binary::from(Sha256::digest(auth_token.as_bytes()).to_vec())
+}
+
 /// Helper function to lock funds in the Escrow contract
 pub fn lock_funds(
     contracts: &mut TestContracts,
@@ -199,8 +219,10 @@ pub fn lock_funds(
         &ExecuteMsg::LockFunds {
             tool_id: tool_id.to_string(),
             max_fee: Uint128::new(max_fee),
-            expires: current_height + expires_in_blocks,
-            auth_token: auth_token,
+            expected_price: None,
+            expires: Expiration::AtHeight(current_height + expires_in_blocks),
+            from_deposit: None,
+            auth_commitment: auth_commitment(&auth_token),
         },
         funds,
     )?;
@@ -248,6 +270,50 @@ pub fn lock_funds(
     Ok(escrow_id)
 }
 
+/// Helper function to lock the default max fee for the default tool as `caller`, expiring in `ttl` blocks
+pub fn lock_default(contracts: &mut TestContracts, caller: &str, ttl: u64) -> u64 {
+    let funds = neutron(DEFAULT_MAX_FEE);
+    lock_funds(contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, ttl, "default_token".to_string(), caller, &funds).unwrap()
+}
+
+/// Helper function to execute any message on the Escrow contract as `sender`
+pub fn execute_as(
+    contracts: &mut TestContracts,
+    sender: &str,
+    msg: &ExecuteMsg,
+    funds: &[Coin],
+) -> AnyResult<AppResponse> {
+    let sender_addr = contracts.app.api().addr_make(sender);
+    contracts.app.execute_contract(sender_addr, Addr::unchecked(&contracts.escrow_addr), msg, funds)
+}
+
+/// Helper function to assert that an execution failed with a matching contract error
+pub fn expect_error(result: AnyResult<AppResponse>, check: impl Fn(&ContractError) -> bool) {
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(err) => assert!(check(&err), "Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+}
+
+/// Helper function to build funds of `amount` untrn
+pub fn neutron(amount: u128) -> Vec<Coin> {
+    vec![Coin {
+        denom: NEUTRON.to_string(),
+        amount: Uint128::new(amount),
+    }]
+}
+
+/// Helper function to get the untrn balance of a mock account
+pub fn balance(contracts: &TestContracts, who: &str) -> u128 {
+    let addr = contracts.app.api().addr_make(who);
+    balance_of(contracts, &addr, NEUTRON)
+}
+
+/// Helper function to get the balance of any address in any denom
+pub fn balance_of(contracts: &TestContracts, addr: &Addr, denom: &str) -> u128 {
+    contracts.app.wrap().query_balance(addr, denom).unwrap().amount.u128()
+}
+
 /// Helper function to release funds from the Escrow contract
 pub fn release_funds(
     contracts: &mut TestContracts,
@@ -0,0 +1,320 @@
+//! # Subscription Test
+//!
+//! This module tests recurring tool subscriptions in the Escrow contract.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Callers can only subscribe to tools that advertise subscription terms
+//! 2. Subscriptions grant entitlement for the prepaid periods
+//! 3. Providers can claim each period once it has elapsed, minus the platform fee
+//! 4. Cancelling refunds the periods that have not started
+//! 5. Extensions are paid at the current terms, and huge period counts fail without panicking
+//! 6. A subscription to a transferred tool cannot be extended; a new one pays the new provider
+
+use cosmwasm_std::{Addr, Uint128};
+use cw_multi_test::error::AnyResult;
+use cw_multi_test::{AppResponse, Executor};
+use registry::msg::ExecuteMsg as RegistryExecuteMsg;
+use registry::state::SubscriptionTerms;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, QueryMsg, SubscriptionResponse};
+use crate::state::PaidPeriods;
+use crate::tests::setup_contract::{
+    balance, execute_as, expect_error, neutron, register_tool, setup_contracts, setup_contracts_with_fee, TestContracts,
+    DEFAULT_MAX_FEE, DEFAULT_TOOL_ID, PROVIDER, UNAUTHORIZED, USER,
+};
+
+const PERIOD_PRICE: u128 = 100;
+const PERIOD: u64 = 20;
+
+fn subscribe(contracts: &mut TestContracts, periods: u64) -> AnyResult<AppResponse> {
+    let msg = ExecuteMsg::Subscribe {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+        periods,
+    };
+    execute_as(contracts, USER, &msg, &neutron(PERIOD_PRICE * periods as u128))
+}
+
+fn claim(contracts: &mut TestContracts, sender: &str) -> AnyResult<AppResponse> {
+    let msg = ExecuteMsg::ClaimSubscription {
+        caller: contracts.app.api().addr_make(USER).to_string(),
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+    };
+    execute_as(contracts, sender, &msg, &[])
+}
+
+fn query_subscription(contracts: &TestContracts) -> Option<SubscriptionResponse> {
+    contracts.app.wrap().query_wasm_smart(
+        &contracts.escrow_addr,
+        &QueryMsg::GetSubscription {
+            caller: contracts.app.api().addr_make(USER).to_string(),
+            tool_id: DEFAULT_TOOL_ID.to_string(),
+        },
+    ).unwrap()
+}
+
+fn offer_subscription(contracts: &mut TestContracts) {
+    set_terms(contracts, PERIOD_PRICE, PERIOD);
+}
+
+fn set_terms(contracts: &mut TestContracts, price: u128, period: u64) {
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &RegistryExecuteMsg::UpdateSubscriptionTerms {
+            tool_id: DEFAULT_TOOL_ID.to_string(),
+            subscription: Some(SubscriptionTerms {
+                price: Uint128::new(price),
+                period,
+            }),
+        },
+        &[],
+    ).unwrap();
+}
+
+fn transfer_tool(contracts: &mut TestContracts, new_provider: &str) {
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+    let new_provider_addr = contracts.app.api().addr_make(new_provider);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &RegistryExecuteMsg::ProposeToolTransfer {
+            tool_id: DEFAULT_TOOL_ID.to_string(),
+            new_provider: new_provider_addr.to_string(),
+        },
+        &[],
+    ).unwrap();
+    contracts.app.execute_contract(
+        new_provider_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &RegistryExecuteMsg::AcceptToolTransfer {
+            tool_id: DEFAULT_TOOL_ID.to_string(),
+        },
+        &[],
+    ).unwrap();
+}
+
+fn setup_subscription(mut contracts: TestContracts) -> TestContracts {
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+    offer_subscription(&mut contracts);
+    contracts
+}
+
+/// # Test: Subscribing to a Tool
+///
+/// ## Test Steps:
+///
+/// 1. Verify subscribing to a tool without subscription terms fails
+/// 2. Advertise terms and verify subscribing without enough funds fails
+/// 3. Subscribe for 3 periods and verify the caller is entitled
+/// 4. Extend the subscription by 1 period while it is active
+/// 5. Verify the caller is no longer entitled once all periods have passed
+#[test]
+fn test_subscribe() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+
+    expect_error(subscribe(&mut contracts, 1), |e| matches!(e, ContractError::SubscriptionsNotOffered {}));
+    offer_subscription(&mut contracts);
+    assert_eq!(None, query_subscription(&contracts));
+
+    let msg = ExecuteMsg::Subscribe {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+        periods: 3,
+    };
+    expect_error(execute_as(&mut contracts, USER, &msg, &neutron(PERIOD_PRICE)), |e| {
+        matches!(e, ContractError::InsufficientFunds { .. })
+    });
+    let msg = ExecuteMsg::Subscribe {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+        periods: 0,
+    };
+    expect_error(execute_as(&mut contracts, USER, &msg, &[]), |e| matches!(e, ContractError::ZeroPeriods {}));
+
+    let start = contracts.app.block_info().height;
+    subscribe(&mut contracts, 3).unwrap();
+    let subscription = query_subscription(&contracts).unwrap();
+    assert!(subscription.entitled);
+    assert_eq!(start + 3 * PERIOD, subscription.paid_until);
+
+    contracts.app.update_block(|block| block.height += PERIOD);
+    subscribe(&mut contracts, 1).unwrap();
+    let subscription = query_subscription(&contracts).unwrap();
+    assert_eq!(4, subscription.periods);
+    assert_eq!(start, subscription.start);
+
+    contracts.app.update_block(|block| block.height = start + 4 * PERIOD);
+    assert!(!query_subscription(&contracts).unwrap().entitled);
+}
+
+/// # Test: Claiming Elapsed Periods
+///
+/// ## Test Steps:
+///
+/// 1. Subscribe for 3 periods on a contract with a 10% fee
+/// 2. Verify nothing can be claimed before the first period elapses
+/// 3. Verify only the provider can claim
+/// 4. Claim two elapsed periods at once and verify the payout minus the fee
+/// 5. Claim the last period and verify the subscription is removed
+#[test]
+fn test_claim_subscription() {
+    let mut contracts = setup_subscription(setup_contracts_with_fee(1_000));
+    let provider_start = balance(&contracts, PROVIDER);
+    subscribe(&mut contracts, 3).unwrap();
+
+    expect_error(claim(&mut contracts, PROVIDER), |e| matches!(e, ContractError::NothingToClaim { .. }));
+
+    contracts.app.update_block(|block| block.height += 2 * PERIOD);
+    expect_error(claim(&mut contracts, UNAUTHORIZED), |e| matches!(e, ContractError::Unauthorized {}));
+    claim(&mut contracts, PROVIDER).unwrap();
+    assert_eq!(provider_start + 180, balance(&contracts, PROVIDER));
+    assert_eq!(2, query_subscription(&contracts).unwrap().claimed);
+
+    contracts.app.update_block(|block| block.height += PERIOD);
+    claim(&mut contracts, PROVIDER).unwrap();
+    assert_eq!(provider_start + 270, balance(&contracts, PROVIDER));
+    assert_eq!(None, query_subscription(&contracts));
+}
+
+/// # Test: Cancelling a Subscription
+///
+/// ## Test Steps:
+///
+/// 1. Subscribe for 5 periods
+/// 2. Cancel during the second period and verify 3 periods are refunded
+/// 3. Verify the caller stays entitled until the end of the current period
+/// 4. Verify the provider can claim the two used periods
+/// 5. Verify cancelling an ended subscription fails
+#[test]
+fn test_cancel_subscription() {
+    let mut contracts = setup_subscription(setup_contracts());
+    let user_start = balance(&contracts, USER);
+    let provider_start = balance(&contracts, PROVIDER);
+    let start = contracts.app.block_info().height;
+    subscribe(&mut contracts, 5).unwrap();
+
+    contracts.app.update_block(|block| block.height += PERIOD + 1);
+    let cancel = ExecuteMsg::CancelSubscription {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+    };
+    execute_as(&mut contracts, USER, &cancel, &[]).unwrap();
+    assert_eq!(user_start - 2 * PERIOD_PRICE, balance(&contracts, USER));
+
+    let subscription = query_subscription(&contracts).unwrap();
+    assert!(subscription.entitled);
+    assert_eq!(start + 2 * PERIOD, subscription.paid_until);
+
+    contracts.app.update_block(|block| block.height = start + 2 * PERIOD);
+    assert!(!query_subscription(&contracts).unwrap().entitled);
+    claim(&mut contracts, PROVIDER).unwrap();
+    assert_eq!(provider_start + 2 * PERIOD_PRICE, balance(&contracts, PROVIDER));
+
+    subscribe(&mut contracts, 1).unwrap();
+    contracts.app.update_block(|block| block.height += PERIOD);
+    expect_error(execute_as(&mut contracts, USER, &cancel, &[]), |e| {
+        matches!(e, ContractError::SubscriptionEnded {})
+    });
+}
+
+/// # Test: Extending at the Current Terms
+///
+/// ## Test Steps:
+///
+/// 1. Subscribe for 2 periods, then raise the price to 150
+/// 2. Verify an extension paid at the old price fails, and extend by 2 periods at the new one
+/// 3. Change the period length and verify the active subscription can no longer be extended
+/// 4. Cancel during the first period and verify the later periods are refunded at their own prices
+/// 5. Verify the provider claims the first period at the old price
+#[test]
+fn test_extend_at_current_terms() {
+    let mut contracts = setup_subscription(setup_contracts());
+    let user_start = balance(&contracts, USER);
+    let provider_start = balance(&contracts, PROVIDER);
+    subscribe(&mut contracts, 2).unwrap();
+
+    set_terms(&mut contracts, 150, PERIOD);
+    expect_error(subscribe(&mut contracts, 2), |e| matches!(e, ContractError::InsufficientFunds { .. }));
+    let extend = ExecuteMsg::Subscribe {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+        periods: 2,
+    };
+    execute_as(&mut contracts, USER, &extend, &neutron(300)).unwrap();
+    let subscription = query_subscription(&contracts).unwrap();
+    assert_eq!(4, subscription.periods);
+    assert_eq!(
+        vec![
+            PaidPeriods { periods: 2, price: Uint128::new(PERIOD_PRICE) },
+            PaidPeriods { periods: 2, price: Uint128::new(150) },
+        ],
+        subscription.prices,
+    );
+
+    set_terms(&mut contracts, 150, 2 * PERIOD);
+    expect_error(execute_as(&mut contracts, USER, &extend, &neutron(300)), |e| {
+        matches!(e, ContractError::SubscriptionTermsChanged {})
+    });
+
+    let cancel = ExecuteMsg::CancelSubscription {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+    };
+    execute_as(&mut contracts, USER, &cancel, &[]).unwrap();
+    assert_eq!(user_start - PERIOD_PRICE, balance(&contracts, USER));
+    let subscription = query_subscription(&contracts).unwrap();
+    assert_eq!(vec![PaidPeriods { periods: 1, price: Uint128::new(PERIOD_PRICE) }], subscription.prices);
+
+    contracts.app.update_block(|block| block.height += PERIOD);
+    claim(&mut contracts, PROVIDER).unwrap();
+    assert_eq!(provider_start + PERIOD_PRICE, balance(&contracts, PROVIDER));
+}
+
+/// # Test: Extending After a Tool Transfer
+///
+/// ## Test Steps:
+///
+/// 1. Subscribe for 2 periods and transfer the tool to a new provider
+/// 2. Verify the active subscription can no longer be extended
+/// 3. Let it end and subscribe again
+/// 4. Verify the old provider was paid for the ended periods and the new subscription pays the new provider
+#[test]
+fn test_extend_after_transfer() {
+    let mut contracts = setup_subscription(setup_contracts());
+    let provider_start = balance(&contracts, PROVIDER);
+    subscribe(&mut contracts, 2).unwrap();
+
+    transfer_tool(&mut contracts, UNAUTHORIZED);
+    expect_error(subscribe(&mut contracts, 1), |e| matches!(e, ContractError::SubscriptionTermsChanged {}));
+    assert_eq!(2, query_subscription(&contracts).unwrap().periods);
+
+    contracts.app.update_block(|block| block.height += 2 * PERIOD);
+    subscribe(&mut contracts, 1).unwrap();
+    assert_eq!(provider_start + 2 * PERIOD_PRICE, balance(&contracts, PROVIDER));
+    let subscription = query_subscription(&contracts).unwrap();
+    assert_eq!(contracts.app.api().addr_make(UNAUTHORIZED), subscription.provider);
+    assert_eq!(1, subscription.periods);
+}
+
+/// # Test: Overflowing Period Counts
+///
+/// ## Test Steps:
+///
+/// 1. Verify subscribing for u64::MAX periods fails with an overflow error
+/// 2. Subscribe for 1 period and verify extending it by u64::MAX periods fails the same way
+#[test]
+fn test_overflowing_periods() {
+    let mut contracts = setup_subscription(setup_contracts());
+    let msg = ExecuteMsg::Subscribe {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+        periods: u64::MAX,
+    };
+    expect_error(execute_as(&mut contracts, USER, &msg, &neutron(PERIOD_PRICE)), |e| {
+        matches!(e, ContractError::Overflow(_))
+    });
+
+    subscribe(&mut contracts, 1).unwrap();
+    expect_error(execute_as(&mut contracts, USER, &msg, &neutron(PERIOD_PRICE)), |e| {
+        matches!(e, ContractError::Overflow(_))
+    });
+}
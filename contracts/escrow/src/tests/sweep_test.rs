@@ -0,0 +1,194 @@
+//! # Sweep Test
+//!
+//! This module tests permissionless refunds of expired escrows in the Escrow contract.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. SweepExpired refunds expired escrows of any caller, oldest first, up to the limit
+//! 2. Live and settled escrows are left alone, for both height and time expirations
+//! 3. RefundExpiredBatch refunds the listed escrows and fails as a whole on any invalid one
+//! 4. The keeper reward is kept out of the refund, only for other callers' escrows, and is capped at 1%
+
+use cosmwasm_std::{Addr, Uint128};
+use cw_multi_test::Executor;
+
+use crate::contract::MAX_KEEPER_REWARD_BPS;
+use crate::error::ContractError;
+use crate::msg::{CollectedFeesResponse, ExecuteMsg, QueryMsg};
+use crate::state::{EscrowStatus, Expiration};
+use crate::tests::setup_contract::{
+    auth_commitment, balance, execute_as, expect_error, lock_default, neutron, query_escrow, release_funds,
+    setup_contracts_with_tool, TestContracts, DEFAULT_MAX_FEE, DEFAULT_TOOL_ID, DEFAULT_TTL, DEFAULT_USAGE_FEE, NEUTRON,
+    OWNER, PROVIDER, UNAUTHORIZED, USER,
+};
+
+fn refund_batch(escrow_ids: Vec<u64>) -> ExecuteMsg {
+    ExecuteMsg::RefundExpiredBatch { escrow_ids }
+}
+
+fn set_keeper_reward(keeper_reward_bps: u64) -> ExecuteMsg {
+    ExecuteMsg::UpdateConfig {
+        registry_addr: None,
+        fee_bps: None,
+        withdrawal_delay: None,
+        expiry_bounds: None,
+        keeper_reward_bps: Some(keeper_reward_bps),
+    }
+}
+
+fn status(contracts: &TestContracts, escrow_id: u64) -> EscrowStatus {
+    query_escrow(contracts, escrow_id).unwrap().status
+}
+
+fn collected_fees(contracts: &TestContracts) -> Vec<(String, Uint128)> {
+    let fees: CollectedFeesResponse = contracts.app.wrap()
+        .query_wasm_smart(&contracts.escrow_addr, &QueryMsg::GetCollectedFees {})
+        .unwrap();
+    fees.collected_fees
+}
+
+/// # Test: Sweeping Expired Escrows
+///
+/// ## Test Steps:
+///
+/// 1. Lock escrows expiring at 5, 10 and 40 blocks, one released early, and one expiring in 60 seconds
+/// 2. Move past the first two heights and the time expiration
+/// 3. Sweep with a limit of 1 and verify only the oldest escrow is refunded
+/// 4. Sweep again and verify the remaining expired escrows are refunded to the caller
+/// 5. Verify the live and released escrows are untouched
+#[test]
+fn test_sweep_expired() {
+    let mut contracts = setup_contracts_with_tool(1_000);
+
+    let first = lock_default(&mut contracts, USER, 5);
+    let second = lock_default(&mut contracts, USER, DEFAULT_TTL);
+    let live = lock_default(&mut contracts, USER, 40);
+    let released = lock_default(&mut contracts, USER, 5);
+    release_funds(&mut contracts, released, DEFAULT_USAGE_FEE, PROVIDER).unwrap();
+
+    let timed = contracts.app.block_info().time.plus_seconds(60);
+    let user_addr = contracts.app.api().addr_make(USER);
+    contracts.app.execute_contract(
+        user_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::LockFunds {
+            tool_id: DEFAULT_TOOL_ID.to_string(),
+            max_fee: Uint128::new(DEFAULT_MAX_FEE),
+            expected_price: None,
+            auth_commitment: auth_commitment("timed_token"),
+            expires: Expiration::AtTime(timed),
+            from_deposit: None,
+        },
+        &neutron(DEFAULT_MAX_FEE),
+    ).unwrap();
+    let timed_id = released + 1;
+
+    contracts.app.update_block(|block| {
+        block.height += DEFAULT_TTL + 1;
+        block.time = block.time.plus_seconds(61);
+    });
+
+    let user_before = balance(&contracts, USER);
+    execute_as(&mut contracts, UNAUTHORIZED, &ExecuteMsg::SweepExpired { limit: Some(1) }, &[]).unwrap();
+    assert_eq!(EscrowStatus::Refunded, status(&contracts, first));
+    assert_eq!(EscrowStatus::Locked, status(&contracts, second));
+
+    execute_as(&mut contracts, UNAUTHORIZED, &ExecuteMsg::SweepExpired { limit: None }, &[]).unwrap();
+    assert_eq!(EscrowStatus::Refunded, status(&contracts, second));
+    assert_eq!(EscrowStatus::Refunded, status(&contracts, timed_id));
+    assert_eq!(EscrowStatus::Locked, status(&contracts, live));
+    assert_eq!(EscrowStatus::Released, status(&contracts, released));
+    assert_eq!(user_before + 3 * DEFAULT_MAX_FEE, balance(&contracts, USER));
+
+    // Nothing is left to sweep
+    let res = execute_as(&mut contracts, UNAUTHORIZED, &ExecuteMsg::SweepExpired { limit: None }, &[]).unwrap();
+    let summary = res.events.iter().find(|e| e.ty == "wasm-wasm-toolpay.expired_swept").unwrap();
+    assert!(summary.attributes.iter().any(|a| a.key == "count" && a.value == "0"));
+}
+
+/// # Test: Batch Refunds
+///
+/// ## Test Steps:
+///
+/// 1. Lock two short escrows and one long escrow
+/// 2. Verify a batch including the live escrow fails and refunds nothing
+/// 3. Verify an oversized batch is rejected
+/// 4. Verify a batch listing the same escrow twice is rejected and leaves other funds alone
+/// 5. Refund the two expired escrows in one batch as another address
+#[test]
+fn test_refund_expired_batch() {
+    let mut contracts = setup_contracts_with_tool(1_000);
+
+    let first = lock_default(&mut contracts, USER, 5);
+    let second = lock_default(&mut contracts, USER, 5);
+    let live = lock_default(&mut contracts, USER, 40);
+    contracts.app.update_block(|block| block.height += 6);
+
+    expect_error(
+        execute_as(&mut contracts, UNAUTHORIZED, &refund_batch(vec![first, live]), &[]),
+        |e| matches!(e, ContractError::EscrowNotExpired {}),
+    );
+    assert_eq!(EscrowStatus::Locked, status(&contracts, first));
+
+    expect_error(
+        execute_as(&mut contracts, UNAUTHORIZED, &refund_batch(vec![first; 31]), &[]),
+        |e| matches!(e, ContractError::BatchTooLarge { max: 30 }),
+    );
+
+    // A repeated id must not pay the refund twice out of the live escrow's funds
+    expect_error(
+        execute_as(&mut contracts, UNAUTHORIZED, &refund_batch(vec![first, first]), &[]),
+        |e| matches!(e, ContractError::EscrowNotLocked {}),
+    );
+    assert_eq!(EscrowStatus::Locked, status(&contracts, first));
+    assert_eq!(EscrowStatus::Locked, status(&contracts, live));
+
+    let user_before = balance(&contracts, USER);
+    execute_as(&mut contracts, UNAUTHORIZED, &refund_batch(vec![first, second]), &[]).unwrap();
+    assert_eq!(EscrowStatus::Refunded, status(&contracts, first));
+    assert_eq!(EscrowStatus::Refunded, status(&contracts, second));
+    assert_eq!(user_before + 2 * DEFAULT_MAX_FEE, balance(&contracts, USER));
+}
+
+/// # Test: Keeper Reward
+///
+/// ## Test Steps:
+///
+/// 1. Verify a keeper reward above 1% is rejected
+/// 2. Set a keeper reward of 1% and collect a platform fee of 5 from a release
+/// 3. Let three escrows expire
+/// 4. Sweep one as the caller and verify it is refunded in full
+/// 5. Sweep the rest as a keeper and verify 1 per escrow is kept out of the refunds for the keeper
+/// 6. Verify the collected fees are untouched
+#[test]
+fn test_keeper_reward() {
+    let mut contracts = setup_contracts_with_tool(1_000);
+    expect_error(execute_as(&mut contracts, OWNER, &set_keeper_reward(MAX_KEEPER_REWARD_BPS + 1), &[]), |e| {
+        matches!(e, ContractError::InvalidKeeperRewardBps(101))
+    });
+    execute_as(&mut contracts, OWNER, &set_keeper_reward(MAX_KEEPER_REWARD_BPS), &[]).unwrap();
+
+    let released = lock_default(&mut contracts, USER, 5);
+    release_funds(&mut contracts, released, DEFAULT_USAGE_FEE, PROVIDER).unwrap();
+    for _ in 0..3 {
+        lock_default(&mut contracts, USER, 5);
+    }
+    contracts.app.update_block(|block| block.height += 6);
+
+    // Callers sweeping their own escrows get everything back
+    let user_before = balance(&contracts, USER);
+    execute_as(&mut contracts, USER, &ExecuteMsg::SweepExpired { limit: Some(1) }, &[]).unwrap();
+    assert_eq!(user_before + DEFAULT_MAX_FEE, balance(&contracts, USER));
+
+    let user_before = balance(&contracts, USER);
+    let keeper_before = balance(&contracts, UNAUTHORIZED);
+    let res = execute_as(&mut contracts, UNAUTHORIZED, &ExecuteMsg::SweepExpired { limit: None }, &[]).unwrap();
+    assert_eq!(keeper_before + 2, balance(&contracts, UNAUTHORIZED));
+    assert_eq!(user_before + 2 * (DEFAULT_MAX_FEE - 1), balance(&contracts, USER));
+    let summary = res.events.iter().find(|e| e.ty == "wasm-wasm-toolpay.expired_swept").unwrap();
+    assert!(summary.attributes.iter().any(|a| a.key == "rewards" && a.value == format!("2{NEUTRON}")));
+
+    // The reward never comes out of the platform's fees
+    assert_eq!(vec![(NEUTRON.to_string(), Uint128::new(5))], collected_fees(&contracts));
+}
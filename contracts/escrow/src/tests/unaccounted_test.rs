@@ -0,0 +1,155 @@
+//! # Unaccounted Balance Test
+//!
+//! This module tests surplus refunds and the recovery of stray funds in the Escrow contract.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Coins sent beyond `max_fee`, and coins in other denoms, are refunded in the same transaction
+//! 2. The contract's liabilities match its balance after a full lock, release and fee claim flow
+//! 3. Funds sent to the contract outside any flow are reported as unaccounted
+//! 4. Only the owner can recover unaccounted funds, and only when there are some
+
+use cosmwasm_std::{Addr, Coin, Uint128};
+use cw_multi_test::Executor;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, QueryMsg, UnaccountedBalanceResponse};
+use crate::tests::setup_contract::{
+    balance_of, execute_as, expect_error, lock_funds, release_funds, setup_contracts_with_tool, TestContracts,
+    DEFAULT_MAX_FEE, DEFAULT_TOOL_ID, DEFAULT_TTL, DEFAULT_USAGE_FEE, NEUTRON, OWNER, PROVIDER, UNAUTHORIZED, USER,
+};
+
+const ATOM: &str = "uatom";
+
+fn coin(denom: &str, amount: u128) -> Coin {
+    Coin {
+        denom: denom.to_string(),
+        amount: Uint128::new(amount),
+    }
+}
+
+fn unaccounted(contracts: &TestContracts, denom: &str) -> UnaccountedBalanceResponse {
+    contracts.app.wrap().query_wasm_smart(
+        &contracts.escrow_addr,
+        &QueryMsg::GetUnaccountedBalance {
+            denom: denom.to_string(),
+        },
+    ).unwrap()
+}
+
+fn setup() -> TestContracts {
+    let mut contracts = setup_contracts_with_tool(1_000);
+
+    // Give the user a second denom to send by mistake
+    contracts.app.init_modules(|router, api, storage| {
+        router.bank.init_balance(
+            storage,
+            &api.addr_make(USER),
+            vec![coin(NEUTRON, 5000), coin(ATOM, 1000)],
+        ).unwrap();
+    });
+
+    contracts
+}
+
+/// # Test: Surplus Refund
+///
+/// ## Test Steps:
+///
+/// 1. Lock funds sending 500 NEUTRON and 200 ATOM for a max fee of 100
+/// 2. Verify the user only paid the max fee and got the ATOM back
+/// 3. Verify the contract holds exactly what it owes
+#[test]
+fn test_surplus_refund() {
+    let mut contracts = setup();
+    let user_addr = contracts.app.api().addr_make(USER);
+    let escrow_addr = Addr::unchecked(&contracts.escrow_addr);
+
+    let funds = vec![coin(NEUTRON, 500), coin(ATOM, 200)];
+    lock_funds(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, DEFAULT_TTL, "surplus_token".to_string(), USER, &funds)
+        .unwrap();
+
+    assert_eq!(balance_of(&contracts, &user_addr, NEUTRON), 5000 - DEFAULT_MAX_FEE);
+    assert_eq!(balance_of(&contracts, &user_addr, ATOM), 1000);
+    assert_eq!(balance_of(&contracts, &escrow_addr, NEUTRON), DEFAULT_MAX_FEE);
+    assert_eq!(balance_of(&contracts, &escrow_addr, ATOM), 0);
+
+    let neutron = unaccounted(&contracts, NEUTRON);
+    assert_eq!(neutron.balance, Uint128::new(DEFAULT_MAX_FEE));
+    assert_eq!(neutron.liabilities, Uint128::new(DEFAULT_MAX_FEE));
+    assert_eq!(neutron.unaccounted, Uint128::zero());
+}
+
+/// # Test: Liabilities Follow the Escrow Lifecycle
+///
+/// ## Test Steps:
+///
+/// 1. Lock and release an escrow, taking a platform fee
+/// 2. Verify liabilities match the balance after the release
+/// 3. Claim the fees and verify both drop to zero
+#[test]
+fn test_liabilities_follow_lifecycle() {
+    let mut contracts = setup();
+
+    let funds = vec![coin(NEUTRON, DEFAULT_MAX_FEE)];
+    let escrow_id =
+        lock_funds(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, DEFAULT_TTL, "cycle_token".to_string(), USER, &funds)
+            .unwrap();
+    release_funds(&mut contracts, escrow_id, DEFAULT_USAGE_FEE, PROVIDER).unwrap();
+
+    let after_release = unaccounted(&contracts, NEUTRON);
+    assert!(!after_release.balance.is_zero());
+    assert_eq!(after_release.liabilities, after_release.balance);
+    assert_eq!(after_release.unaccounted, Uint128::zero());
+
+    execute_as(&mut contracts, OWNER, &ExecuteMsg::ClaimFees { denom: None }, &[]).unwrap();
+
+    let after_claim = unaccounted(&contracts, NEUTRON);
+    assert_eq!(after_claim.balance, Uint128::zero());
+    assert_eq!(after_claim.liabilities, Uint128::zero());
+}
+
+/// # Test: Recover Stray Funds
+///
+/// ## Test Steps:
+///
+/// 1. Lock an escrow, then send 300 NEUTRON straight to the contract
+/// 2. Verify the query reports 300 as unaccounted
+/// 3. Verify a non-owner cannot recover the funds
+/// 4. Recover them to a chosen recipient as the owner
+/// 5. Verify the escrowed funds are untouched and a second recovery fails
+#[test]
+fn test_recover_unaccounted() {
+    let mut contracts = setup();
+    let user_addr = contracts.app.api().addr_make(USER);
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+    let escrow_addr = Addr::unchecked(&contracts.escrow_addr);
+
+    let funds = vec![coin(NEUTRON, DEFAULT_MAX_FEE)];
+    lock_funds(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, DEFAULT_TTL, "stray_token".to_string(), USER, &funds)
+        .unwrap();
+    contracts.app.send_tokens(user_addr, escrow_addr.clone(), &[coin(NEUTRON, 300)]).unwrap();
+
+    let report = unaccounted(&contracts, NEUTRON);
+    assert_eq!(report.balance, Uint128::new(DEFAULT_MAX_FEE + 300));
+    assert_eq!(report.liabilities, Uint128::new(DEFAULT_MAX_FEE));
+    assert_eq!(report.unaccounted, Uint128::new(300));
+
+    let recover = ExecuteMsg::RecoverUnaccounted {
+        denom: NEUTRON.to_string(),
+        recipient: Some(provider_addr.to_string()),
+    };
+    expect_error(execute_as(&mut contracts, UNAUTHORIZED, &recover, &[]), |e| {
+        matches!(e, ContractError::Unauthorized {})
+    });
+
+    let provider_before = balance_of(&contracts, &provider_addr, NEUTRON);
+    execute_as(&mut contracts, OWNER, &recover, &[]).unwrap();
+    assert_eq!(balance_of(&contracts, &provider_addr, NEUTRON), provider_before + 300);
+    assert_eq!(balance_of(&contracts, &escrow_addr, NEUTRON), DEFAULT_MAX_FEE);
+
+    expect_error(execute_as(&mut contracts, OWNER, &recover, &[]), |e| {
+        matches!(e, ContractError::NothingToRecover { .. })
+    });
+}
@@ -0,0 +1,161 @@
+//! # VerifyEscrow Query Test
+//!
+//! This module tests the provider-side VerifyEscrow query used by HTTP middleware.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. A locked escrow for the right tool and provider with enough funds is valid
+//! 2. Each failing check is reported with its reason code instead of an error
+//! 3. A frozen contract reports every escrow as frozen
+//! 4. The remaining amount is capped at the tool price recorded at lock time
+
+use cosmwasm_std::{to_json_binary, Addr, Coin, Uint128};
+use cw_multi_test::SudoMsg as CwSudoMsg;
+
+use crate::msg::{ExecuteMsg, QueryMsg, SudoMsg, VerifyEscrowReason, VerifyEscrowResponse};
+use crate::state::Expiration;
+use crate::tests::setup_contract::{
+    execute_as, lock_funds, register_tool, release_funds, setup_contracts, TestContracts, DEFAULT_MAX_FEE,
+    DEFAULT_TOOL_ID, DEFAULT_TTL, DEFAULT_USAGE_FEE, NEUTRON, PROVIDER, USER,
+};
+
+fn verify(
+    contracts: &TestContracts,
+    escrow_id: u64,
+    tool_id: &str,
+    provider: &str,
+    min_amount: u128,
+) -> VerifyEscrowResponse {
+    contracts.app.wrap().query_wasm_smart(
+        &contracts.escrow_addr,
+        &QueryMsg::VerifyEscrow {
+            escrow_id,
+            tool_id: tool_id.to_string(),
+            provider: contracts.app.api().addr_make(provider).to_string(),
+            min_amount: Uint128::new(min_amount),
+        },
+    ).unwrap()
+}
+
+fn setup_escrows() -> TestContracts {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+    for i in 0..2 {
+        lock_funds(
+            &mut contracts,
+            DEFAULT_TOOL_ID,
+            DEFAULT_MAX_FEE,
+            DEFAULT_TTL,
+            format!("verify_token_{}", i),
+            USER,
+            &[Coin {
+                denom: NEUTRON.to_string(),
+                amount: Uint128::new(DEFAULT_MAX_FEE),
+            }],
+        ).unwrap();
+    }
+    contracts
+}
+
+/// # Test: Reason Codes for Each Failing Check
+///
+/// ## Test Steps:
+///
+/// 1. Lock two escrows
+/// 2. Verify a matching request is valid
+/// 3. Verify unknown IDs, wrong tool, wrong provider and too high minimum amounts
+/// 4. Release one escrow and verify it is reported as not locked
+/// 5. Let the other expire and verify it is reported as expired
+#[test]
+fn test_verify_escrow_reasons() {
+    let mut contracts = setup_escrows();
+    let expires = Expiration::AtHeight(contracts.app.block_info().height + DEFAULT_TTL);
+
+    let res = verify(&contracts, 1, DEFAULT_TOOL_ID, PROVIDER, DEFAULT_MAX_FEE);
+    assert!(res.valid);
+    assert_eq!(None, res.reason);
+    assert_eq!(Uint128::new(DEFAULT_MAX_FEE), res.remaining);
+    assert_eq!(Some(expires), res.expires);
+
+    let res = verify(&contracts, 99, DEFAULT_TOOL_ID, PROVIDER, 0);
+    assert!(!res.valid);
+    assert_eq!(Some(VerifyEscrowReason::NotFound), res.reason);
+    assert_eq!(None, res.expires);
+
+    let res = verify(&contracts, 1, "other_tool", PROVIDER, 0);
+    assert_eq!(Some(VerifyEscrowReason::WrongTool), res.reason);
+
+    let res = verify(&contracts, 1, DEFAULT_TOOL_ID, USER, 0);
+    assert_eq!(Some(VerifyEscrowReason::WrongProvider), res.reason);
+
+    let res = verify(&contracts, 1, DEFAULT_TOOL_ID, PROVIDER, DEFAULT_MAX_FEE + 1);
+    assert_eq!(Some(VerifyEscrowReason::Insufficient), res.reason);
+
+    release_funds(&mut contracts, 1, DEFAULT_USAGE_FEE, PROVIDER).unwrap();
+    let res = verify(&contracts, 1, DEFAULT_TOOL_ID, PROVIDER, 0);
+    assert_eq!(Some(VerifyEscrowReason::NotLocked), res.reason);
+
+    contracts.app.update_block(|block| block.height += DEFAULT_TTL + 1);
+    let res = verify(&contracts, 2, DEFAULT_TOOL_ID, PROVIDER, 0);
+    assert_eq!(Some(VerifyEscrowReason::Expired), res.reason);
+}
+
+/// # Test: Frozen Contract
+///
+/// ## Test Steps:
+///
+/// 1. Lock an escrow and freeze the contract via sudo
+/// 2. Verify the otherwise valid escrow is reported as frozen
+#[test]
+fn test_verify_escrow_frozen() {
+    let mut contracts = setup_escrows();
+
+    contracts.app.sudo(
+        CwSudoMsg::Wasm(cw_multi_test::WasmSudo {
+            contract_addr: Addr::unchecked(&contracts.escrow_addr),
+            message: to_json_binary(&SudoMsg::Freeze {}).unwrap(),
+        }),
+    ).unwrap();
+
+    let res = verify(&contracts, 1, DEFAULT_TOOL_ID, PROVIDER, 0);
+    assert!(!res.valid);
+    assert_eq!(Some(VerifyEscrowReason::Frozen), res.reason);
+}
+
+/// # Test: Remaining Amount Capped at the Price
+///
+/// ## Test Steps:
+///
+/// 1. Register a tool priced at 10 and lock an escrow with a max_fee of 100
+/// 2. Verify the remaining amount is the price, and a minimum above it is insufficient
+/// 3. Charge 4 and verify the remaining amount drops to 6
+#[test]
+fn test_verify_escrow_capped_at_price() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, 10, PROVIDER).unwrap();
+    let escrow_id = lock_funds(
+        &mut contracts,
+        DEFAULT_TOOL_ID,
+        DEFAULT_MAX_FEE,
+        DEFAULT_TTL,
+        "capped_token".to_string(),
+        USER,
+        &[Coin {
+            denom: NEUTRON.to_string(),
+            amount: Uint128::new(DEFAULT_MAX_FEE),
+        }],
+    ).unwrap();
+
+    let res = verify(&contracts, escrow_id, DEFAULT_TOOL_ID, PROVIDER, 10);
+    assert!(res.valid);
+    assert_eq!(Uint128::new(10), res.remaining);
+
+    let res = verify(&contracts, escrow_id, DEFAULT_TOOL_ID, PROVIDER, 50);
+    assert_eq!(Some(VerifyEscrowReason::Insufficient), res.reason);
+    assert_eq!(Uint128::new(10), res.remaining);
+
+    execute_as(&mut contracts, PROVIDER, &ExecuteMsg::Charge { escrow_id, amount: Uint128::new(4) }, &[]).unwrap();
+    let res = verify(&contracts, escrow_id, DEFAULT_TOOL_ID, PROVIDER, 0);
+    assert_eq!(Uint128::new(6), res.remaining);
+}
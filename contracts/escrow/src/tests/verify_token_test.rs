@@ -0,0 +1,135 @@
+//! # Escrow Token Verification Test
+//!
+//! This module tests the commit-reveal scheme for escrow auth tokens.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. LockFunds only accepts a 32 byte sha256 commitment
+//! 2. VerifyEscrowToken accepts the committed token and reports the remaining amount and expiry,
+//!    capped at the tool price recorded at lock time
+//! 3. Wrong tokens, settled escrows and expired escrows are reported as invalid
+//! 4. Escrow queries never return the token itself
+
+use cosmwasm_std::{Addr, Binary, Coin, Uint128};
+use cw_multi_test::Executor;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, QueryMsg, VerifyEscrowTokenResponse};
+use crate::state::Expiration;
+use crate::tests::setup_contract::{
+    auth_commitment, execute_as, lock_funds, query_escrow, register_tool, release_funds, setup_contracts,
+    TestContracts, DEFAULT_MAX_FEE, DEFAULT_TOOL_ID, DEFAULT_TTL, DEFAULT_USAGE_FEE, NEUTRON,
+    PROVIDER, USER,
+};
+
+const TOKEN: &str = "secret-token:4f1c9a";
+
+fn verify(contracts: &TestContracts, escrow_id: u64, token: &str) -> VerifyEscrowTokenResponse {
+    contracts.app.wrap().query_wasm_smart(
+        &contracts.escrow_addr,
+        &QueryMsg::VerifyEscrowToken {
+            escrow_id,
+            token: token.to_string(),
+        },
+    ).unwrap()
+}
+
+fn funds() -> Vec<Coin> {
+    vec![Coin {
+        denom: NEUTRON.to_string(),
+        amount: Uint128::new(DEFAULT_MAX_FEE),
+    }]
+}
+
+/// # Test: Locking Requires a sha256 Commitment
+///
+/// ## Test Steps:
+///
+/// 1. Register a tool
+/// 2. Attempt to lock with a commitment that is not 32 bytes and verify it fails
+#[test]
+fn test_lock_rejects_invalid_commitment() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+    let user_addr = contracts.app.api().addr_make(USER);
+
+    let result = contracts.app.execute_contract(
+        user_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::LockFunds {
+            tool_id: DEFAULT_TOOL_ID.to_string(),
+            max_fee: Uint128::new(DEFAULT_MAX_FEE),
+            expected_price: None,
+            auth_commitment: Binary::from(TOKEN.as_bytes()),
+            expires: Expiration::AtHeight(contracts.app.block_info().height + DEFAULT_TTL),
+            from_deposit: None,
+        },
+        &funds(),
+    );
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::InvalidCommitment { len }) => assert_eq!(TOKEN.len(), len),
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+}
+
+/// # Test: Verifying Tokens Against the Commitment
+///
+/// ## Test Steps:
+///
+/// 1. Lock two escrows committed to known tokens
+/// 2. Verify the right token is valid and reports remaining amount and expiry
+/// 3. Verify a wrong token is invalid
+/// 4. Release the first escrow and verify its token is no longer valid
+/// 5. Let the second escrow expire and verify its token is no longer valid
+#[test]
+fn test_verify_escrow_token() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+    let expires = Expiration::AtHeight(contracts.app.block_info().height + DEFAULT_TTL);
+
+    let first = lock_funds(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, DEFAULT_TTL, TOKEN.to_string(), USER, &funds()).unwrap();
+    let second = lock_funds(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, DEFAULT_TTL, "other-token".to_string(), USER, &funds()).unwrap();
+
+    let res = verify(&contracts, first, TOKEN);
+    assert!(res.valid);
+    assert_eq!(Uint128::new(DEFAULT_MAX_FEE), res.remaining);
+    assert_eq!(expires, res.expires);
+
+    let res = verify(&contracts, first, "other-token");
+    assert!(!res.valid);
+    assert_eq!(Uint128::zero(), res.remaining);
+
+    // Only the commitment is stored and returned
+    let escrow = query_escrow(&contracts, first).unwrap();
+    assert_eq!(auth_commitment(TOKEN), escrow.auth_commitment);
+
+    release_funds(&mut contracts, first, DEFAULT_USAGE_FEE, PROVIDER).unwrap();
+    assert!(!verify(&contracts, first, TOKEN).valid);
+
+    assert!(verify(&contracts, second, "other-token").valid);
+    contracts.app.update_block(|block| block.height += DEFAULT_TTL + 1);
+    assert!(!verify(&contracts, second, "other-token").valid);
+}
+
+/// # Test: Remaining Amount Capped at the Price
+///
+/// ## Test Steps:
+///
+/// 1. Register a tool priced at 10 and lock an escrow with a max_fee of 100
+/// 2. Verify the token reports the price as the remaining amount
+/// 3. Charge 4 and verify the remaining amount drops to 6
+#[test]
+fn test_verify_escrow_token_capped_at_price() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, 10, PROVIDER).unwrap();
+    let escrow_id = lock_funds(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, DEFAULT_TTL, TOKEN.to_string(), USER, &funds()).unwrap();
+
+    let res = verify(&contracts, escrow_id, TOKEN);
+    assert!(res.valid);
+    assert_eq!(Uint128::new(10), res.remaining);
+
+    execute_as(&mut contracts, PROVIDER, &ExecuteMsg::Charge { escrow_id, amount: Uint128::new(4) }, &[]).unwrap();
+    assert_eq!(Uint128::new(6), verify(&contracts, escrow_id, TOKEN).remaining);
+}
@@ -1,10 +1,21 @@
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
-use cw2::set_contract_version;
+use cosmwasm_std::{
+    to_json_binary, Addr, Api, Binary, Deps, DepsMut, Empty, Env, Event, MessageInfo, Order, Response, StdResult,
+    Storage, Uint128,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::Bound;
+use semver::Version;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, ToolResponse};
-use crate::state::{ToolMeta, TOOLS};
+use crate::migrations;
+use crate::msg::{
+    ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, PendingTransferResponse, ProviderStatusResponse,
+    QueryMsg, ToolResponse, ToolsResponse,
+};
+use crate::state::{
+    Config, ExpiryBounds, PendingTransfer, SubscriptionTerms, Tombstone, ToolMeta, BLOCKED_PROVIDERS, CONFIG, PENDING_TRANSFERS, TOMBSTONES, TOOLS,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:registry";
@@ -13,6 +24,19 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 // Default denomination for backward compatibility
 const DEFAULT_DENOM: &str = "untrn";
 
+// Prefix of denoms that refer to a CW20 token contract, e.g. "cw20:neutron1..."
+pub const CW20_DENOM_PREFIX: &str = "cw20:";
+
+// Pagination limits for GetTools
+const DEFAULT_LIMIT: u32 = 30;
+const MAX_LIMIT: u32 = 100;
+
+// Number of blocks a retired tool ID stays reserved for its last provider (~1 week at 6s blocks)
+const TOMBSTONE_BLOCKS: u64 = 100_800;
+
+// Maximum number of tools a single GetTools query will scan while applying filters
+const MAX_SCAN: usize = 1000;
+
 /// Validates endpoint URL format and length
 fn validate_endpoint(endpoint: &str) -> Result<(), ContractError> {
     // Check length constraint (≤ 512 characters)
@@ -28,32 +52,60 @@ fn validate_endpoint(endpoint: &str) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// Validates that a CW20 denom refers to a valid contract address
+fn validate_denom(api: &dyn Api, denom: &str) -> Result<(), ContractError> {
+    if let Some(token) = denom.strip_prefix(CW20_DENOM_PREFIX) {
+        api.addr_validate(token).map_err(|_| ContractError::InvalidCw20Denom { denom: denom.to_string() })?;
+    }
+    
+    Ok(())
+}
+
+/// Loads the config and verifies the sender is the registry admin
+fn ensure_admin(storage: &dyn Storage, sender: &Addr) -> Result<Config, ContractError> {
+    let config = CONFIG.load(storage)?;
+    if *sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(config)
+}
+
 #[entry_point]
 pub fn instantiate(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    _msg: InstantiateMsg,
+    msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     // Set contract version for migration info
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     
+    // Use provided admin or default to the instantiating account
+    let admin = match msg.admin {
+        Some(admin) => deps.api.addr_validate(&admin)?,
+        None => info.sender.clone(),
+    };
+    CONFIG.save(deps.storage, &Config { admin: admin.clone() })?;
+    
     // Return success response
     Ok(Response::new()
         .add_attribute("method", "instantiate")
-        .add_attribute("owner", info.sender))
+        .add_attribute("owner", info.sender)
+        .add_attribute("admin", admin))
 }
 
 #[entry_point]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::RegisterTool { tool_id, price, denom, description, endpoint } => 
-            execute_register_tool(deps, info, tool_id, price, denom, description, endpoint),
+            execute_register_tool(deps, env, info, tool_id, price, denom, description, endpoint),
+        ExecuteMsg::UnregisterTool { tool_id } => 
+            execute_unregister_tool(deps, env, info, tool_id),
         ExecuteMsg::UpdatePrice { tool_id, price } => 
             execute_update_price(deps, info, tool_id, price),
         ExecuteMsg::PauseTool { tool_id } => 
@@ -64,12 +116,36 @@ pub fn execute(
             execute_update_denom(deps, info, tool_id, denom),
         ExecuteMsg::UpdateEndpoint { tool_id, endpoint } => 
             execute_update_endpoint(deps, info, tool_id, endpoint),
+        ExecuteMsg::UpdateSubscriptionTerms { tool_id, subscription } => 
+            execute_update_subscription_terms(deps, info, tool_id, subscription),
+        ExecuteMsg::UpdateExpiryBounds { tool_id, expiry_bounds } => 
+            execute_update_expiry_bounds(deps, info, tool_id, expiry_bounds),
+        ExecuteMsg::UpdateCancellable { tool_id, cancellable } => 
+            execute_update_cancellable(deps, info, tool_id, cancellable),
+        ExecuteMsg::ProposeToolTransfer { tool_id, new_provider } => 
+            execute_propose_tool_transfer(deps, info, tool_id, new_provider),
+        ExecuteMsg::AcceptToolTransfer { tool_id } => 
+            execute_accept_tool_transfer(deps, info, tool_id),
+        ExecuteMsg::CancelToolTransfer { tool_id } => 
+            execute_cancel_tool_transfer(deps, info, tool_id),
+        ExecuteMsg::UpdateConfig { admin } => 
+            execute_update_config(deps, info, admin),
+        ExecuteMsg::ForcePauseTool { tool_id, reason } => 
+            execute_force_pause_tool(deps, info, tool_id, reason),
+        ExecuteMsg::DelistTool { tool_id, reason } => 
+            execute_delist_tool(deps, env, info, tool_id, reason),
+        ExecuteMsg::BlockProvider { provider, reason } => 
+            execute_block_provider(deps, info, provider, reason),
+        ExecuteMsg::UnblockProvider { provider } => 
+            execute_unblock_provider(deps, info, provider),
     }
 }
 
 // RegisterTool handler implementation
+#[allow(clippy::too_many_arguments)]
 pub fn execute_register_tool(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     tool_id: String,
     price: cosmwasm_std::Uint128,
@@ -90,11 +166,33 @@ pub fn execute_register_tool(
     // Validate endpoint
     validate_endpoint(&endpoint)?;
     
+    // Never overwrite an existing registration
+    if TOOLS.has(deps.storage, &tool_id) {
+        return Err(ContractError::ToolAlreadyExists {});
+    }
+    
     // Store provider address from info.sender
     let provider = info.sender;
     
+    // Blocked providers cannot list new tools
+    if BLOCKED_PROVIDERS.has(deps.storage, &provider) {
+        return Err(ContractError::ProviderBlocked {});
+    }
+    
+    // A retired ID stays reserved for its last provider until the tombstone period ends,
+    // while delisted IDs are reserved for nobody
+    if let Some(tombstone) = TOMBSTONES.may_load(deps.storage, &tool_id)? {
+        let available_at = tombstone.retired_at + TOMBSTONE_BLOCKS;
+        let reserved_for_sender = !tombstone.delisted && tombstone.provider == provider;
+        if !reserved_for_sender && env.block.height < available_at {
+            return Err(ContractError::ToolIdRetired { available_at });
+        }
+        TOMBSTONES.remove(deps.storage, &tool_id);
+    }
+    
     // Use provided denom or default to "untrn"
     let denom = denom.unwrap_or_else(|| DEFAULT_DENOM.to_string());
+    validate_denom(deps.api, &denom)?;
     
     // Store tool metadata in TOOLS map
     let tool = ToolMeta {
@@ -104,6 +202,10 @@ pub fn execute_register_tool(
         is_active: true,
         description: description.clone(),
         endpoint: endpoint.clone(),
+        suspended: false,
+        subscription: None,
+        expiry_bounds: None,
+        cancellable: false,
     };
     
     TOOLS.save(deps.storage, &tool_id, &tool)?;
@@ -120,6 +222,37 @@ pub fn execute_register_tool(
         .add_attribute("endpoint", endpoint))
 }
 
+// UnregisterTool handler implementation
+pub fn execute_unregister_tool(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tool_id: String,
+) -> Result<Response, ContractError> {
+    // Load existing tool
+    let tool = TOOLS.may_load(deps.storage, &tool_id)?
+        .ok_or(ContractError::ToolNotFound {})?;
+    
+    // Verify sender is the provider
+    if info.sender != tool.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Remove the tool and reserve its ID for the provider
+    TOOLS.remove(deps.storage, &tool_id)?;
+    PENDING_TRANSFERS.remove(deps.storage, &tool_id);
+    TOMBSTONES.save(deps.storage, &tool_id, &Tombstone {
+        provider: tool.provider,
+        retired_at: env.block.height,
+        delisted: false,
+    })?;
+    
+    Ok(Response::new()
+        .add_attribute("method", "unregister_tool")
+        .add_attribute("tool_id", tool_id)
+        .add_attribute("reserved_until", (env.block.height + TOMBSTONE_BLOCKS).to_string()))
+}
+
 // UpdatePrice handler implementation
 pub fn execute_update_price(
     deps: DepsMut,
@@ -180,16 +313,41 @@ pub fn execute_resume_tool(
     let mut tool = TOOLS.may_load(deps.storage, &tool_id)?
         .ok_or(ContractError::ToolNotFound {})?;
     
-    // Verify sender is the provider
-    if info.sender != tool.provider {
+    // Suspended tools can only be resumed by the admin, which lifts the suspension
+    let unsuspended = tool.suspended;
+    if tool.suspended {
+        let config = CONFIG.load(deps.storage)?;
+        if info.sender == config.admin {
+            tool.suspended = false;
+        } else if info.sender == tool.provider {
+            return Err(ContractError::ToolSuspended {});
+        } else {
+            return Err(ContractError::Unauthorized {});
+        }
+    } else if info.sender != tool.provider {
         return Err(ContractError::Unauthorized {});
     }
     
+    // Blocked providers cannot bring tools back online
+    if BLOCKED_PROVIDERS.has(deps.storage, &tool.provider) {
+        return Err(ContractError::ProviderBlocked {});
+    }
+    
     // Set is_active to true
     tool.is_active = true;
     TOOLS.save(deps.storage, &tool_id, &tool)?;
     
-    Ok(Response::new()
+    let mut response = Response::new();
+    if unsuspended {
+        response = response.add_event(
+            Event::new("wasm-toolpay.tool_unsuspended")
+                .add_attribute("admin", info.sender)
+                .add_attribute("tool_id", tool_id.clone())
+                .add_attribute("provider", tool.provider),
+        );
+    }
+    
+    Ok(response
         .add_attribute("method", "resume_tool")
         .add_attribute("tool_id", tool_id))
 }
@@ -210,8 +368,10 @@ pub fn execute_update_denom(
         return Err(ContractError::Unauthorized {});
     }
     
+    validate_denom(deps.api, &denom)?;
+    
     // Update denom and save
-    tool.denom = denom.clone();
+    tool.denom.clone_from(&denom);
     TOOLS.save(deps.storage, &tool_id, &tool)?;
     
     Ok(Response::new()
@@ -240,7 +400,7 @@ pub fn execute_update_endpoint(
     }
     
     // Update endpoint and save
-    tool.endpoint = endpoint.clone();
+    tool.endpoint.clone_from(&endpoint);
     TOOLS.save(deps.storage, &tool_id, &tool)?;
     
     Ok(Response::new()
@@ -249,11 +409,406 @@ pub fn execute_update_endpoint(
         .add_attribute("new_endpoint", endpoint))
 }
 
+// UpdateSubscriptionTerms handler implementation
+pub fn execute_update_subscription_terms(
+    deps: DepsMut,
+    info: MessageInfo,
+    tool_id: String,
+    subscription: Option<SubscriptionTerms>,
+) -> Result<Response, ContractError> {
+    // Load existing tool
+    let mut tool = TOOLS.may_load(deps.storage, &tool_id)?
+        .ok_or(ContractError::ToolNotFound {})?;
+    
+    // Verify sender is the provider
+    if info.sender != tool.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Periods and their price must be non-zero
+    if let Some(terms) = &subscription {
+        if terms.price.is_zero() || terms.period == 0 {
+            return Err(ContractError::InvalidSubscriptionTerms {});
+        }
+    }
+    
+    // Update subscription terms and save
+    tool.subscription.clone_from(&subscription);
+    TOOLS.save(deps.storage, &tool_id, &tool)?;
+    
+    let response = Response::new()
+        .add_attribute("method", "update_subscription_terms")
+        .add_attribute("tool_id", tool_id);
+    Ok(match subscription {
+        Some(terms) => response
+            .add_attribute("subscription_price", terms.price.to_string())
+            .add_attribute("subscription_period", terms.period.to_string()),
+        None => response.add_attribute("subscription_price", "none"),
+    })
+}
+
+// UpdateExpiryBounds handler implementation
+pub fn execute_update_expiry_bounds(
+    deps: DepsMut,
+    info: MessageInfo,
+    tool_id: String,
+    expiry_bounds: Option<ExpiryBounds>,
+) -> Result<Response, ContractError> {
+    // Load existing tool
+    let mut tool = TOOLS.may_load(deps.storage, &tool_id)?
+        .ok_or(ContractError::ToolNotFound {})?;
+    
+    // Verify sender is the provider
+    if info.sender != tool.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    if let Some(bounds) = &expiry_bounds {
+        if bounds.min_blocks > bounds.max_blocks || bounds.min_seconds > bounds.max_seconds {
+            return Err(ContractError::InvalidExpiryBounds {});
+        }
+    }
+    
+    // Update expiry bounds and save
+    tool.expiry_bounds.clone_from(&expiry_bounds);
+    TOOLS.save(deps.storage, &tool_id, &tool)?;
+    
+    let response = Response::new()
+        .add_attribute("method", "update_expiry_bounds")
+        .add_attribute("tool_id", tool_id);
+    Ok(match expiry_bounds {
+        Some(bounds) => response
+            .add_attribute("ttl_blocks", format!("{}-{}", bounds.min_blocks, bounds.max_blocks))
+            .add_attribute("ttl_seconds", format!("{}-{}", bounds.min_seconds, bounds.max_seconds)),
+        None => response.add_attribute("ttl_blocks", "default"),
+    })
+}
+
+// UpdateCancellable handler implementation
+pub fn execute_update_cancellable(
+    deps: DepsMut,
+    info: MessageInfo,
+    tool_id: String,
+    cancellable: bool,
+) -> Result<Response, ContractError> {
+    // Load existing tool
+    let mut tool = TOOLS.may_load(deps.storage, &tool_id)?
+        .ok_or(ContractError::ToolNotFound {})?;
+    
+    // Verify sender is the provider
+    if info.sender != tool.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Update cancellable flag and save
+    tool.cancellable = cancellable;
+    TOOLS.save(deps.storage, &tool_id, &tool)?;
+    
+    Ok(Response::new()
+        .add_attribute("method", "update_cancellable")
+        .add_attribute("tool_id", tool_id)
+        .add_attribute("cancellable", cancellable.to_string()))
+}
+
+// ProposeToolTransfer handler implementation
+pub fn execute_propose_tool_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+    tool_id: String,
+    new_provider: String,
+) -> Result<Response, ContractError> {
+    // Load existing tool
+    let tool = TOOLS.may_load(deps.storage, &tool_id)?
+        .ok_or(ContractError::ToolNotFound {})?;
+    
+    // Verify sender is the provider
+    if info.sender != tool.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Record the proposal, replacing any earlier one
+    let new_provider = deps.api.addr_validate(&new_provider)?;
+    PENDING_TRANSFERS.save(deps.storage, &tool_id, &PendingTransfer {
+        from: tool.provider,
+        to: new_provider.clone(),
+    })?;
+    
+    Ok(Response::new()
+        .add_attribute("method", "propose_tool_transfer")
+        .add_attribute("tool_id", tool_id)
+        .add_attribute("new_provider", new_provider))
+}
+
+// AcceptToolTransfer handler implementation
+pub fn execute_accept_tool_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+    tool_id: String,
+) -> Result<Response, ContractError> {
+    let transfer = PENDING_TRANSFERS.may_load(deps.storage, &tool_id)?
+        .ok_or(ContractError::NoPendingTransfer {})?;
+    
+    // Verify sender is the proposed provider
+    if info.sender != transfer.to {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Blocked providers cannot take over tools
+    if BLOCKED_PROVIDERS.has(deps.storage, &transfer.to) {
+        return Err(ContractError::ProviderBlocked {});
+    }
+    
+    // Load existing tool
+    let mut tool = TOOLS.may_load(deps.storage, &tool_id)?
+        .ok_or(ContractError::ToolNotFound {})?;
+    
+    // Hand the tool over; escrows already locked keep the provider recorded at lock time
+    tool.provider = transfer.to;
+    TOOLS.save(deps.storage, &tool_id, &tool)?;
+    PENDING_TRANSFERS.remove(deps.storage, &tool_id);
+    
+    Ok(Response::new()
+        .add_attribute("method", "accept_tool_transfer")
+        .add_attribute("tool_id", tool_id)
+        .add_attribute("previous_provider", transfer.from)
+        .add_attribute("provider", tool.provider))
+}
+
+// CancelToolTransfer handler implementation
+pub fn execute_cancel_tool_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+    tool_id: String,
+) -> Result<Response, ContractError> {
+    let transfer = PENDING_TRANSFERS.may_load(deps.storage, &tool_id)?
+        .ok_or(ContractError::NoPendingTransfer {})?;
+    
+    // Either side of the transfer can call it off
+    if info.sender != transfer.from && info.sender != transfer.to {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    PENDING_TRANSFERS.remove(deps.storage, &tool_id);
+    
+    Ok(Response::new()
+        .add_attribute("method", "cancel_tool_transfer")
+        .add_attribute("tool_id", tool_id))
+}
+
+// UpdateConfig handler implementation
+pub fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    admin: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = ensure_admin(deps.storage, &info.sender)?;
+    
+    if let Some(admin) = admin {
+        config.admin = deps.api.addr_validate(&admin)?;
+    }
+    CONFIG.save(deps.storage, &config)?;
+    
+    let event = Event::new("wasm-toolpay.registry_config_updated")
+        .add_attribute("updated_by", info.sender)
+        .add_attribute("admin", config.admin.to_string());
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("method", "update_config")
+        .add_attribute("admin", config.admin))
+}
+
+// ForcePauseTool handler implementation
+pub fn execute_force_pause_tool(
+    deps: DepsMut,
+    info: MessageInfo,
+    tool_id: String,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    ensure_admin(deps.storage, &info.sender)?;
+    
+    // Load existing tool
+    let mut tool = TOOLS.may_load(deps.storage, &tool_id)?
+        .ok_or(ContractError::ToolNotFound {})?;
+    
+    // Deactivate and lock the tool so the provider cannot resume it
+    tool.is_active = false;
+    tool.suspended = true;
+    TOOLS.save(deps.storage, &tool_id, &tool)?;
+    
+    let event = Event::new("wasm-toolpay.tool_force_paused")
+        .add_attribute("admin", info.sender)
+        .add_attribute("tool_id", tool_id.clone())
+        .add_attribute("provider", tool.provider)
+        .add_attribute("reason", reason.unwrap_or_default());
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("method", "force_pause_tool")
+        .add_attribute("tool_id", tool_id))
+}
+
+// DelistTool handler implementation
+pub fn execute_delist_tool(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tool_id: String,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    ensure_admin(deps.storage, &info.sender)?;
+    
+    // Load existing tool
+    let tool = TOOLS.may_load(deps.storage, &tool_id)?
+        .ok_or(ContractError::ToolNotFound {})?;
+    
+    // Remove the tool and retire its ID without reserving it for the provider
+    TOOLS.remove(deps.storage, &tool_id)?;
+    PENDING_TRANSFERS.remove(deps.storage, &tool_id);
+    TOMBSTONES.save(deps.storage, &tool_id, &Tombstone {
+        provider: tool.provider.clone(),
+        retired_at: env.block.height,
+        delisted: true,
+    })?;
+    
+    let event = Event::new("wasm-toolpay.tool_delisted")
+        .add_attribute("admin", info.sender)
+        .add_attribute("tool_id", tool_id.clone())
+        .add_attribute("provider", tool.provider)
+        .add_attribute("reason", reason.unwrap_or_default());
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("method", "delist_tool")
+        .add_attribute("tool_id", tool_id))
+}
+
+// BlockProvider handler implementation
+pub fn execute_block_provider(
+    deps: DepsMut,
+    info: MessageInfo,
+    provider: String,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    ensure_admin(deps.storage, &info.sender)?;
+    
+    let provider = deps.api.addr_validate(&provider)?;
+    BLOCKED_PROVIDERS.save(deps.storage, &provider, &Empty {})?;
+    
+    // Take the provider's existing tools offline as if each had been force-paused,
+    // so they stop accepting escrows and only the admin can bring them back
+    let tools = TOOLS.idx.provider
+        .prefix(provider.to_string())
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let suspended_tools = tools.len();
+    for (tool_id, mut tool) in tools {
+        tool.is_active = false;
+        tool.suspended = true;
+        TOOLS.save(deps.storage, &tool_id, &tool)?;
+    }
+    
+    let event = Event::new("wasm-toolpay.provider_blocked")
+        .add_attribute("admin", info.sender)
+        .add_attribute("provider", provider.to_string())
+        .add_attribute("suspended_tools", suspended_tools.to_string())
+        .add_attribute("reason", reason.unwrap_or_default());
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("method", "block_provider")
+        .add_attribute("provider", provider))
+}
+
+// UnblockProvider handler implementation
+pub fn execute_unblock_provider(
+    deps: DepsMut,
+    info: MessageInfo,
+    provider: String,
+) -> Result<Response, ContractError> {
+    ensure_admin(deps.storage, &info.sender)?;
+    
+    let provider = deps.api.addr_validate(&provider)?;
+    BLOCKED_PROVIDERS.remove(deps.storage, &provider);
+    
+    let event = Event::new("wasm-toolpay.provider_unblocked")
+        .add_attribute("admin", info.sender)
+        .add_attribute("provider", provider.to_string());
+    
+    Ok(Response::new()
+        .add_event(event)
+        .add_attribute("method", "unblock_provider")
+        .add_attribute("provider", provider))
+}
+
+#[entry_point]
+pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    // Only upgrade state written by this contract, and never to an older version
+    let stored = get_contract_version(deps.storage)?;
+    if stored.contract != CONTRACT_NAME {
+        return Err(ContractError::InvalidContractName {
+            expected: CONTRACT_NAME.to_string(),
+            found: stored.contract,
+        });
+    }
+    let stored_version: Version = stored.version.parse()?;
+    let new_version: Version = CONTRACT_VERSION.parse()?;
+    if stored_version > new_version {
+        return Err(ContractError::CannotDowngrade {
+            from: stored.version,
+            to: CONTRACT_VERSION.to_string(),
+        });
+    }
+    
+    let mut response = Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", stored.version)
+        .add_attribute("to_version", CONTRACT_VERSION);
+    
+    // Run state transforms for every release between the stored and the new version
+    if stored_version < Version::new(0, 2, 0) {
+        let migrated = migrations::migrate_from_v0_1(deps.branch(), msg.admin)?;
+        response = response.add_attribute("migrated_tools", migrated.to_string());
+    } else if let Some(admin) = msg.admin {
+        let admin = deps.api.addr_validate(&admin)?;
+        CONFIG.save(deps.storage, &Config { admin })?;
+    }
+    
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    
+    Ok(response)
+}
+
 #[entry_point]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::GetTool { tool_id } => query_tool(deps, tool_id),
-        QueryMsg::GetTools {} => query_all_tools(deps),
+        QueryMsg::GetTools { start_after, limit, provider, denom, active_only, max_price } =>
+            query_all_tools(deps, start_after, limit, provider, denom, active_only, max_price),
+        QueryMsg::GetPendingTransfer { tool_id } => query_pending_transfer(deps, tool_id),
+        QueryMsg::GetConfig {} => query_config(deps),
+        QueryMsg::GetProviderStatus { provider } => query_provider_status(deps, provider),
+        QueryMsg::ListToolsByProvider { provider, start_after, limit } =>
+            query_tools_by_provider(deps, provider, start_after, limit),
+        QueryMsg::ListToolsByDenom { denom, start_after, limit } =>
+            query_tools_by_denom(deps, denom, start_after, limit),
+    }
+}
+
+// Converts stored tool metadata into the public query response
+fn to_tool_response(tool_id: String, tool_meta: ToolMeta) -> ToolResponse {
+    ToolResponse {
+        tool_id,
+        provider: tool_meta.provider.to_string(),
+        price: tool_meta.price,
+        denom: tool_meta.denom,
+        is_active: tool_meta.is_active,
+        description: tool_meta.description,
+        endpoint: tool_meta.endpoint,
+        suspended: tool_meta.suspended,
+        subscription: tool_meta.subscription,
+        expiry_bounds: tool_meta.expiry_bounds,
+        cancellable: tool_meta.cancellable,
     }
 }
 
@@ -262,40 +817,127 @@ pub fn query_tool(deps: Deps, tool_id: String) -> StdResult<Binary> {
     let tool = TOOLS.may_load(deps.storage, &tool_id)?;
     
     match tool {
-        Some(tool_meta) => {
-            let response = ToolResponse {
-                tool_id,
-                provider: tool_meta.provider.to_string(),
-                price: tool_meta.price,
-                denom: tool_meta.denom,
-                is_active: tool_meta.is_active,
-                description: tool_meta.description,
-                endpoint: tool_meta.endpoint,
-            };
-            to_json_binary(&response)
-        },
+        Some(tool_meta) => to_json_binary(&to_tool_response(tool_id, tool_meta)),
         None => to_json_binary(&Option::<ToolResponse>::None),
     }
 }
 
+// GetPendingTransfer query implementation
+pub fn query_pending_transfer(deps: Deps, tool_id: String) -> StdResult<Binary> {
+    let response = PENDING_TRANSFERS
+        .may_load(deps.storage, &tool_id)?
+        .map(|transfer| PendingTransferResponse {
+            tool_id,
+            provider: transfer.from.to_string(),
+            new_provider: transfer.to.to_string(),
+        });
+    to_json_binary(&response)
+}
+
+// GetConfig query implementation
+pub fn query_config(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_json_binary(&ConfigResponse {
+        admin: config.admin.to_string(),
+    })
+}
+
+// GetProviderStatus query implementation
+pub fn query_provider_status(deps: Deps, provider: String) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&provider)?;
+    to_json_binary(&ProviderStatusResponse {
+        blocked: BLOCKED_PROVIDERS.has(deps.storage, &addr),
+        provider,
+    })
+}
+
 // GetTools query implementation
-pub fn query_all_tools(deps: Deps) -> StdResult<Binary> {
-    let tools: StdResult<Vec<_>> = TOOLS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .map(|item| {
-            let (tool_id, tool_meta) = item?;
-            Ok(ToolResponse {
-                tool_id: tool_id.to_string(),
-                provider: tool_meta.provider.to_string(),
-                price: tool_meta.price,
-                denom: tool_meta.denom,
-                is_active: tool_meta.is_active,
-                description: tool_meta.description,
-                endpoint: tool_meta.endpoint,
-            })
-        })
-        .collect();
-
-    let response = crate::msg::ToolsResponse { tools: tools? };
+//
+// When a provider, denom or active_only filter is given the matching secondary index
+// is walked instead of the whole map. Remaining filters are applied while walking, so
+// a page may hold fewer than `limit` tools when the scan budget runs out.
+// `next_start_after` always points at the last tool examined, which lets clients keep
+// paging until it comes back as None.
+pub fn query_all_tools(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    provider: Option<String>,
+    denom: Option<String>,
+    active_only: Option<bool>,
+    max_price: Option<Uint128>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT) as usize;
+    let active_only = active_only.unwrap_or(false);
+
+    // Pick the narrowest index available for the requested filters
+    let items: Box<dyn Iterator<Item = StdResult<(String, ToolMeta)>>> = if let Some(provider) = &provider {
+        let start = start_after.map(Bound::exclusive);
+        TOOLS.idx.provider.prefix(provider.clone()).range(deps.storage, start, None, Order::Ascending)
+    } else if let Some(denom) = &denom {
+        let start = start_after.map(Bound::exclusive);
+        TOOLS.idx.denom.prefix(denom.clone()).range(deps.storage, start, None, Order::Ascending)
+    } else if active_only {
+        let start = start_after.map(Bound::exclusive);
+        TOOLS.idx.is_active.prefix(1).range(deps.storage, start, None, Order::Ascending)
+    } else {
+        let start = start_after.as_deref().map(Bound::exclusive);
+        TOOLS.range(deps.storage, start, None, Order::Ascending)
+    };
+
+    let mut tools = Vec::new();
+    let mut last_seen = None;
+    let mut exhausted = true;
+
+    for (scanned, item) in items.enumerate() {
+        // Stop once the page is full or the scan budget is spent
+        if tools.len() >= limit || scanned >= MAX_SCAN {
+            exhausted = false;
+            break;
+        }
+
+        let (tool_id, tool_meta) = item?;
+        last_seen = Some(tool_id.clone());
+
+        if provider.as_ref().is_some_and(|p| tool_meta.provider.as_str() != p) {
+            continue;
+        }
+        if denom.as_ref().is_some_and(|d| tool_meta.denom != *d) {
+            continue;
+        }
+        if active_only && !tool_meta.is_active {
+            continue;
+        }
+        if max_price.is_some_and(|max| tool_meta.price > max) {
+            continue;
+        }
+
+        tools.push(to_tool_response(tool_id, tool_meta));
+    }
+
+    let response = ToolsResponse {
+        tools,
+        next_start_after: if exhausted { None } else { last_seen },
+    };
     to_json_binary(&response)
-}
\ No newline at end of file
+}
+
+// ListToolsByProvider query implementation
+pub fn query_tools_by_provider(
+    deps: Deps,
+    provider: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    query_all_tools(deps, start_after, limit, Some(provider), None, None, None)
+}
+
+// ListToolsByDenom query implementation
+pub fn query_tools_by_denom(
+    deps: Deps,
+    denom: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    query_all_tools(deps, start_after, limit, None, Some(denom), None, None)
+}
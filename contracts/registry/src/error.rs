@@ -9,12 +9,48 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("No pending transfer for this tool")]
+    NoPendingTransfer {},
+
+    #[error("Provider is blocked")]
+    ProviderBlocked {},
+
+    #[error("Tool is suspended by the admin")]
+    ToolSuspended {},
+
+    #[error("An admin address is required to migrate state without a registry config")]
+    MissingAdmin {},
+
+    #[error("Cannot migrate from contract {found}, expected {expected}")]
+    InvalidContractName { expected: String, found: String },
+
+    #[error("Cannot migrate from version {from} to older version {to}")]
+    CannotDowngrade { from: String, to: String },
+
+    #[error("Semver parsing error: {0}")]
+    SemVer(String),
+
     #[error("Tool ID must be 16 characters or less")]
     ToolIdTooLong {},
 
     #[error("Tool not found")]
     ToolNotFound {},
+
+    #[error("Tool ID is already registered")]
+    ToolAlreadyExists {},
+
+    #[error("Tool ID was retired and cannot be registered by another provider before block {available_at}")]
+    ToolIdRetired { available_at: u64 },
     
+    #[error("Subscription price and period must be greater than zero")]
+    InvalidSubscriptionTerms {},
+
+    #[error("Expiry bounds must not have a minimum above the maximum")]
+    InvalidExpiryBounds {},
+
+    #[error("Invalid CW20 denom {denom}: expected cw20:<contract address>")]
+    InvalidCw20Denom { denom: String },
+
     #[error("Description must be 256 characters or less")]
     DescriptionTooLong {},
 
@@ -24,3 +60,9 @@ pub enum ContractError {
     #[error("Endpoint must start with https://")]
     InvalidEndpointFormat {},
 }
+
+impl From<semver::Error> for ContractError {
+    fn from(err: semver::Error) -> Self {
+        Self::SemVer(err.to_string())
+    }
+}
@@ -0,0 +1,65 @@
+//! State migrations between released versions of the Registry contract.
+//!
+//! Each `migrate_from_*` function upgrades storage written by an older release to the
+//! current layout. Legacy layouts are kept here so they can be read back exactly as
+//! they were stored.
+
+use cosmwasm_std::{DepsMut, Order, StdResult};
+
+use crate::error::ContractError;
+use crate::state::{Config, ToolMeta, CONFIG, TOOLS};
+
+/// Storage layout of the 0.1.x releases
+pub mod v0_1 {
+    use cosmwasm_std::{Addr, Uint128};
+    use cw_storage_plus::Map;
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+
+    /// Tool metadata as stored by 0.1.x
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    pub struct ToolMeta {
+        pub provider: Addr,
+        pub price: Uint128,
+        pub denom: String,
+        pub is_active: bool,
+        pub description: String,
+        pub endpoint: String,
+    }
+
+    /// Plain, non-indexed tool map used by 0.1.x
+    pub const TOOLS: Map<&str, ToolMeta> = Map::new("tools");
+}
+
+/// Upgrades 0.1.x state: records the registry admin and rewrites every tool in the
+/// current layout, which also builds the secondary indexes.
+///
+/// Returns the number of migrated tools.
+pub fn migrate_from_v0_1(deps: DepsMut, admin: Option<String>) -> Result<usize, ContractError> {
+    // 0.1.x had no admin, so one must be supplied
+    let admin = admin.ok_or(ContractError::MissingAdmin {})?;
+    let admin = deps.api.addr_validate(&admin)?;
+    CONFIG.save(deps.storage, &Config { admin })?;
+
+    let tools = v0_1::TOOLS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (tool_id, legacy) in &tools {
+        let tool = ToolMeta {
+            provider: legacy.provider.clone(),
+            price: legacy.price,
+            denom: legacy.denom.clone(),
+            is_active: legacy.is_active,
+            description: legacy.description.clone(),
+            endpoint: legacy.endpoint.clone(),
+            suspended: false,
+            subscription: None,
+            expiry_bounds: None,
+            cancellable: false,
+        };
+        TOOLS.save(deps.storage, tool_id, &tool)?;
+    }
+
+    Ok(tools.len())
+}
@@ -1,9 +1,21 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Uint128;
 
-/// InstantiateMsg is empty for the Registry contract MVP
+use crate::state::{ExpiryBounds, SubscriptionTerms};
+
+/// InstantiateMsg sets up the registry configuration
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Registry admin address, defaults to the instantiating account
+    pub admin: Option<String>,
+}
+
+/// MigrateMsg carries settings that did not exist in older versions of the registry
 #[cw_serde]
-pub struct InstantiateMsg {}
+pub struct MigrateMsg {
+    /// Admin address to record; required when migrating from 0.1.x, which had no admin
+    pub admin: Option<String>,
+}
 
 /// ExecuteMsg defines the set of available contract actions
 #[cw_serde]
@@ -14,7 +26,7 @@ pub enum ExecuteMsg {
         tool_id: String,
         /// Price to use the tool, in base currency units
         price: Uint128,
-        /// Token denomination for the tool price (e.g. "untrn" or IBC denom)
+        /// Token denomination for the tool price (e.g. "untrn", IBC denom or "cw20:<contract address>")
         /// If not provided, defaults to "untrn" for backward compatibility
         denom: Option<String>,
         /// Description of the tool (max 256 characters)
@@ -22,6 +34,11 @@ pub enum ExecuteMsg {
         /// API endpoint URL for the tool (max 512 characters, must start with https://)
         endpoint: String,
     },
+    /// Unregister a tool, leaving a tombstone that reserves its ID for the provider
+    UnregisterTool {
+        /// Tool identifier to unregister
+        tool_id: String,
+    },
     /// Update the price of an existing tool
     UpdatePrice {
         /// Existing tool identifier
@@ -33,7 +50,7 @@ pub enum ExecuteMsg {
     UpdateDenom {
         /// Existing tool identifier
         tool_id: String,
-        /// New token denomination for the tool price
+        /// New token denomination for the tool price, "cw20:<contract address>" for CW20 tokens
         denom: String,
     },
     /// Update the endpoint of an existing tool
@@ -49,10 +66,81 @@ pub enum ExecuteMsg {
         tool_id: String,
     },
     /// Resume a paused tool (make it available for use again)
+    /// Suspended tools can only be resumed by the admin, which emits a tool_unsuspended event
     ResumeTool {
         /// Tool identifier to resume
         tool_id: String,
     },
+    /// Set or remove the subscription pricing of an existing tool
+    UpdateSubscriptionTerms {
+        /// Existing tool identifier
+        tool_id: String,
+        /// New subscription terms, None stops offering subscriptions
+        subscription: Option<SubscriptionTerms>,
+    },
+    /// Set or remove the escrow TTL bounds of an existing tool
+    UpdateExpiryBounds {
+        /// Existing tool identifier
+        tool_id: String,
+        /// New bounds, None falls back to the escrow contract's defaults
+        expiry_bounds: Option<ExpiryBounds>,
+    },
+    /// Allow or forbid callers to cancel escrows for a tool before the provider acknowledges them
+    UpdateCancellable {
+        /// Existing tool identifier
+        tool_id: String,
+        /// Whether escrows locked from now on can be cancelled
+        cancellable: bool,
+    },
+    /// Propose handing a tool over to another provider; takes effect once accepted
+    ProposeToolTransfer {
+        /// Tool identifier to transfer
+        tool_id: String,
+        /// Address that will become the tool provider
+        new_provider: String,
+    },
+    /// Accept a pending transfer, called by the proposed new provider
+    AcceptToolTransfer {
+        /// Tool identifier being transferred
+        tool_id: String,
+    },
+    /// Cancel a pending transfer, called by the current or the proposed provider
+    CancelToolTransfer {
+        /// Tool identifier with a pending transfer
+        tool_id: String,
+    },
+    /// Admin only: update the registry configuration
+    UpdateConfig {
+        /// New admin address (optional)
+        admin: Option<String>,
+    },
+    /// Admin only: pause a tool so that its provider cannot resume it
+    ForcePauseTool {
+        /// Tool identifier to suspend
+        tool_id: String,
+        /// Reason shown in the moderation event (optional)
+        reason: Option<String>,
+    },
+    /// Admin only: remove a tool from the registry and retire its ID
+    DelistTool {
+        /// Tool identifier to delist
+        tool_id: String,
+        /// Reason shown in the moderation event (optional)
+        reason: Option<String>,
+    },
+    /// Admin only: prevent a provider from registering or resuming tools, and suspend
+    /// every tool they currently list
+    BlockProvider {
+        /// Provider address to block
+        provider: String,
+        /// Reason shown in the moderation event (optional)
+        reason: Option<String>,
+    },
+    /// Admin only: lift a provider block; their suspended tools stay offline until the admin resumes them
+    UnblockProvider {
+        /// Provider address to unblock
+        provider: String,
+    },
 }
 
 /// QueryMsg defines the set of available queries on the contract
@@ -66,9 +154,62 @@ pub enum QueryMsg {
         tool_id: String,
     },
     
-    /// GetTools returns a list of all registered tools
+    /// GetTools returns a page of registered tools, optionally filtered
     #[returns(ToolsResponse)]
-    GetTools {},
+    GetTools {
+        /// Pagination cursor - start after this tool ID (optional)
+        start_after: Option<String>,
+        /// Maximum number of tools to return (default: 30, max: 100)
+        limit: Option<u32>,
+        /// Filter by provider address (optional)
+        provider: Option<String>,
+        /// Filter by token denomination (optional)
+        denom: Option<String>,
+        /// If true, only active tools are returned (optional)
+        active_only: Option<bool>,
+        /// Filter out tools priced above this amount (optional)
+        max_price: Option<Uint128>,
+    },
+
+    /// GetPendingTransfer returns the pending ownership transfer of a tool if there is one
+    #[returns(Option<PendingTransferResponse>)]
+    GetPendingTransfer {
+        /// Tool identifier to query
+        tool_id: String,
+    },
+
+    /// GetConfig returns the registry configuration
+    #[returns(ConfigResponse)]
+    GetConfig {},
+
+    /// GetProviderStatus returns whether a provider is blocked
+    #[returns(ProviderStatusResponse)]
+    GetProviderStatus {
+        /// Provider address to check
+        provider: String,
+    },
+
+    /// ListToolsByProvider returns a page of tools registered by a provider
+    #[returns(ToolsResponse)]
+    ListToolsByProvider {
+        /// Provider address to list tools for
+        provider: String,
+        /// Pagination cursor - start after this tool ID (optional)
+        start_after: Option<String>,
+        /// Maximum number of tools to return (default: 30, max: 100)
+        limit: Option<u32>,
+    },
+
+    /// ListToolsByDenom returns a page of tools priced in a denomination
+    #[returns(ToolsResponse)]
+    ListToolsByDenom {
+        /// Token denomination to list tools for
+        denom: String,
+        /// Pagination cursor - start after this tool ID (optional)
+        start_after: Option<String>,
+        /// Maximum number of tools to return (default: 30, max: 100)
+        limit: Option<u32>,
+    },
 }
 
 /// ToolResponse is the return type for a GetTool query
@@ -88,11 +229,48 @@ pub struct ToolResponse {
     pub description: String,
     /// API endpoint URL for the tool (max 512 characters)
     pub endpoint: String,
+    /// Whether the tool was force-paused by the admin
+    pub suspended: bool,
+    /// Subscription pricing, if the tool offers subscriptions
+    pub subscription: Option<SubscriptionTerms>,
+    /// Escrow TTL bounds for this tool, None if the escrow defaults apply
+    pub expiry_bounds: Option<ExpiryBounds>,
+    /// Whether callers may cancel unacknowledged escrows for this tool
+    pub cancellable: bool,
 }
 
 /// ToolsResponse is the return type for a GetTools query
 #[cw_serde]
 pub struct ToolsResponse {
-    /// List of all registered tools
+    /// Tools in this page, ordered by tool ID
     pub tools: Vec<ToolResponse>,
+    /// Cursor to pass as `start_after` for the next page, None when the listing is complete
+    pub next_start_after: Option<String>,
+}
+
+/// ConfigResponse is the return type for a GetConfig query
+#[cw_serde]
+pub struct ConfigResponse {
+    /// Registry admin address
+    pub admin: String,
+}
+
+/// ProviderStatusResponse is the return type for a GetProviderStatus query
+#[cw_serde]
+pub struct ProviderStatusResponse {
+    /// Provider address
+    pub provider: String,
+    /// Whether the provider is blocked by the admin
+    pub blocked: bool,
+}
+
+/// PendingTransferResponse is the return type for a GetPendingTransfer query
+#[cw_serde]
+pub struct PendingTransferResponse {
+    /// Tool identifier
+    pub tool_id: String,
+    /// Current provider that proposed the transfer
+    pub provider: String,
+    /// Address that must accept the transfer
+    pub new_provider: String,
 }
@@ -1,8 +1,15 @@
-use cosmwasm_std::{Addr, Uint128};
-use cw_storage_plus::Map;
+use cosmwasm_std::{Addr, Empty, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Config holds registry-wide settings
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    /// Address allowed to moderate tools and providers
+    pub admin: Addr,
+}
+
 /// ToolMeta contains metadata about a registered tool
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ToolMeta {
@@ -10,7 +17,7 @@ pub struct ToolMeta {
     pub provider: Addr,
     /// Price to use the tool, in base currency units
     pub price: Uint128,
-    /// Token denomination for the tool price (e.g. "untrn" or IBC denom)
+    /// Token denomination for the tool price (e.g. "untrn", IBC denom or "cw20:<contract address>")
     pub denom: String,
     /// Whether the tool is currently active and available for use
     pub is_active: bool,
@@ -18,8 +25,99 @@ pub struct ToolMeta {
     pub description: String,
     /// API endpoint URL for the tool (max 512 characters, must start with https://)
     pub endpoint: String,
+    /// Whether the admin has force-paused the tool; only the admin can resume it
+    #[serde(default)]
+    pub suspended: bool,
+    /// Flat-fee subscription pricing offered alongside per-call pricing (optional)
+    #[serde(default)]
+    pub subscription: Option<SubscriptionTerms>,
+    /// Escrow TTL bounds replacing the escrow contract's defaults for this tool (optional)
+    #[serde(default)]
+    pub expiry_bounds: Option<ExpiryBounds>,
+    /// Whether callers may cancel escrows for this tool until the provider acknowledges them
+    #[serde(default)]
+    pub cancellable: bool,
+}
+
+/// SubscriptionTerms advertises recurring pricing for a tool
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SubscriptionTerms {
+    /// Price of one period, in the tool's denomination
+    pub price: Uint128,
+    /// Length of one period in blocks
+    pub period: u64,
 }
 
-/// TOOLS maps tool_id strings to their metadata
-pub const TOOLS: Map<&str, ToolMeta> = Map::new("tools");
+/// ExpiryBounds limits how far ahead an escrow for a tool may expire
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ExpiryBounds {
+    /// Minimum TTL of a height-based expiration, in blocks
+    pub min_blocks: u64,
+    /// Maximum TTL of a height-based expiration, in blocks
+    pub max_blocks: u64,
+    /// Minimum TTL of a time-based expiration, in seconds
+    pub min_seconds: u64,
+    /// Maximum TTL of a time-based expiration, in seconds
+    pub max_seconds: u64,
+}
+
+/// CONFIG stores the registry configuration
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// BLOCKED_PROVIDERS holds provider addresses the admin has barred from listing tools
+pub const BLOCKED_PROVIDERS: Map<&Addr, Empty> = Map::new("blocked_providers");
+
+/// Secondary indexes over registered tools
+pub struct ToolIndexes<'a> {
+    /// Tools by provider address
+    pub provider: MultiIndex<'a, String, ToolMeta, String>,
+    /// Tools by price denomination
+    pub denom: MultiIndex<'a, String, ToolMeta, String>,
+    /// Tools by active flag (1 = active, 0 = paused)
+    pub is_active: MultiIndex<'a, u8, ToolMeta, String>,
+}
+
+impl IndexList<ToolMeta> for ToolIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<ToolMeta>> + '_> {
+        let v: Vec<&dyn Index<ToolMeta>> = vec![&self.provider, &self.denom, &self.is_active];
+        Box::new(v.into_iter())
+    }
+}
+
+/// TOOLS maps tool_id strings to their metadata, indexed by provider, denom and active flag
+pub const TOOLS: IndexedMap<&str, ToolMeta, ToolIndexes> = IndexedMap::new(
+    "tools",
+    ToolIndexes {
+        provider: MultiIndex::new(|_pk, t| t.provider.to_string(), "tools", "tools__provider"),
+        denom: MultiIndex::new(|_pk, t| t.denom.clone(), "tools", "tools__denom"),
+        is_active: MultiIndex::new(|_pk, t| t.is_active as u8, "tools", "tools__is_active"),
+    },
+);
+
+
+/// Tombstone left behind when a provider unregisters a tool
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Tombstone {
+    /// Provider that owned the tool when it was retired
+    pub provider: Addr,
+    /// Block height at which the tool was unregistered
+    pub retired_at: u64,
+    /// Whether the tool was delisted by the admin; delisted IDs are not reserved for the provider
+    #[serde(default)]
+    pub delisted: bool,
+}
+
+/// TOMBSTONES maps retired tool_id strings to who retired them and when
+pub const TOMBSTONES: Map<&str, Tombstone> = Map::new("tombstones");
+
+/// PendingTransfer records a proposed change of a tool's provider
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingTransfer {
+    /// Provider that proposed the transfer
+    pub from: Addr,
+    /// Address that must accept the transfer to become the provider
+    pub to: Addr,
+}
 
+/// PENDING_TRANSFERS maps tool_id strings to their proposed ownership transfer
+pub const PENDING_TRANSFERS: Map<&str, PendingTransfer> = Map::new("pending_transfers");
@@ -0,0 +1,257 @@
+//! # Admin Moderation Test
+//!
+//! This module tests the registry admin role and its moderation powers.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. The instantiating account becomes admin and can hand the role over with UpdateConfig
+//! 2. Only the admin can force-pause a tool, and the provider cannot resume it afterwards
+//! 3. Delisting removes a tool and keeps its ID from being reclaimed by the provider
+//! 4. Blocked providers cannot register tools until they are unblocked, and their existing tools are suspended
+//! 5. Every admin action emits a moderation event
+//!
+//! ## Relation to Requirements
+//!
+//! Without an owner nobody but the provider could act on a fraudulent or malicious tool.
+
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
+use cosmwasm_std::{from_json, Addr, Response, Uint128};
+use crate::contract::{execute, execute_register_tool, query_config, query_provider_status, query_tool};
+use crate::error::ContractError;
+use crate::msg::{ConfigResponse, ExecuteMsg, ProviderStatusResponse, ToolResponse};
+use crate::tests::setup_contract::{register_tool_with_default_endpoint, setup_contract};
+
+fn event_attr(res: &Response, ty: &str, key: &str) -> Option<String> {
+    res.events
+        .iter()
+        .find(|e| e.ty == ty)
+        .and_then(|e| e.attributes.iter().find(|a| a.key == key))
+        .map(|a| a.value.clone())
+}
+
+/// # Test: Admin Is Recorded and Can Be Rotated
+///
+/// ## Test Steps:
+///
+/// 1. Setup the contract and verify the creator is admin
+/// 2. Attempt UpdateConfig as a non-admin and verify it fails
+/// 3. Rotate the admin and verify the new admin is stored and the event is emitted
+#[test]
+fn admin_can_update_config() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+
+    let config: ConfigResponse = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!("creator", config.admin);
+
+    let new_admin = deps.api.addr_make("new_admin");
+    let msg = ExecuteMsg::UpdateConfig { admin: Some(new_admin.to_string()) };
+
+    let err = execute(deps.as_mut(), mock_env(), message_info(&Addr::unchecked("provider1"), &[]), msg.clone()).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let res = execute(deps.as_mut(), mock_env(), message_info(&Addr::unchecked("creator"), &[]), msg).unwrap();
+    assert_eq!(Some(new_admin.to_string()), event_attr(&res, "wasm-toolpay.registry_config_updated", "admin"));
+
+    let config: ConfigResponse = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(new_admin.to_string(), config.admin);
+
+    // The previous admin has lost its powers
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&Addr::unchecked("creator"), &[]),
+        ExecuteMsg::UpdateConfig { admin: None },
+    ).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+}
+
+/// # Test: Force-Paused Tools Stay Paused Until the Admin Resumes Them
+///
+/// ## Test Steps:
+///
+/// 1. Register a tool as provider1
+/// 2. Attempt ForcePauseTool as the provider and verify it fails
+/// 3. Force-pause as admin and verify the tool is inactive and suspended
+/// 4. Verify the provider cannot resume the tool
+/// 5. Resume as admin and verify the suspension is lifted with an event
+/// 6. Verify a later resume by the provider emits no moderation event
+#[test]
+fn force_pause_locks_out_provider() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+    register_tool_with_default_endpoint(deps.as_mut(), "provider1", "tool1".to_string(), Uint128::new(100), "Tool".to_string()).unwrap();
+
+    let provider = message_info(&Addr::unchecked("provider1"), &[]);
+    let admin = message_info(&Addr::unchecked("creator"), &[]);
+    let force_pause = ExecuteMsg::ForcePauseTool {
+        tool_id: "tool1".to_string(),
+        reason: Some("phishing endpoint".to_string()),
+    };
+
+    let err = execute(deps.as_mut(), mock_env(), provider.clone(), force_pause.clone()).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let res = execute(deps.as_mut(), mock_env(), admin.clone(), force_pause).unwrap();
+    assert_eq!(Some("tool1".to_string()), event_attr(&res, "wasm-toolpay.tool_force_paused", "tool_id"));
+    assert_eq!(Some("phishing endpoint".to_string()), event_attr(&res, "wasm-toolpay.tool_force_paused", "reason"));
+
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert!(!tool.is_active);
+    assert!(tool.suspended);
+
+    let resume = ExecuteMsg::ResumeTool { tool_id: "tool1".to_string() };
+    let err = execute(deps.as_mut(), mock_env(), provider.clone(), resume.clone()).unwrap_err();
+    assert_eq!(ContractError::ToolSuspended {}, err);
+
+    let res = execute(deps.as_mut(), mock_env(), admin, resume.clone()).unwrap();
+    assert_eq!(Some("tool1".to_string()), event_attr(&res, "wasm-toolpay.tool_unsuspended", "tool_id"));
+    assert_eq!(Some("creator".to_string()), event_attr(&res, "wasm-toolpay.tool_unsuspended", "admin"));
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert!(tool.is_active);
+    assert!(!tool.suspended);
+
+    // A provider's own resume is not a moderation action
+    execute(deps.as_mut(), mock_env(), provider.clone(), ExecuteMsg::PauseTool { tool_id: "tool1".to_string() }).unwrap();
+    let res = execute(deps.as_mut(), mock_env(), provider, resume).unwrap();
+    assert_eq!(None, event_attr(&res, "wasm-toolpay.tool_unsuspended", "tool_id"));
+}
+
+/// # Test: Delisted Tools Are Removed and Their ID Is Not Reserved
+///
+/// ## Test Steps:
+///
+/// 1. Register a tool as provider1
+/// 2. Delist it as admin and verify it is gone and the event is emitted
+/// 3. Verify the provider cannot register the same ID again
+#[test]
+fn delist_tool_removes_tool() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+    register_tool_with_default_endpoint(deps.as_mut(), "provider1", "tool1".to_string(), Uint128::new(100), "Tool".to_string()).unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&Addr::unchecked("creator"), &[]),
+        ExecuteMsg::DelistTool { tool_id: "tool1".to_string(), reason: None },
+    ).unwrap();
+    assert_eq!(Some("provider1".to_string()), event_attr(&res, "wasm-toolpay.tool_delisted", "provider"));
+
+    let tool: Option<ToolResponse> = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert!(tool.is_none());
+
+    let err = register_tool_with_default_endpoint(deps.as_mut(), "provider1", "tool1".to_string(), Uint128::new(100), "Tool".to_string()).unwrap_err();
+    assert!(matches!(err, ContractError::ToolIdRetired { .. }));
+}
+
+/// # Test: Blocked Providers Cannot Register Tools
+///
+/// ## Test Steps:
+///
+/// 1. Block a provider as admin and verify the status query and event
+/// 2. Verify the blocked provider cannot register a tool
+/// 3. Unblock the provider and verify registration now succeeds
+#[test]
+fn blocked_provider_cannot_register() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+
+    let bad_provider = deps.api.addr_make("bad_provider");
+    let admin = message_info(&Addr::unchecked("creator"), &[]);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&Addr::unchecked("provider1"), &[]),
+        ExecuteMsg::BlockProvider { provider: bad_provider.to_string(), reason: None },
+    ).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        admin.clone(),
+        ExecuteMsg::BlockProvider { provider: bad_provider.to_string(), reason: Some("fraud".to_string()) },
+    ).unwrap();
+    assert_eq!(Some("fraud".to_string()), event_attr(&res, "wasm-toolpay.provider_blocked", "reason"));
+
+    let status: ProviderStatusResponse = from_json(query_provider_status(deps.as_ref(), bad_provider.to_string()).unwrap()).unwrap();
+    assert!(status.blocked);
+
+    let register = |deps: cosmwasm_std::DepsMut| execute_register_tool(
+        deps,
+        mock_env(),
+        message_info(&bad_provider, &[]),
+        "tool1".to_string(),
+        Uint128::new(100),
+        None,
+        "Tool".to_string(),
+        "https://api.example.com/tool".to_string(),
+    );
+
+    let err = register(deps.as_mut()).unwrap_err();
+    assert_eq!(ContractError::ProviderBlocked {}, err);
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        admin,
+        ExecuteMsg::UnblockProvider { provider: bad_provider.to_string() },
+    ).unwrap();
+    assert_eq!(Some(bad_provider.to_string()), event_attr(&res, "wasm-toolpay.provider_unblocked", "provider"));
+
+    let status: ProviderStatusResponse = from_json(query_provider_status(deps.as_ref(), bad_provider.to_string()).unwrap()).unwrap();
+    assert!(!status.blocked);
+    register(deps.as_mut()).unwrap();
+}
+
+/// # Test: Blocking a Provider Suspends Their Tools
+///
+/// ## Test Steps:
+///
+/// 1. Register two tools for a provider and one for another provider
+/// 2. Block the first provider and verify both of their tools are inactive and suspended
+/// 3. Verify the other provider's tool is untouched
+/// 4. Unblock the provider and verify the tools stay suspended until the admin resumes them
+#[test]
+fn blocking_provider_suspends_tools() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+    let bad_provider = deps.api.addr_make("bad_provider");
+    register_tool_with_default_endpoint(deps.as_mut(), bad_provider.as_str(), "tool1".to_string(), Uint128::new(100), "Tool".to_string()).unwrap();
+    register_tool_with_default_endpoint(deps.as_mut(), bad_provider.as_str(), "tool2".to_string(), Uint128::new(100), "Tool".to_string()).unwrap();
+    register_tool_with_default_endpoint(deps.as_mut(), "provider1", "tool3".to_string(), Uint128::new(100), "Tool".to_string()).unwrap();
+
+    let admin = message_info(&Addr::unchecked("creator"), &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        admin.clone(),
+        ExecuteMsg::BlockProvider { provider: bad_provider.to_string(), reason: None },
+    ).unwrap();
+    assert_eq!(Some("2".to_string()), event_attr(&res, "wasm-toolpay.provider_blocked", "suspended_tools"));
+
+    for tool_id in ["tool1", "tool2"] {
+        let tool: ToolResponse = from_json(query_tool(deps.as_ref(), tool_id.to_string()).unwrap()).unwrap();
+        assert!(!tool.is_active);
+        assert!(tool.suspended);
+    }
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool3".to_string()).unwrap()).unwrap();
+    assert!(tool.is_active);
+    assert!(!tool.suspended);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        admin.clone(),
+        ExecuteMsg::UnblockProvider { provider: bad_provider.to_string() },
+    ).unwrap();
+    let resume = ExecuteMsg::ResumeTool { tool_id: "tool1".to_string() };
+    let err = execute(deps.as_mut(), mock_env(), message_info(&bad_provider, &[]), resume.clone()).unwrap_err();
+    assert_eq!(ContractError::ToolSuspended {}, err);
+    execute(deps.as_mut(), mock_env(), admin, resume).unwrap();
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert!(tool.is_active);
+}
@@ -0,0 +1,53 @@
+//! # Cancellable Test
+//! 
+//! This module tests opting tools in to caller cancellations in the Registry contract.
+//! 
+//! ## Test Coverage
+//! 
+//! This test verifies that:
+//! 1. Newly registered tools do not allow cancellations
+//! 2. The provider can allow and forbid cancellations
+//! 3. Only the provider can change the flag
+
+use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::{Addr, from_json, Uint128};
+use crate::contract::{execute_update_cancellable, query_tool};
+use crate::error::ContractError;
+use crate::msg::ToolResponse;
+use crate::tests::setup_contract::{register_tool_with_default_endpoint, setup_contract};
+
+/// # Test: Updating the Cancellable Flag
+/// 
+/// ## Test Steps:
+/// 
+/// 1. Register a tool and verify it is not cancellable
+/// 2. Allow cancellations and verify GetTool reports it
+/// 3. Verify another address cannot change the flag
+/// 4. Forbid cancellations again
+#[test]
+fn update_cancellable() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+    register_tool_with_default_endpoint(
+        deps.as_mut(), "provider1", "tool1".to_string(), Uint128::new(10), "Cancellable tool".to_string(),
+    ).unwrap();
+
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert!(!tool.cancellable);
+
+    let provider = message_info(&Addr::unchecked("provider1"), &[]);
+    let res = execute_update_cancellable(deps.as_mut(), provider.clone(), "tool1".to_string(), true).unwrap();
+    assert_eq!("update_cancellable", res.attributes[0].value);
+    assert_eq!("true", res.attributes[2].value);
+
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert!(tool.cancellable);
+
+    let other = message_info(&Addr::unchecked("provider2"), &[]);
+    let err = execute_update_cancellable(deps.as_mut(), other, "tool1".to_string(), false).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    execute_update_cancellable(deps.as_mut(), provider, "tool1".to_string(), false).unwrap();
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert!(!tool.cancellable);
+}
@@ -0,0 +1,70 @@
+//! # Expiry Bounds Test
+//! 
+//! This module tests per-tool escrow TTL bounds in the Registry contract.
+//! 
+//! ## Test Coverage
+//! 
+//! This test verifies that:
+//! 1. Newly registered tools use the escrow defaults
+//! 2. The provider can set and remove expiry bounds
+//! 3. Bounds with a minimum above the maximum are rejected
+//! 4. Only the provider can change the bounds
+
+use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::{Addr, from_json, Uint128};
+use crate::contract::{execute_update_expiry_bounds, query_tool};
+use crate::error::ContractError;
+use crate::msg::ToolResponse;
+use crate::state::ExpiryBounds;
+use crate::tests::setup_contract::{register_tool_with_default_endpoint, setup_contract};
+
+/// # Test: Updating Expiry Bounds
+/// 
+/// ## Test Steps:
+/// 
+/// 1. Register a tool and verify it has no expiry bounds
+/// 2. Set bounds and verify they are returned by GetTool
+/// 3. Verify bounds with a minimum above the maximum are rejected
+/// 4. Verify another address cannot change the bounds
+/// 5. Remove the bounds and verify the tool falls back to the defaults
+#[test]
+fn update_expiry_bounds() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+    register_tool_with_default_endpoint(
+        deps.as_mut(), "provider1", "tool1".to_string(), Uint128::new(10), "Long running tool".to_string(),
+    ).unwrap();
+
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert_eq!(None, tool.expiry_bounds);
+
+    let provider = message_info(&Addr::unchecked("provider1"), &[]);
+    let bounds = ExpiryBounds { min_blocks: 5, max_blocks: 200, min_seconds: 30, max_seconds: 1200 };
+    let res = execute_update_expiry_bounds(
+        deps.as_mut(), provider.clone(), "tool1".to_string(), Some(bounds.clone()),
+    ).unwrap();
+    assert_eq!("update_expiry_bounds", res.attributes[0].value);
+    assert_eq!("5-200", res.attributes[2].value);
+    assert_eq!("30-1200", res.attributes[3].value);
+
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert_eq!(Some(bounds), tool.expiry_bounds);
+
+    for invalid in [
+        ExpiryBounds { min_blocks: 201, max_blocks: 200, min_seconds: 30, max_seconds: 1200 },
+        ExpiryBounds { min_blocks: 5, max_blocks: 200, min_seconds: 1201, max_seconds: 1200 },
+    ] {
+        let err = execute_update_expiry_bounds(
+            deps.as_mut(), provider.clone(), "tool1".to_string(), Some(invalid),
+        ).unwrap_err();
+        assert_eq!(ContractError::InvalidExpiryBounds {}, err);
+    }
+
+    let other = message_info(&Addr::unchecked("provider2"), &[]);
+    let err = execute_update_expiry_bounds(deps.as_mut(), other, "tool1".to_string(), None).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    execute_update_expiry_bounds(deps.as_mut(), provider, "tool1".to_string(), None).unwrap();
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert_eq!(None, tool.expiry_bounds);
+}
@@ -0,0 +1,75 @@
+//! # Migration Version Checks Test
+//!
+//! This module tests the guards of the Registry migrate entry point.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Migrating state written by a different contract is refused
+//! 2. Migrating to an older version than the stored one is refused
+//! 3. Migrating from 0.1.0 requires an admin and records it
+//! 4. Re-running the migration on current state succeeds and keeps the config
+//!
+//! ## Relation to Requirements
+//!
+//! Deployed registries must be upgradable in place without risking their tool data.
+
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::from_json;
+use crate::contract::{migrate, query_config};
+use crate::error::ContractError;
+use crate::msg::{ConfigResponse, MigrateMsg};
+use crate::tests::setup_contract::setup_contract;
+
+/// # Test: Migration Refuses Foreign Contracts and Downgrades
+///
+/// ## Test Steps:
+///
+/// 1. Store a different contract name and verify migrate fails
+/// 2. Store a newer version and verify migrate fails
+#[test]
+fn migrate_rejects_wrong_contract_and_downgrade() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+
+    cw2::set_contract_version(deps.as_mut().storage, "crates.io:escrow", "0.1.0").unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg { admin: None }).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidContractName { .. }));
+
+    cw2::set_contract_version(deps.as_mut().storage, "crates.io:registry", "9.0.0").unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg { admin: None }).unwrap_err();
+    assert_eq!(
+        ContractError::CannotDowngrade {
+            from: "9.0.0".to_string(),
+            to: env!("CARGO_PKG_VERSION").to_string(),
+        },
+        err
+    );
+}
+
+/// # Test: Migration from 0.1.0 Records the Admin
+///
+/// ## Test Steps:
+///
+/// 1. Store version 0.1.0 and verify migrate without an admin fails
+/// 2. Migrate with an admin and verify it is stored and the version is bumped
+/// 3. Migrate again without an admin and verify the config is kept
+#[test]
+fn migrate_from_v0_1_requires_admin() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+    cw2::set_contract_version(deps.as_mut().storage, "crates.io:registry", "0.1.0").unwrap();
+
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg { admin: None }).unwrap_err();
+    assert_eq!(ContractError::MissingAdmin {}, err);
+
+    let admin = deps.api.addr_make("admin").to_string();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg { admin: Some(admin.clone()) }).unwrap();
+
+    let version = cw2::get_contract_version(deps.as_ref().storage).unwrap();
+    assert_eq!(env!("CARGO_PKG_VERSION"), version.version);
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg { admin: None }).unwrap();
+    let config: ConfigResponse = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(admin, config.admin);
+}
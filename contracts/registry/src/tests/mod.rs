@@ -1,5 +1,6 @@
 pub mod setup_contract;
 mod register_tool_success;
+mod register_tool_duplicate;
 mod register_tool_invalid_id;
 mod register_tool_invalid_description;
 mod register_tool_invalid_endpoint;
@@ -8,7 +9,15 @@ mod update_price_success;
 mod pause_resume_tool;
 mod query_tool_functionality;
 mod query_all_tools;
+mod query_tools_pagination;
+mod list_tools_by_index;
 mod query_endpoint_functionality;
 mod unauthorized_pause_resume;
 mod update_denom_test;
 mod update_endpoint_test;
+mod admin_moderation;
+mod tool_transfer;
+mod migrate_test;
+mod subscription_terms;
+mod expiry_bounds;
+mod cancellable;
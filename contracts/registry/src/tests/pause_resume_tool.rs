@@ -16,7 +16,7 @@
 //! Task 2.2 of the implementation plan, which requires the ability to pause and resume tools.
 //! This enables providers to temporarily disable their tools while maintaining ownership.
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{Addr, from_json, Uint128};
 use crate::contract::{execute_pause_tool, execute_register_tool, execute_resume_tool, query_tool};
 use crate::msg::ToolResponse;
@@ -46,7 +46,7 @@ fn pause_resume_tool() {
     let price = Uint128::new(100);
     let description = "Tool that can be paused and resumed".to_string();
     let endpoint = "https://api.provider1.com/pausable-tool".to_string();
-    execute_register_tool(deps.as_mut(), info.clone(), tool_id.clone(), price, None, description.clone(), endpoint).unwrap();
+    execute_register_tool(deps.as_mut(), mock_env(), info.clone(), tool_id.clone(), price, None, description.clone(), endpoint).unwrap();
 
     // Pause the tool and verify the response
     let res = execute_pause_tool(deps.as_mut(), info.clone(), tool_id.clone()).unwrap();
@@ -54,7 +54,7 @@ fn pause_resume_tool() {
 
     // Query tool state and verify it's inactive after pausing
     let query_res = query_tool(deps.as_ref(), tool_id.clone()).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
     assert!(!tool_response.is_active);
 
     // Resume the tool and verify the response
@@ -63,6 +63,6 @@ fn pause_resume_tool() {
 
     // Query tool state and verify it's active again after resuming
     let query_res = query_tool(deps.as_ref(), tool_id).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
     assert!(tool_response.is_active);
 }
@@ -14,7 +14,7 @@
 //! This test validates the contract's ability to list all available tools,
 //! which is essential for discovery in the UI and integration with other components.
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{from_json, Addr, Uint128};
 use crate::contract::{execute_register_tool, query_all_tools};
 use crate::msg::ToolsResponse;
@@ -37,8 +37,8 @@ fn query_all_tools_functionality() {
     setup_contract(deps.as_mut()).unwrap();
 
     // Check empty state first
-    let query_res = query_all_tools(deps.as_ref()).unwrap();
-    let tools_response: ToolsResponse = from_json(&query_res).unwrap();
+    let query_res = query_all_tools(deps.as_ref(), None, None, None, None, None, None).unwrap();
+    let tools_response: ToolsResponse = from_json(query_res).unwrap();
     assert!(tools_response.tools.is_empty(), "Expected empty tools list before registration");
 
     // Register first tool
@@ -47,7 +47,7 @@ fn query_all_tools_functionality() {
     let price1 = Uint128::new(100);
     let desc1 = "First tool description".to_string();
     let endpoint1 = "https://api.provider1.com/tool1".to_string();
-    execute_register_tool(deps.as_mut(), info1, tool_id1.clone(), price1, None, desc1.clone(), endpoint1.clone()).unwrap();
+    execute_register_tool(deps.as_mut(), mock_env(), info1, tool_id1.clone(), price1, None, desc1.clone(), endpoint1.clone()).unwrap();
 
     // Register second tool
     let info2 = message_info(&Addr::unchecked("provider2"), &[]);
@@ -56,7 +56,7 @@ fn query_all_tools_functionality() {
     let desc2 = "Second tool description".to_string();
     let endpoint2 = "https://api.provider2.com/tool2".to_string();
     let denom2 = Some("uatom".to_string());
-    execute_register_tool(deps.as_mut(), info2, tool_id2.clone(), price2, denom2.clone(), desc2.clone(), endpoint2.clone()).unwrap();
+    execute_register_tool(deps.as_mut(), mock_env(), info2, tool_id2.clone(), price2, denom2.clone(), desc2.clone(), endpoint2.clone()).unwrap();
 
     // Register third tool with same provider as first
     let info3 = message_info(&Addr::unchecked("provider1"), &[]);
@@ -64,11 +64,11 @@ fn query_all_tools_functionality() {
     let price3 = Uint128::new(300);
     let desc3 = "Third tool description".to_string();
     let endpoint3 = "https://api.provider1.com/tool3".to_string();
-    execute_register_tool(deps.as_mut(), info3, tool_id3.clone(), price3, None, desc3.clone(), endpoint3.clone()).unwrap();
+    execute_register_tool(deps.as_mut(), mock_env(), info3, tool_id3.clone(), price3, None, desc3.clone(), endpoint3.clone()).unwrap();
 
     // Query all tools and verify response
-    let query_res = query_all_tools(deps.as_ref()).unwrap();
-    let tools_response: ToolsResponse = from_json(&query_res).unwrap();
+    let query_res = query_all_tools(deps.as_ref(), None, None, None, None, None, None).unwrap();
+    let tools_response: ToolsResponse = from_json(query_res).unwrap();
     
     // Verify we got all 3 tools
     assert_eq!(3, tools_response.tools.len(), "Expected 3 tools in response");
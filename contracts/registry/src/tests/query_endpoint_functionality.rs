@@ -15,7 +15,7 @@
 //! This test validates that the endpoint field is properly included in all
 //! query responses as specified in Task 14.1 of the endpoint feature implementation.
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{Addr, from_json, Uint128};
 use crate::contract::{
     execute_register_tool, 
@@ -54,7 +54,8 @@ fn query_tool_includes_endpoint() {
     let endpoint = "https://api.query-test.com/v1/tool".to_string();
     
     execute_register_tool(
-        deps.as_mut(), 
+        deps.as_mut(),
+        mock_env(), 
         info, 
         tool_id.clone(), 
         price, 
@@ -65,7 +66,7 @@ fn query_tool_includes_endpoint() {
 
     // Query the tool and verify endpoint field
     let query_res = query_tool(deps.as_ref(), tool_id.clone()).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
 
     // Verify all fields including endpoint
     assert_eq!(tool_id, tool_response.tool_id);
@@ -105,6 +106,7 @@ fn query_all_tools_includes_endpoints() {
         let info = message_info(&Addr::unchecked(*provider), &[]);
         execute_register_tool(
             deps.as_mut(),
+            mock_env(),
             info,
             tool_id.to_string(),
             Uint128::new(100),
@@ -115,8 +117,8 @@ fn query_all_tools_includes_endpoints() {
     }
 
     // Query all tools and verify endpoint fields
-    let query_res = query_all_tools(deps.as_ref()).unwrap();
-    let tools_response: ToolsResponse = from_json(&query_res).unwrap();
+    let query_res = query_all_tools(deps.as_ref(), None, None, None, None, None, None).unwrap();
+    let tools_response: ToolsResponse = from_json(query_res).unwrap();
     
     assert_eq!(3, tools_response.tools.len());
 
@@ -125,7 +127,7 @@ fn query_all_tools_includes_endpoints() {
         let tool = tools_response.tools
             .iter()
             .find(|t| t.tool_id == *expected_tool_id)
-            .expect(&format!("Tool {} should be in response", expected_tool_id));
+            .unwrap_or_else(|| panic!("Tool {} should be in response", expected_tool_id));
         
         assert_eq!(*expected_provider, tool.provider);
         assert_eq!(*expected_endpoint, tool.endpoint);
@@ -161,7 +163,8 @@ fn endpoint_persistence_through_operations() {
     let initial_endpoint = "https://api.persistence-test.com/v1".to_string();
     
     execute_register_tool(
-        deps.as_mut(), 
+        deps.as_mut(),
+        mock_env(), 
         info.clone(), 
         tool_id.clone(), 
         price, 
@@ -172,7 +175,7 @@ fn endpoint_persistence_through_operations() {
 
     // Verify initial state
     let query_res = query_tool(deps.as_ref(), tool_id.clone()).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
     assert_eq!(initial_endpoint, tool_response.endpoint);
     assert!(tool_response.is_active);
 
@@ -180,7 +183,7 @@ fn endpoint_persistence_through_operations() {
     execute_update_price(deps.as_mut(), info.clone(), tool_id.clone(), Uint128::new(200)).unwrap();
     
     let query_res = query_tool(deps.as_ref(), tool_id.clone()).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
     assert_eq!(initial_endpoint, tool_response.endpoint);
     assert_eq!(Uint128::new(200), tool_response.price);
 
@@ -188,7 +191,7 @@ fn endpoint_persistence_through_operations() {
     execute_pause_tool(deps.as_mut(), info.clone(), tool_id.clone()).unwrap();
     
     let query_res = query_tool(deps.as_ref(), tool_id.clone()).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
     assert_eq!(initial_endpoint, tool_response.endpoint);
     assert!(!tool_response.is_active);
 
@@ -196,7 +199,7 @@ fn endpoint_persistence_through_operations() {
     execute_resume_tool(deps.as_mut(), info.clone(), tool_id.clone()).unwrap();
     
     let query_res = query_tool(deps.as_ref(), tool_id.clone()).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
     assert_eq!(initial_endpoint, tool_response.endpoint);
     assert!(tool_response.is_active);
 
@@ -205,7 +208,7 @@ fn endpoint_persistence_through_operations() {
     execute_update_endpoint(deps.as_mut(), info, tool_id.clone(), new_endpoint.clone()).unwrap();
     
     let query_res = query_tool(deps.as_ref(), tool_id).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
     assert_eq!(new_endpoint, tool_response.endpoint);
     assert_eq!(Uint128::new(200), tool_response.price); // Price should remain
     assert!(tool_response.is_active); // Status should remain
@@ -243,6 +246,7 @@ fn endpoint_data_integrity() {
         
         execute_register_tool(
             deps.as_mut(),
+            mock_env(),
             info,
             tool_id.clone(),
             Uint128::new(100),
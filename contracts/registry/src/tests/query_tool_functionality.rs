@@ -16,7 +16,7 @@
 //! retrieve tool metadata for integration with the Escrow contract and
 //! front-end applications.
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{Addr, from_json, Uint128};
 use crate::contract::{execute_register_tool, query_tool};
 use crate::msg::ToolResponse;
@@ -45,11 +45,11 @@ fn query_tool_functionality() {
     let price = Uint128::new(100);
     let description = "Tool for query functionality test".to_string();
     let endpoint = "https://api.test.com/tool1".to_string();
-    execute_register_tool(deps.as_mut(), info, tool_id.clone(), price, None, description.clone(), endpoint.clone()).unwrap();
+    execute_register_tool(deps.as_mut(), mock_env(), info, tool_id.clone(), price, None, description.clone(), endpoint.clone()).unwrap();
 
     // Query the registered tool and verify metadata
     let query_res = query_tool(deps.as_ref(), tool_id).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
 
     // Validate all fields match expected values including endpoint
     assert_eq!("provider1", tool_response.provider);
@@ -60,7 +60,7 @@ fn query_tool_functionality() {
 
     // Query a non-existent tool
     let query_res = query_tool(deps.as_ref(), "nonexistent".to_string()).unwrap();
-    let tool_response: Option<ToolResponse> = from_json(&query_res).unwrap();
+    let tool_response: Option<ToolResponse> = from_json(query_res).unwrap();
 
     // Verify that query for non-existent tool returns None
     assert!(tool_response.is_none());
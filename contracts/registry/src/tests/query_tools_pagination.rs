@@ -0,0 +1,112 @@
+//! # Paginated Tools Query Test
+//!
+//! This module tests pagination and filtering of the GetTools query in the Registry contract.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. GetTools honours `limit` and returns a `next_start_after` cursor
+//! 2. Walking the cursor visits every tool exactly once and ends with no cursor
+//! 3. Provider, denom, active_only and max_price filters are applied before the limit
+//!
+//! ## Relation to Requirements
+//!
+//! Unbounded listing runs out of gas once the marketplace grows, so clients must be
+//! able to page through the catalogue and narrow it down on-chain.
+
+use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::{from_json, Addr, Uint128};
+use crate::contract::{execute_pause_tool, query_all_tools};
+use crate::msg::ToolsResponse;
+use crate::tests::setup_contract::{register_tool, register_tool_with_default_endpoint, setup_contract};
+
+/// # Test: Walking the Catalogue with a Cursor
+///
+/// This test ensures that GetTools splits the catalogue into pages and that the
+/// returned cursor lets a client list every tool.
+///
+/// ## Test Steps:
+///
+/// 1. Setup the contract and register five tools
+/// 2. Query pages of two tools, following `next_start_after`
+/// 3. Verify all tools were returned in order and the last page has no cursor
+#[test]
+fn query_tools_paginates_with_cursor() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+
+    for i in 0..5 {
+        register_tool_with_default_endpoint(
+            deps.as_mut(),
+            "provider1",
+            format!("tool{}", i),
+            Uint128::new(100),
+            format!("Tool number {}", i),
+        ).unwrap();
+    }
+
+    let mut seen = vec![];
+    let mut cursor = None;
+    let mut pages = 0;
+    loop {
+        let res = query_all_tools(deps.as_ref(), cursor, Some(2), None, None, None, None).unwrap();
+        let page: ToolsResponse = from_json(res).unwrap();
+        assert!(page.tools.len() <= 2, "Page must not exceed the requested limit");
+        seen.extend(page.tools.into_iter().map(|t| t.tool_id));
+        pages += 1;
+
+        match page.next_start_after {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    assert_eq!(3, pages);
+    assert_eq!(vec!["tool0", "tool1", "tool2", "tool3", "tool4"], seen);
+}
+
+/// # Test: Filters Are Applied Before the Limit
+///
+/// This test verifies that filtered queries fill the page with matching tools
+/// instead of filtering an already truncated page.
+///
+/// ## Test Steps:
+///
+/// 1. Register tools from two providers with different denoms and prices
+/// 2. Pause one of them
+/// 3. Query with each filter and verify only matching tools are returned
+#[test]
+fn query_tools_applies_filters() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+
+    let endpoint = "https://api.example.com/tool".to_string();
+    register_tool(deps.as_mut(), "provider1", "a1".to_string(), Uint128::new(100), None, "a1".to_string(), endpoint.clone()).unwrap();
+    register_tool(deps.as_mut(), "provider1", "a2".to_string(), Uint128::new(500), Some("uatom".to_string()), "a2".to_string(), endpoint.clone()).unwrap();
+    register_tool(deps.as_mut(), "provider2", "b1".to_string(), Uint128::new(50), None, "b1".to_string(), endpoint.clone()).unwrap();
+    register_tool(deps.as_mut(), "provider2", "b2".to_string(), Uint128::new(10), None, "b2".to_string(), endpoint).unwrap();
+    execute_pause_tool(deps.as_mut(), message_info(&Addr::unchecked("provider2"), &[]), "b2".to_string()).unwrap();
+
+    let ids = |res: ToolsResponse| res.tools.into_iter().map(|t| t.tool_id).collect::<Vec<_>>();
+
+    // A limit of 1 with a provider filter must still find the provider's first tool
+    let res = query_all_tools(deps.as_ref(), None, Some(1), Some("provider2".to_string()), None, None, None).unwrap();
+    let page: ToolsResponse = from_json(res).unwrap();
+    assert_eq!(Some("b1".to_string()), page.next_start_after);
+    assert_eq!(vec!["b1"], ids(page));
+
+    let res = query_all_tools(deps.as_ref(), None, None, None, Some("uatom".to_string()), None, None).unwrap();
+    assert_eq!(vec!["a2"], ids(from_json(res).unwrap()));
+
+    let res = query_all_tools(deps.as_ref(), None, None, None, None, Some(true), None).unwrap();
+    assert_eq!(vec!["a1", "a2", "b1"], ids(from_json(res).unwrap()));
+
+    let res = query_all_tools(deps.as_ref(), None, None, None, None, None, Some(Uint128::new(100))).unwrap();
+    assert_eq!(vec!["a1", "b1", "b2"], ids(from_json(res).unwrap()));
+
+    // Combined filters with no match return an empty, complete listing
+    let res = query_all_tools(deps.as_ref(), None, None, Some("provider1".to_string()), None, Some(true), Some(Uint128::new(10))).unwrap();
+    let page: ToolsResponse = from_json(res).unwrap();
+    assert!(page.tools.is_empty());
+    assert_eq!(None, page.next_start_after);
+}
@@ -0,0 +1,147 @@
+//! # Duplicate Registration and Unregister Test
+//!
+//! This module tests how the Registry contract protects existing and retired tool IDs.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Registering an existing tool ID fails with ToolAlreadyExists and leaves the tool untouched
+//! 2. Only the provider can unregister a tool
+//! 3. An unregistered tool ID is reserved for its last provider during the tombstone period
+//! 4. Another provider can claim the ID once the tombstone period has passed
+//!
+//! ## Relation to Requirements
+//!
+//! Silently overwriting a registration would let any address take over another
+//! provider's tool and redirect the payments of every escrow locked afterwards.
+
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
+use cosmwasm_std::{from_json, Addr, DepsMut, Env, Response, Uint128};
+use crate::contract::{execute_register_tool, execute_unregister_tool, query_tool};
+use crate::error::ContractError;
+use crate::msg::ToolResponse;
+use crate::tests::setup_contract::{register_tool_with_default_endpoint, setup_contract};
+
+/// # Test: Re-registering an Existing Tool ID Is Rejected
+///
+/// This test ensures that a second RegisterTool call for the same ID fails, whether it
+/// comes from another provider or from the owner, and that the stored tool is unchanged.
+///
+/// ## Test Steps:
+///
+/// 1. Register "tool1" as provider1
+/// 2. Attempt to register "tool1" again as provider2 and as provider1
+/// 3. Verify both attempts fail and the tool still belongs to provider1 at the original price
+#[test]
+fn register_existing_tool_id_fails() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+
+    register_tool_with_default_endpoint(deps.as_mut(), "provider1", "tool1".to_string(), Uint128::new(100), "Original".to_string()).unwrap();
+
+    for sender in ["provider2", "provider1"] {
+        let err = execute_register_tool(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(sender), &[]),
+            "tool1".to_string(),
+            Uint128::new(1),
+            None,
+            "Hijacked".to_string(),
+            "https://evil.example.com".to_string(),
+        ).unwrap_err();
+        assert_eq!(ContractError::ToolAlreadyExists {}, err);
+    }
+
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert_eq!("provider1", tool.provider);
+    assert_eq!(Uint128::new(100), tool.price);
+    assert_eq!("Original", tool.description);
+}
+
+// Registers "tool1" as `sender` at the given block environment
+fn register_tool1(deps: DepsMut, env: Env, sender: &str) -> Result<Response, ContractError> {
+    execute_register_tool(
+        deps,
+        env,
+        message_info(&Addr::unchecked(sender), &[]),
+        "tool1".to_string(),
+        Uint128::new(50),
+        None,
+        "Reclaimed".to_string(),
+        "https://api.example.com/tool".to_string(),
+    )
+}
+
+/// # Test: Unregistered Tool IDs Are Reserved by a Tombstone
+///
+/// This test verifies the unregister flow and the reservation window that follows it.
+///
+/// ## Test Steps:
+///
+/// 1. Register "tool1" as provider1
+/// 2. Attempt to unregister as provider2 and verify it fails
+/// 3. Unregister as provider1 and verify the tool is gone
+/// 4. Verify provider2 cannot claim the ID during the tombstone period
+/// 5. Verify provider2 can claim the ID after the tombstone period
+#[test]
+fn unregister_tool_leaves_tombstone() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+
+    register_tool_with_default_endpoint(deps.as_mut(), "provider1", "tool1".to_string(), Uint128::new(100), "Tool".to_string()).unwrap();
+
+    let err = execute_unregister_tool(deps.as_mut(), mock_env(), message_info(&Addr::unchecked("provider2"), &[]), "tool1".to_string()).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    let env = mock_env();
+    execute_unregister_tool(deps.as_mut(), env.clone(), message_info(&Addr::unchecked("provider1"), &[]), "tool1".to_string()).unwrap();
+
+    let tool: Option<ToolResponse> = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert!(tool.is_none(), "Unregistered tool should no longer be returned");
+
+    // Unregistering twice fails because the tool no longer exists
+    let err = execute_unregister_tool(deps.as_mut(), env.clone(), message_info(&Addr::unchecked("provider1"), &[]), "tool1".to_string()).unwrap_err();
+    assert_eq!(ContractError::ToolNotFound {}, err);
+
+    // Another provider is locked out during the tombstone period
+    let mut later = env.clone();
+    later.block.height += 1_000;
+    let err = register_tool1(deps.as_mut(), later, "provider2").unwrap_err();
+    match err {
+        ContractError::ToolIdRetired { available_at } => assert!(available_at > env.block.height + 1_000),
+        other => panic!("Expected ToolIdRetired, got {:?}", other),
+    }
+
+    // Once the tombstone period is over anyone can register the ID
+    let mut much_later = env;
+    much_later.block.height += 200_000;
+    register_tool1(deps.as_mut(), much_later, "provider2").unwrap();
+
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert_eq!("provider2", tool.provider);
+    assert_eq!("Reclaimed", tool.description);
+}
+
+/// # Test: Providers Can Reclaim Their Own Retired Tool ID
+///
+/// This test verifies that the tombstone only blocks other providers.
+///
+/// ## Test Steps:
+///
+/// 1. Register and unregister "tool1" as provider1
+/// 2. Register "tool1" again as provider1 in the same block and verify it succeeds
+#[test]
+fn provider_can_reclaim_own_retired_tool_id() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+
+    register_tool_with_default_endpoint(deps.as_mut(), "provider1", "tool1".to_string(), Uint128::new(100), "Tool".to_string()).unwrap();
+    execute_unregister_tool(deps.as_mut(), mock_env(), message_info(&Addr::unchecked("provider1"), &[]), "tool1".to_string()).unwrap();
+
+    register_tool1(deps.as_mut(), mock_env(), "provider1").unwrap();
+
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert_eq!("provider1", tool.provider);
+    assert!(tool.is_active);
+}
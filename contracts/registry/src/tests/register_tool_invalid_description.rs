@@ -13,7 +13,7 @@
 //! This test validates the contract's enforcement of tool description constraints, which requires
 //! validating that the description length is less than or equal to 256 characters.
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{Addr, Uint128};
 use crate::contract::execute_register_tool;
 use crate::error::ContractError;
@@ -50,7 +50,7 @@ fn register_tool_invalid_description() {
     
     // Execute tool registration and expect an error
     let endpoint = "https://api.provider1.com/long-desc-tool".to_string();
-    let err = execute_register_tool(deps.as_mut(), info, tool_id, price, None, description, endpoint).unwrap_err();
+    let err = execute_register_tool(deps.as_mut(), mock_env(), info, tool_id, price, None, description, endpoint).unwrap_err();
 
     // Verify that the error is the expected DescriptionTooLong error
     match err {
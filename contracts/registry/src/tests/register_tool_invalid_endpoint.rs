@@ -15,7 +15,7 @@
 //! endpoint feature implementation (Task 14.1), ensuring that only valid
 //! HTTPS endpoints are accepted for tool registration.
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{Addr, Uint128};
 use crate::contract::execute_register_tool;
 use crate::error::ContractError;
@@ -48,7 +48,8 @@ fn register_tool_endpoint_too_long() {
     
     // Execute tool registration and expect an error
     let err = execute_register_tool(
-        deps.as_mut(), 
+        deps.as_mut(),
+        mock_env(), 
         info, 
         tool_id, 
         price, 
@@ -89,7 +90,8 @@ fn register_tool_invalid_endpoint_format() {
     let http_endpoint = "http://api.example.com/tool".to_string();
     
     let err = execute_register_tool(
-        deps.as_mut(), 
+        deps.as_mut(),
+        mock_env(), 
         info, 
         tool_id.clone(), 
         price, 
@@ -108,7 +110,8 @@ fn register_tool_invalid_endpoint_format() {
     let plain_endpoint = "api.example.com/tool".to_string();
     
     let err = execute_register_tool(
-        deps.as_mut(), 
+        deps.as_mut(),
+        mock_env(), 
         info, 
         "tool2".to_string(), 
         price, 
@@ -127,7 +130,8 @@ fn register_tool_invalid_endpoint_format() {
     let empty_endpoint = "".to_string();
     
     let err = execute_register_tool(
-        deps.as_mut(), 
+        deps.as_mut(),
+        mock_env(), 
         info, 
         "tool3".to_string(), 
         price, 
@@ -167,7 +171,8 @@ fn register_tool_valid_endpoint() {
     
     // Execute tool registration and expect success
     let res = execute_register_tool(
-        deps.as_mut(), 
+        deps.as_mut(),
+        mock_env(), 
         info, 
         tool_id, 
         price, 
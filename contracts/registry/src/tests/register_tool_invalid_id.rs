@@ -14,7 +14,7 @@
 //! Task 2.2 of the implementation plan, which requires validating that tool_id length is 
 //! less than or equal to 16 characters.
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{Addr, Uint128};
 use crate::contract::execute_register_tool;
 use crate::error::ContractError;
@@ -45,7 +45,7 @@ fn register_tool_invalid_id() {
     let endpoint = "https://api.provider1.com/invalid-tool".to_string();
 
     // Execute tool registration and expect an error
-    let err = execute_register_tool(deps.as_mut(), info, tool_id, price, None, description, endpoint).unwrap_err();
+    let err = execute_register_tool(deps.as_mut(), mock_env(), info, tool_id, price, None, description, endpoint).unwrap_err();
 
     // Verify that the error is the expected ToolIdTooLong error
     match err {
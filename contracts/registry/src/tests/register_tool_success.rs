@@ -16,7 +16,7 @@
 //! functionality as specified in the project requirements (Task 2.2 in the implementation plan).
 //! It validates that providers can register tools with unique IDs and set initial prices.
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{Addr, from_json, Uint128};
 use crate::contract::{execute_register_tool, query_tool};
 use crate::msg::ToolResponse;
@@ -48,7 +48,7 @@ fn register_tool_success() {
     let endpoint = "https://api.example.com/tool1".to_string();
 
     // Execute tool registration
-    let res = execute_register_tool(deps.as_mut(), info, tool_id.clone(), price, None, description.clone(), endpoint.clone()).unwrap();
+    let res = execute_register_tool(deps.as_mut(), mock_env(), info, tool_id.clone(), price, None, description.clone(), endpoint.clone()).unwrap();
 
     // Verify response attributes
     assert_eq!(8, res.attributes.len()); // Updated to 8 attributes
@@ -63,7 +63,7 @@ fn register_tool_success() {
 
     // Query tool metadata and verify it was stored correctly
     let query_res = query_tool(deps.as_ref(), tool_id).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
 
     // Validate all tool properties including endpoint
     assert_eq!("tool1", tool_response.tool_id);
@@ -30,7 +30,7 @@ use crate::error::ContractError;
 /// * `Result<cosmwasm_std::Response, ContractError>` - The result of contract instantiation
 pub fn setup_contract(deps: cosmwasm_std::DepsMut) -> Result<cosmwasm_std::Response, ContractError> {
     let info = message_info(&Addr::unchecked("creator"), &[]);
-    instantiate(deps, mock_env(), info, InstantiateMsg {})
+    instantiate(deps, mock_env(), info, InstantiateMsg { admin: None })
 }
 
 /// Helper function to register a tool with endpoint for testing
@@ -61,7 +61,7 @@ pub fn register_tool(
     endpoint: String,
 ) -> Result<cosmwasm_std::Response, ContractError> {
     let info = message_info(&Addr::unchecked(provider), &[]);
-    execute_register_tool(deps, info, tool_id, price, denom, description, endpoint)
+    execute_register_tool(deps, mock_env(), info, tool_id, price, denom, description, endpoint)
 }
 
 /// Helper function to register a tool with default endpoint for testing
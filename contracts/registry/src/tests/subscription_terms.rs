@@ -0,0 +1,70 @@
+//! # Subscription Terms Test
+//! 
+//! This module tests advertising subscription pricing for tools in the Registry contract.
+//! 
+//! ## Test Coverage
+//! 
+//! This test verifies that:
+//! 1. Newly registered tools offer no subscription
+//! 2. The provider can set and remove subscription terms
+//! 3. Terms with a zero price or period are rejected
+//! 4. Only the provider can change the terms
+
+use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::{Addr, from_json, Uint128};
+use crate::contract::{execute_update_subscription_terms, query_tool};
+use crate::error::ContractError;
+use crate::msg::ToolResponse;
+use crate::state::SubscriptionTerms;
+use crate::tests::setup_contract::{register_tool_with_default_endpoint, setup_contract};
+
+/// # Test: Updating Subscription Terms
+/// 
+/// ## Test Steps:
+/// 
+/// 1. Register a tool and verify it has no subscription terms
+/// 2. Set terms of 1000 per 100 blocks and verify they are returned by GetTool
+/// 3. Verify zero price or zero period terms are rejected
+/// 4. Verify another address cannot change the terms
+/// 5. Remove the terms and verify the tool no longer offers a subscription
+#[test]
+fn update_subscription_terms() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+    register_tool_with_default_endpoint(
+        deps.as_mut(), "provider1", "tool1".to_string(), Uint128::new(10), "Subscription tool".to_string(),
+    ).unwrap();
+
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert_eq!(None, tool.subscription);
+
+    let provider = message_info(&Addr::unchecked("provider1"), &[]);
+    let terms = SubscriptionTerms { price: Uint128::new(1000), period: 100 };
+    let res = execute_update_subscription_terms(
+        deps.as_mut(), provider.clone(), "tool1".to_string(), Some(terms.clone()),
+    ).unwrap();
+    assert_eq!("update_subscription_terms", res.attributes[0].value);
+    assert_eq!("1000", res.attributes[2].value);
+    assert_eq!("100", res.attributes[3].value);
+
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert_eq!(Some(terms), tool.subscription);
+
+    for invalid in [
+        SubscriptionTerms { price: Uint128::zero(), period: 100 },
+        SubscriptionTerms { price: Uint128::new(1000), period: 0 },
+    ] {
+        let err = execute_update_subscription_terms(
+            deps.as_mut(), provider.clone(), "tool1".to_string(), Some(invalid),
+        ).unwrap_err();
+        assert_eq!(ContractError::InvalidSubscriptionTerms {}, err);
+    }
+
+    let other = message_info(&Addr::unchecked("provider2"), &[]);
+    let err = execute_update_subscription_terms(deps.as_mut(), other, "tool1".to_string(), None).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    execute_update_subscription_terms(deps.as_mut(), provider, "tool1".to_string(), None).unwrap();
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert_eq!(None, tool.subscription);
+}
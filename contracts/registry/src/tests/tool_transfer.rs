@@ -0,0 +1,120 @@
+//! # Tool Ownership Transfer Test
+//!
+//! This module tests the two-step transfer of a tool between providers.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Only the current provider can propose a transfer
+//! 2. The pending transfer is queryable and only the proposed provider can accept it
+//! 3. Accepting moves the tool, including its provider index entry, to the new provider
+//! 4. Either party can cancel a pending transfer
+//!
+//! ## Relation to Requirements
+//!
+//! Teams rotating keys or selling a tool need to keep its ID instead of re-registering.
+
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
+use cosmwasm_std::{from_json, Addr, Uint128};
+use crate::contract::{execute, query_pending_transfer, query_tool, query_tools_by_provider};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, PendingTransferResponse, ToolResponse, ToolsResponse};
+use crate::tests::setup_contract::{register_tool_with_default_endpoint, setup_contract};
+
+/// # Test: Propose and Accept a Tool Transfer
+///
+/// ## Test Steps:
+///
+/// 1. Register a tool as provider1
+/// 2. Attempt to propose a transfer as someone else and verify it fails
+/// 3. Propose a transfer to a new provider and query it
+/// 4. Attempt to accept as the wrong address and verify it fails
+/// 5. Accept as the new provider and verify ownership, indexes and the cleared proposal
+#[test]
+fn propose_and_accept_transfer() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+    register_tool_with_default_endpoint(deps.as_mut(), "provider1", "tool1".to_string(), Uint128::new(100), "Tool".to_string()).unwrap();
+
+    let new_provider = deps.api.addr_make("new_provider");
+    let propose = ExecuteMsg::ProposeToolTransfer {
+        tool_id: "tool1".to_string(),
+        new_provider: new_provider.to_string(),
+    };
+
+    let err = execute(deps.as_mut(), mock_env(), message_info(&Addr::unchecked("provider2"), &[]), propose.clone()).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    execute(deps.as_mut(), mock_env(), message_info(&Addr::unchecked("provider1"), &[]), propose).unwrap();
+
+    let pending: Option<PendingTransferResponse> = from_json(query_pending_transfer(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    let pending = pending.expect("Transfer should be pending");
+    assert_eq!("provider1", pending.provider);
+    assert_eq!(new_provider.to_string(), pending.new_provider);
+
+    // Proposing does not change the provider yet
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert_eq!("provider1", tool.provider);
+
+    let accept = ExecuteMsg::AcceptToolTransfer { tool_id: "tool1".to_string() };
+    let err = execute(deps.as_mut(), mock_env(), message_info(&Addr::unchecked("provider2"), &[]), accept.clone()).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    execute(deps.as_mut(), mock_env(), message_info(&new_provider, &[]), accept.clone()).unwrap();
+
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert_eq!(new_provider.to_string(), tool.provider);
+
+    let old: ToolsResponse = from_json(query_tools_by_provider(deps.as_ref(), "provider1".to_string(), None, None).unwrap()).unwrap();
+    assert!(old.tools.is_empty());
+    let new: ToolsResponse = from_json(query_tools_by_provider(deps.as_ref(), new_provider.to_string(), None, None).unwrap()).unwrap();
+    assert_eq!(1, new.tools.len());
+
+    let pending: Option<PendingTransferResponse> = from_json(query_pending_transfer(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert!(pending.is_none());
+
+    // The transfer cannot be accepted twice
+    let err = execute(deps.as_mut(), mock_env(), message_info(&new_provider, &[]), accept).unwrap_err();
+    assert_eq!(ContractError::NoPendingTransfer {}, err);
+}
+
+/// # Test: Cancel a Pending Tool Transfer
+///
+/// ## Test Steps:
+///
+/// 1. Propose a transfer and cancel it as the current provider
+/// 2. Propose again and cancel it as the proposed provider
+/// 3. Verify an unrelated address cannot cancel and nothing can be accepted afterwards
+#[test]
+fn cancel_pending_transfer() {
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+    register_tool_with_default_endpoint(deps.as_mut(), "provider1", "tool1".to_string(), Uint128::new(100), "Tool".to_string()).unwrap();
+
+    let new_provider = deps.api.addr_make("new_provider");
+    let provider = message_info(&Addr::unchecked("provider1"), &[]);
+    let propose = ExecuteMsg::ProposeToolTransfer {
+        tool_id: "tool1".to_string(),
+        new_provider: new_provider.to_string(),
+    };
+    let cancel = ExecuteMsg::CancelToolTransfer { tool_id: "tool1".to_string() };
+
+    execute(deps.as_mut(), mock_env(), provider.clone(), propose.clone()).unwrap();
+    execute(deps.as_mut(), mock_env(), provider.clone(), cancel.clone()).unwrap();
+
+    execute(deps.as_mut(), mock_env(), provider, propose).unwrap();
+    let err = execute(deps.as_mut(), mock_env(), message_info(&Addr::unchecked("provider2"), &[]), cancel.clone()).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+    execute(deps.as_mut(), mock_env(), message_info(&new_provider, &[]), cancel).unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&new_provider, &[]),
+        ExecuteMsg::AcceptToolTransfer { tool_id: "tool1".to_string() },
+    ).unwrap_err();
+    assert_eq!(ContractError::NoPendingTransfer {}, err);
+
+    let tool: ToolResponse = from_json(query_tool(deps.as_ref(), "tool1".to_string()).unwrap()).unwrap();
+    assert_eq!("provider1", tool.provider);
+}
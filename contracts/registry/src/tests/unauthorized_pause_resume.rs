@@ -16,7 +16,7 @@
 //! as specified in Task 2.2 of the implementation plan, which requires the contract to verify 
 //! that only the original provider can manage a tool's active status.
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{Addr, Uint128};
 use crate::contract::{execute_pause_tool, execute_register_tool, execute_resume_tool};
 use crate::error::ContractError;
@@ -48,7 +48,7 @@ fn unauthorized_pause_resume() {
     let price = Uint128::new(100);
     let description = "Tool for unauthorized pause/resume test".to_string();
     let endpoint = "https://api.provider1.com/pause-test-tool".to_string();
-    execute_register_tool(deps.as_mut(), info, tool_id.clone(), price, None, description, endpoint).unwrap();
+    execute_register_tool(deps.as_mut(), mock_env(), info, tool_id.clone(), price, None, description, endpoint).unwrap();
 
     // Create message info for unauthorized provider (provider2)
     let unauthorized_info = message_info(&Addr::unchecked("provider2"), &[]);
@@ -9,8 +9,9 @@
 //! 2. The denomination can be updated by the tool provider
 //! 3. Unauthorized users cannot update the denomination
 //! 4. The updated denomination is reflected in the tool metadata
+//! 5. CW20 denominations must refer to a valid contract address
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{Addr, from_json, Uint128};
 use crate::contract::{execute_register_tool, execute_update_denom, query_tool};
 use crate::msg::ToolResponse;
@@ -44,11 +45,11 @@ fn update_denom_success() {
     let description = "A tool with custom denomination".to_string();
     let endpoint = "https://api.provider1.com/denom-tool".to_string();
     
-    execute_register_tool(deps.as_mut(), info.clone(), tool_id.clone(), price, initial_denom, description.clone(), endpoint).unwrap();
+    execute_register_tool(deps.as_mut(), mock_env(), info.clone(), tool_id.clone(), price, initial_denom, description.clone(), endpoint).unwrap();
 
     // Query the tool to verify the denom
     let query_res = query_tool(deps.as_ref(), tool_id.clone()).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
     
     // Verify the initial denom
     assert_eq!("uatom", tool_response.denom);
@@ -65,7 +66,7 @@ fn update_denom_success() {
 
     // Query the tool again to verify the denom was updated
     let query_res = query_tool(deps.as_ref(), tool_id).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
     
     // Verify the denom was updated
     assert_eq!("uosmo", tool_response.denom);
@@ -95,7 +96,7 @@ fn update_denom_unauthorized() {
     let description = "Tool from provider1".to_string();
     let endpoint = "https://api.provider1.com/test-tool".to_string();
     
-    execute_register_tool(deps.as_mut(), info1, tool_id.clone(), price, None, description, endpoint).unwrap();
+    execute_register_tool(deps.as_mut(), mock_env(), info1, tool_id.clone(), price, None, description, endpoint).unwrap();
 
     // Attempt to update the denom as provider2
     let provider2 = Addr::unchecked("provider2");
@@ -135,12 +136,55 @@ fn register_tool_default_denom() {
     let description = "Tool with default denom".to_string();
     let endpoint = "https://api.provider1.com/default-denom-tool".to_string();
     
-    execute_register_tool(deps.as_mut(), info, tool_id.clone(), price, None, description, endpoint).unwrap();
+    execute_register_tool(deps.as_mut(), mock_env(), info, tool_id.clone(), price, None, description, endpoint).unwrap();
 
     // Query the tool to verify the denom
     let query_res = query_tool(deps.as_ref(), tool_id).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
     
     // Verify that the default denom is "untrn"
     assert_eq!("untrn", tool_response.denom);
 }
+
+/// # Test: CW20 Denoms
+/// 
+/// This test verifies that a tool can be priced in a CW20 token and that
+/// CW20 denoms must refer to a valid contract address.
+/// 
+/// ## Test Steps:
+///
+/// 1. Setup the contract with mock dependencies
+/// 2. Verify registering with a malformed CW20 denom fails
+/// 3. Register a tool priced in a CW20 token
+/// 4. Verify updating to a malformed CW20 denom fails
+#[test]
+fn cw20_denom_validation() {
+    // Initialize mock dependencies and set up contract
+    let mut deps = mock_dependencies();
+    setup_contract(deps.as_mut()).unwrap();
+
+    let provider = Addr::unchecked("provider1");
+    let info = message_info(&provider, &[]);
+    let tool_id = "tool1".to_string();
+    let price = Uint128::new(100);
+    let description = "Tool priced in a CW20 token".to_string();
+    let endpoint = "https://api.provider1.com/cw20-tool".to_string();
+    let token = deps.api.addr_make("token");
+    let cw20_denom = format!("cw20:{}", token);
+
+    let err = execute_register_tool(
+        deps.as_mut(), mock_env(), info.clone(), tool_id.clone(), price,
+        Some("cw20:not-an-address".to_string()), description.clone(), endpoint.clone(),
+    ).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidCw20Denom { .. }));
+
+    execute_register_tool(
+        deps.as_mut(), mock_env(), info.clone(), tool_id.clone(), price,
+        Some(cw20_denom.clone()), description, endpoint,
+    ).unwrap();
+    let tool_response: ToolResponse = from_json(query_tool(deps.as_ref(), tool_id.clone()).unwrap()).unwrap();
+    assert_eq!(cw20_denom, tool_response.denom);
+
+    let err = execute_update_denom(deps.as_mut(), info, tool_id, "cw20:".to_string()).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidCw20Denom { .. }));
+}
@@ -16,7 +16,7 @@
 //! Task 14.1 of the endpoint feature implementation, ensuring that tool
 //! providers can modify their tool endpoints while maintaining security.
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{Addr, from_json, Uint128};
 use crate::contract::{execute_register_tool, execute_update_endpoint, query_tool};
 use crate::msg::ToolResponse;
@@ -50,7 +50,8 @@ fn update_endpoint_success() {
     let initial_endpoint = "https://api.provider1.com/v1/tool".to_string();
     
     execute_register_tool(
-        deps.as_mut(), 
+        deps.as_mut(),
+        mock_env(), 
         info.clone(), 
         tool_id.clone(), 
         price, 
@@ -76,7 +77,7 @@ fn update_endpoint_success() {
 
     // Query tool metadata and verify the endpoint was updated correctly
     let query_res = query_tool(deps.as_ref(), tool_id).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
 
     // Validate the updated endpoint
     assert_eq!(new_endpoint, tool_response.endpoint);
@@ -108,7 +109,8 @@ fn update_endpoint_unauthorized() {
     let endpoint = "https://api.provider1.com/tool".to_string();
     
     execute_register_tool(
-        deps.as_mut(), 
+        deps.as_mut(),
+        mock_env(), 
         info1, 
         tool_id.clone(), 
         price, 
@@ -196,7 +198,8 @@ fn update_endpoint_invalid_format() {
     let valid_endpoint = "https://api.provider1.com/tool".to_string();
     
     execute_register_tool(
-        deps.as_mut(), 
+        deps.as_mut(),
+        mock_env(), 
         info.clone(), 
         tool_id.clone(), 
         price, 
@@ -247,7 +250,8 @@ fn update_endpoint_too_long() {
     let valid_endpoint = "https://api.provider1.com/tool".to_string();
     
     execute_register_tool(
-        deps.as_mut(), 
+        deps.as_mut(),
+        mock_env(), 
         info.clone(), 
         tool_id.clone(), 
         price, 
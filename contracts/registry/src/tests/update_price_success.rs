@@ -15,7 +15,7 @@
 //! functionality as specified in Task 2.2 of the implementation plan,
 //! which requires allowing providers to update the price of their tools.
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{Addr, from_json, Uint128};
 use crate::contract::{execute_register_tool, execute_update_price, query_tool};
 use crate::msg::ToolResponse;
@@ -46,7 +46,7 @@ fn update_price_success() {
     let price = Uint128::new(100);
     let description = "Tool for price update test".to_string();
     let endpoint = "https://api.provider1.com/tool1".to_string();
-    execute_register_tool(deps.as_mut(), info.clone(), tool_id.clone(), price, None, description, endpoint).unwrap();
+    execute_register_tool(deps.as_mut(), mock_env(), info.clone(), tool_id.clone(), price, None, description, endpoint).unwrap();
 
     // Update the tool's price as the authorized provider
     let new_price = Uint128::new(200);
@@ -60,7 +60,7 @@ fn update_price_success() {
 
     // Query tool metadata and verify the price was updated correctly
     let query_res = query_tool(deps.as_ref(), tool_id).unwrap();
-    let tool_response: ToolResponse = from_json(&query_res).unwrap();
+    let tool_response: ToolResponse = from_json(query_res).unwrap();
 
     // Validate the updated price
     assert_eq!(Uint128::new(200), tool_response.price);
@@ -15,7 +15,7 @@
 //! Task 2.2 of the implementation plan, which requires the contract to verify 
 //! that only the original provider can update a tool's price.
 
-use cosmwasm_std::testing::{mock_dependencies, message_info};
+use cosmwasm_std::testing::{mock_dependencies, message_info, mock_env};
 use cosmwasm_std::{Addr, Uint128};
 use crate::contract::{execute_register_tool, execute_update_price};
 use crate::error::ContractError;
@@ -45,7 +45,7 @@ fn update_price_unauthorized() {
     let price = Uint128::new(100);
     let description = "Tool for unauthorized update test".to_string();
     let endpoint = "https://api.provider1.com/auth-test-tool".to_string();
-    execute_register_tool(deps.as_mut(), info, tool_id.clone(), price, None, description, endpoint).unwrap();
+    execute_register_tool(deps.as_mut(), mock_env(), info, tool_id.clone(), price, None, description, endpoint).unwrap();
 
     // Attempt to update the price using a different provider (provider2)
     let info = message_info(&Addr::unchecked("provider2"), &[]);
@@ -4,7 +4,13 @@ import {
 } from "@cosmjs/cosmwasm-stargate"
 import { DirectSecp256k1Wallet } from "@cosmjs/proto-signing"
 import { GasPrice } from "@cosmjs/stargate"
-import { RegistryQueryClient, EscrowClient, EscrowQueryClient } from "httpay"
+import { createHash, randomBytes } from "node:crypto"
+import {
+  RegistryQueryClient,
+  EscrowClient,
+  EscrowQueryClient,
+  type RegistryTypes,
+} from "httpay"
 import { logger } from "@elizaos/core"
 import type { HTTPayConfig, HTTPayTool, TransactionResult } from "./types.js"
 
@@ -91,10 +97,17 @@ export class HTTPayService {
       }
 
       logger.info("Fetching tools from registry...")
-      const toolsResponse = await this.registryClient.getTools()
+      // GetTools is paginated; follow the cursor until every page is loaded
+      const registryTools: RegistryTypes.ToolResponse[] = []
+      let startAfter: string | undefined
+      do {
+        const toolsResponse = await this.registryClient.getTools({ startAfter })
+        registryTools.push(...toolsResponse.tools)
+        startAfter = toolsResponse.next_start_after ?? undefined
+      } while (startAfter)
 
       // Transform the response to our tool interface
-      const tools: HTTPayTool[] = toolsResponse.tools.map((tool) => ({
+      const tools: HTTPayTool[] = registryTools.map((tool) => ({
         toolId: tool.tool_id,
         name: tool.tool_id, // Use tool_id as name for MVP
         description: tool.description || "No description available",
@@ -170,16 +183,19 @@ export class HTTPayService {
 
       // Get current block height and calculate expires (50 blocks from now)
       const currentHeight = await this.cosmWasmClient.getHeight()
-      const expires = currentHeight + 50 // 50 blocks from current height
-      const authToken = `auth_${Math.random().toString(36).substring(2)}`
+      const expires = { at_height: currentHeight + 50 } // 50 blocks from current height
+      // The token is the bearer secret for the escrow, so it must be unguessable
+      const authToken = `auth_${randomBytes(32).toString("hex")}`
+      // Only the sha256 commitment to the token is stored on chain
+      const authCommitment = createHash("sha256").update(authToken).digest("base64")
       
-      logger.info(`Current block height: ${currentHeight}, expires at: ${expires}`)
+      logger.info(`Current block height: ${currentHeight}, expires at: ${expires.at_height}`)
 
       const result = await this.escrowClient.lockFunds(
         {
           toolId,
           maxFee: fee,
-          authToken,
+          authCommitment,
           expires,
         },
         "auto",
@@ -340,18 +356,17 @@ export class HTTPayService {
         throw new Error("HTTPay service not initialized")
       }
 
-      const escrowResponse = await this.escrowQueryClient.getEscrow({ escrowId })
-
-      // Validate auth token
-      if (escrowResponse.auth_token !== authToken) {
+      // The contract checks the token against the escrow's commitment and expiration
+      const verification = await this.escrowQueryClient.verifyEscrowToken({ escrowId, token: authToken })
+      if (!verification.valid) {
         return {
           isValid: false,
-          error: 'Invalid authentication token'
+          error: 'Invalid authentication token, or escrow expired or no longer locked'
         }
       }
 
-      // Check if escrow is still active (not expired)
-      // Note: In a real implementation, you'd want to check block height
+      const escrowResponse = await this.escrowQueryClient.getEscrow({ escrowId })
+
       return {
         isValid: true,
         escrow: escrowResponse
@@ -11,26 +11,99 @@ import {
   SigningCosmWasmClient,
 } from "@cosmjs/cosmwasm-stargate";
 import {
+  Binary,
+  ChannelResponse,
   CollectedFeesResponse,
+  ConfigResponse,
+  DepositResponse,
+  EffectiveFeeResponse,
   EscrowResponse,
   EscrowsResponse,
+  EscrowStatus,
+  Expiration,
+  ExpiryBounds,
+  FeeOverrideTarget,
+  KeyType,
+  NullableSubscriptionResponse,
+  ReceiptResponse,
+  ReleaseItem,
   Uint128,
+  UnaccountedBalanceResponse,
+  VerifyEscrowResponse,
+  VerifyEscrowTokenResponse,
 } from "./Escrow.types";
 
 export interface EscrowReadOnlyInterface {
   contractAddress: string;
   getEscrow: ({ escrowId }: { escrowId: number }) => Promise<EscrowResponse>;
+  getReceipt: ({ escrowId }: { escrowId: number }) => Promise<ReceiptResponse>;
+  verifyEscrowToken: ({
+    escrowId,
+    token,
+  }: {
+    escrowId: number;
+    token: string;
+  }) => Promise<VerifyEscrowTokenResponse>;
+  verifyEscrow: ({
+    escrowId,
+    minAmount,
+    provider,
+    toolId,
+  }: {
+    escrowId: number;
+    minAmount: Uint128;
+    provider: string;
+    toolId: string;
+  }) => Promise<VerifyEscrowResponse>;
+  getDeposit: ({
+    caller,
+    denom,
+  }: {
+    caller: string;
+    denom: string;
+  }) => Promise<DepositResponse>;
+  getChannel: ({
+    channelId,
+  }: {
+    channelId: number;
+  }) => Promise<ChannelResponse>;
+  getSubscription: ({
+    caller,
+    toolId,
+  }: {
+    caller: string;
+    toolId: string;
+  }) => Promise<NullableSubscriptionResponse>;
+  getConfig: () => Promise<ConfigResponse>;
+  getEffectiveFee: ({
+    provider,
+    toolId,
+  }: {
+    provider: string;
+    toolId: string;
+  }) => Promise<EffectiveFeeResponse>;
+  getUnaccountedBalance: ({
+    denom,
+  }: {
+    denom: string;
+  }) => Promise<UnaccountedBalanceResponse>;
   getCollectedFees: () => Promise<CollectedFeesResponse>;
   getEscrows: ({
     caller,
+    descending,
     limit,
     provider,
     startAfter,
+    status,
+    toolId,
   }: {
     caller?: string;
+    descending?: boolean;
     limit?: number;
     provider?: string;
     startAfter?: number;
+    status?: EscrowStatus;
+    toolId?: string;
   }) => Promise<EscrowsResponse>;
 }
 
@@ -41,6 +114,15 @@ export class EscrowQueryClient implements EscrowReadOnlyInterface {
     this.client = client;
     this.contractAddress = contractAddress;
     this.getEscrow = this.getEscrow.bind(this);
+    this.getReceipt = this.getReceipt.bind(this);
+    this.verifyEscrowToken = this.verifyEscrowToken.bind(this);
+    this.verifyEscrow = this.verifyEscrow.bind(this);
+    this.getDeposit = this.getDeposit.bind(this);
+    this.getChannel = this.getChannel.bind(this);
+    this.getSubscription = this.getSubscription.bind(this);
+    this.getConfig = this.getConfig.bind(this);
+    this.getEffectiveFee = this.getEffectiveFee.bind(this);
+    this.getUnaccountedBalance = this.getUnaccountedBalance.bind(this);
     this.getCollectedFees = this.getCollectedFees.bind(this);
     this.getEscrows = this.getEscrows.bind(this);
   }
@@ -55,6 +137,120 @@ export class EscrowQueryClient implements EscrowReadOnlyInterface {
       },
     });
   };
+  getReceipt = async ({
+    escrowId,
+  }: {
+    escrowId: number;
+  }): Promise<ReceiptResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_receipt: {
+        escrow_id: escrowId,
+      },
+    });
+  };
+  verifyEscrowToken = async ({
+    escrowId,
+    token,
+  }: {
+    escrowId: number;
+    token: string;
+  }): Promise<VerifyEscrowTokenResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      verify_escrow_token: {
+        escrow_id: escrowId,
+        token,
+      },
+    });
+  };
+  verifyEscrow = async ({
+    escrowId,
+    minAmount,
+    provider,
+    toolId,
+  }: {
+    escrowId: number;
+    minAmount: Uint128;
+    provider: string;
+    toolId: string;
+  }): Promise<VerifyEscrowResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      verify_escrow: {
+        escrow_id: escrowId,
+        min_amount: minAmount,
+        provider,
+        tool_id: toolId,
+      },
+    });
+  };
+  getDeposit = async ({
+    caller,
+    denom,
+  }: {
+    caller: string;
+    denom: string;
+  }): Promise<DepositResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_deposit: {
+        caller,
+        denom,
+      },
+    });
+  };
+  getChannel = async ({
+    channelId,
+  }: {
+    channelId: number;
+  }): Promise<ChannelResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_channel: {
+        channel_id: channelId,
+      },
+    });
+  };
+  getSubscription = async ({
+    caller,
+    toolId,
+  }: {
+    caller: string;
+    toolId: string;
+  }): Promise<NullableSubscriptionResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_subscription: {
+        caller,
+        tool_id: toolId,
+      },
+    });
+  };
+  getConfig = async (): Promise<ConfigResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_config: {},
+    });
+  };
+  getEffectiveFee = async ({
+    provider,
+    toolId,
+  }: {
+    provider: string;
+    toolId: string;
+  }): Promise<EffectiveFeeResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_effective_fee: {
+        provider,
+        tool_id: toolId,
+      },
+    });
+  };
+  getUnaccountedBalance = async ({
+    denom,
+  }: {
+    denom: string;
+  }): Promise<UnaccountedBalanceResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_unaccounted_balance: {
+        denom,
+      },
+    });
+  };
   getCollectedFees = async (): Promise<CollectedFeesResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_collected_fees: {},
@@ -62,21 +258,30 @@ export class EscrowQueryClient implements EscrowReadOnlyInterface {
   };
   getEscrows = async ({
     caller,
+    descending,
     limit,
     provider,
     startAfter,
+    status,
+    toolId,
   }: {
     caller?: string;
+    descending?: boolean;
     limit?: number;
     provider?: string;
     startAfter?: number;
+    status?: EscrowStatus;
+    toolId?: string;
   }): Promise<EscrowsResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_escrows: {
         caller,
+        descending,
         limit,
         provider,
         start_after: startAfter,
+        status,
+        tool_id: toolId,
       },
     });
   };
@@ -87,13 +292,17 @@ export interface EscrowInterface extends EscrowReadOnlyInterface {
   sender: string;
   lockFunds: (
     {
-      authToken,
+      authCommitment,
+      expectedPrice,
       expires,
+      fromDeposit,
       maxFee,
       toolId,
     }: {
-      authToken: string;
-      expires: number;
+      authCommitment: Binary;
+      expectedPrice?: Uint128;
+      expires: Expiration;
+      fromDeposit?: boolean;
       maxFee: Uint128;
       toolId: string;
     },
@@ -101,6 +310,20 @@ export interface EscrowInterface extends EscrowReadOnlyInterface {
     memo_?: string,
     funds_?: Coin[]
   ) => Promise<ExecuteResult>;
+  receive: (
+    {
+      amount,
+      msg,
+      sender,
+    }: {
+      amount: Uint128;
+      msg: Binary;
+      sender: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
   release: (
     {
       escrowId,
@@ -113,6 +336,38 @@ export interface EscrowInterface extends EscrowReadOnlyInterface {
     memo_?: string,
     funds_?: Coin[]
   ) => Promise<ExecuteResult>;
+  releaseBatch: (
+    {
+      items,
+    }: {
+      items: ReleaseItem[];
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  charge: (
+    {
+      amount,
+      escrowId,
+    }: {
+      amount: Uint128;
+      escrowId: number;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  close: (
+    {
+      escrowId,
+    }: {
+      escrowId: number;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
   refundExpired: (
     {
       escrowId,
@@ -123,133 +378,1077 @@ export interface EscrowInterface extends EscrowReadOnlyInterface {
     memo_?: string,
     funds_?: Coin[]
   ) => Promise<ExecuteResult>;
-  claimFees: (
+  refundExpiredBatch: (
+    {
+      escrowIds,
+    }: {
+      escrowIds: number[];
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  sweepExpired: (
+    {
+      limit,
+    }: {
+      limit?: number;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  deposit: (
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  withdraw: (
     {
+      amount,
       denom,
     }: {
-      denom?: string;
+      amount: Uint128;
+      denom: string;
     },
     fee_?: number | StdFee | "auto",
     memo_?: string,
     funds_?: Coin[]
   ) => Promise<ExecuteResult>;
-}
-
-export class EscrowClient extends EscrowQueryClient implements EscrowInterface {
-  client: SigningCosmWasmClient;
-  sender: string;
-  contractAddress: string;
-  constructor(
-    client: SigningCosmWasmClient,
-    sender: string,
-    contractAddress: string
-  ) {
-    super(client, contractAddress);
-    this.client = client;
-    this.sender = sender;
-    this.contractAddress = contractAddress;
-    this.lockFunds = this.lockFunds.bind(this);
-    this.release = this.release.bind(this);
-    this.refundExpired = this.refundExpired.bind(this);
-    this.claimFees = this.claimFees.bind(this);
-  }
-  lockFunds = async (
+  claimWithdrawal: (
     {
-      authToken,
-      expires,
-      maxFee,
+      denom,
+    }: {
+      denom: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  openChannel: (
+    {
+      keyType,
+      pubkey,
       toolId,
     }: {
-      authToken: string;
-      expires: number;
-      maxFee: Uint128;
+      keyType: KeyType;
+      pubkey: Binary;
       toolId: string;
     },
-    fee_: number | StdFee | "auto" = "auto",
+    fee_?: number | StdFee | "auto",
     memo_?: string,
     funds_?: Coin[]
-  ): Promise<ExecuteResult> => {
-    return await this.client.execute(
-      this.sender,
-      this.contractAddress,
-      {
-        lock_funds: {
-          auth_token: authToken,
-          expires,
-          max_fee: maxFee,
-          tool_id: toolId,
-        },
-      },
-      fee_,
-      memo_,
-      funds_
-    );
-  };
-  release = async (
+  ) => Promise<ExecuteResult>;
+  settleChannel: (
     {
-      escrowId,
-      usageFee,
+      amount,
+      channelId,
+      signature,
     }: {
-      escrowId: number;
-      usageFee: Uint128;
+      amount: Uint128;
+      channelId: number;
+      signature: Binary;
     },
-    fee_: number | StdFee | "auto" = "auto",
+    fee_?: number | StdFee | "auto",
     memo_?: string,
     funds_?: Coin[]
-  ): Promise<ExecuteResult> => {
-    return await this.client.execute(
-      this.sender,
-      this.contractAddress,
-      {
-        release: {
-          escrow_id: escrowId,
-          usage_fee: usageFee,
-        },
-      },
-      fee_,
-      memo_,
-      funds_
-    );
-  };
-  refundExpired = async (
+  ) => Promise<ExecuteResult>;
+  requestCloseChannel: (
     {
-      escrowId,
+      channelId,
     }: {
-      escrowId: number;
+      channelId: number;
     },
-    fee_: number | StdFee | "auto" = "auto",
+    fee_?: number | StdFee | "auto",
     memo_?: string,
     funds_?: Coin[]
-  ): Promise<ExecuteResult> => {
-    return await this.client.execute(
-      this.sender,
-      this.contractAddress,
-      {
-        refund_expired: {
-          escrow_id: escrowId,
-        },
-      },
-      fee_,
-      memo_,
-      funds_
-    );
-  };
-  claimFees = async (
+  ) => Promise<ExecuteResult>;
+  closeChannel: (
     {
-      denom,
+      channelId,
     }: {
-      denom?: string;
+      channelId: number;
     },
-    fee_: number | StdFee | "auto" = "auto",
+    fee_?: number | StdFee | "auto",
     memo_?: string,
     funds_?: Coin[]
-  ): Promise<ExecuteResult> => {
-    return await this.client.execute(
-      this.sender,
-      this.contractAddress,
-      {
-        claim_fees: {
-          denom,
+  ) => Promise<ExecuteResult>;
+  subscribe: (
+    {
+      periods,
+      toolId,
+    }: {
+      periods: number;
+      toolId: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  claimSubscription: (
+    {
+      caller,
+      toolId,
+    }: {
+      caller: string;
+      toolId: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  cancelSubscription: (
+    {
+      toolId,
+    }: {
+      toolId: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  updateConfig: (
+    {
+      expiryBounds,
+      feeBps,
+      keeperRewardBps,
+      registryAddr,
+      withdrawalDelay,
+    }: {
+      expiryBounds?: ExpiryBounds;
+      feeBps?: number;
+      keeperRewardBps?: number;
+      registryAddr?: string;
+      withdrawalDelay?: number;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  setFeeOverride: (
+    {
+      feeBps,
+      target,
+    }: {
+      feeBps?: number;
+      target: FeeOverrideTarget;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  proposeOwner: (
+    {
+      newOwner,
+    }: {
+      newOwner: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  acceptOwner: (
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  emergencyWithdraw: (
+    {
+      escrowId,
+    }: {
+      escrowId: number;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  declineEscrow: (
+    {
+      escrowId,
+    }: {
+      escrowId: number;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  acknowledgeEscrow: (
+    {
+      escrowId,
+    }: {
+      escrowId: number;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  cancelEscrow: (
+    {
+      escrowId,
+    }: {
+      escrowId: number;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  setPause: (
+    {
+      feeClaims,
+      locks,
+      releases,
+    }: {
+      feeClaims?: boolean;
+      locks?: boolean;
+      releases?: boolean;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  recoverUnaccounted: (
+    {
+      denom,
+      recipient,
+    }: {
+      denom: string;
+      recipient?: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  claimFees: (
+    {
+      denom,
+    }: {
+      denom?: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  pruneReceipts: (
+    {
+      escrowIds,
+    }: {
+      escrowIds: number[];
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+}
+
+export class EscrowClient extends EscrowQueryClient implements EscrowInterface {
+  client: SigningCosmWasmClient;
+  sender: string;
+  contractAddress: string;
+  constructor(
+    client: SigningCosmWasmClient,
+    sender: string,
+    contractAddress: string
+  ) {
+    super(client, contractAddress);
+    this.client = client;
+    this.sender = sender;
+    this.contractAddress = contractAddress;
+    this.lockFunds = this.lockFunds.bind(this);
+    this.receive = this.receive.bind(this);
+    this.release = this.release.bind(this);
+    this.releaseBatch = this.releaseBatch.bind(this);
+    this.charge = this.charge.bind(this);
+    this.close = this.close.bind(this);
+    this.refundExpired = this.refundExpired.bind(this);
+    this.refundExpiredBatch = this.refundExpiredBatch.bind(this);
+    this.sweepExpired = this.sweepExpired.bind(this);
+    this.deposit = this.deposit.bind(this);
+    this.withdraw = this.withdraw.bind(this);
+    this.claimWithdrawal = this.claimWithdrawal.bind(this);
+    this.openChannel = this.openChannel.bind(this);
+    this.settleChannel = this.settleChannel.bind(this);
+    this.requestCloseChannel = this.requestCloseChannel.bind(this);
+    this.closeChannel = this.closeChannel.bind(this);
+    this.subscribe = this.subscribe.bind(this);
+    this.claimSubscription = this.claimSubscription.bind(this);
+    this.cancelSubscription = this.cancelSubscription.bind(this);
+    this.updateConfig = this.updateConfig.bind(this);
+    this.setFeeOverride = this.setFeeOverride.bind(this);
+    this.proposeOwner = this.proposeOwner.bind(this);
+    this.acceptOwner = this.acceptOwner.bind(this);
+    this.emergencyWithdraw = this.emergencyWithdraw.bind(this);
+    this.declineEscrow = this.declineEscrow.bind(this);
+    this.acknowledgeEscrow = this.acknowledgeEscrow.bind(this);
+    this.cancelEscrow = this.cancelEscrow.bind(this);
+    this.setPause = this.setPause.bind(this);
+    this.recoverUnaccounted = this.recoverUnaccounted.bind(this);
+    this.claimFees = this.claimFees.bind(this);
+    this.pruneReceipts = this.pruneReceipts.bind(this);
+  }
+  lockFunds = async (
+    {
+      authCommitment,
+      expectedPrice,
+      expires,
+      fromDeposit,
+      maxFee,
+      toolId,
+    }: {
+      authCommitment: Binary;
+      expectedPrice?: Uint128;
+      expires: Expiration;
+      fromDeposit?: boolean;
+      maxFee: Uint128;
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        lock_funds: {
+          auth_commitment: authCommitment,
+          expected_price: expectedPrice,
+          expires,
+          from_deposit: fromDeposit,
+          max_fee: maxFee,
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  receive = async (
+    {
+      amount,
+      msg,
+      sender,
+    }: {
+      amount: Uint128;
+      msg: Binary;
+      sender: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        receive: {
+          amount,
+          msg,
+          sender,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  release = async (
+    {
+      escrowId,
+      usageFee,
+    }: {
+      escrowId: number;
+      usageFee: Uint128;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        release: {
+          escrow_id: escrowId,
+          usage_fee: usageFee,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  releaseBatch = async (
+    {
+      items,
+    }: {
+      items: ReleaseItem[];
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        release_batch: {
+          items,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  charge = async (
+    {
+      amount,
+      escrowId,
+    }: {
+      amount: Uint128;
+      escrowId: number;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        charge: {
+          amount,
+          escrow_id: escrowId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  close = async (
+    {
+      escrowId,
+    }: {
+      escrowId: number;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        close: {
+          escrow_id: escrowId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  refundExpired = async (
+    {
+      escrowId,
+    }: {
+      escrowId: number;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        refund_expired: {
+          escrow_id: escrowId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  refundExpiredBatch = async (
+    {
+      escrowIds,
+    }: {
+      escrowIds: number[];
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        refund_expired_batch: {
+          escrow_ids: escrowIds,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  sweepExpired = async (
+    {
+      limit,
+    }: {
+      limit?: number;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        sweep_expired: {
+          limit,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  deposit = async (
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        deposit: {},
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  withdraw = async (
+    {
+      amount,
+      denom,
+    }: {
+      amount: Uint128;
+      denom: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        withdraw: {
+          amount,
+          denom,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  claimWithdrawal = async (
+    {
+      denom,
+    }: {
+      denom: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        claim_withdrawal: {
+          denom,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  openChannel = async (
+    {
+      keyType,
+      pubkey,
+      toolId,
+    }: {
+      keyType: KeyType;
+      pubkey: Binary;
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        open_channel: {
+          key_type: keyType,
+          pubkey,
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  settleChannel = async (
+    {
+      amount,
+      channelId,
+      signature,
+    }: {
+      amount: Uint128;
+      channelId: number;
+      signature: Binary;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        settle_channel: {
+          amount,
+          channel_id: channelId,
+          signature,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  requestCloseChannel = async (
+    {
+      channelId,
+    }: {
+      channelId: number;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        request_close_channel: {
+          channel_id: channelId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  closeChannel = async (
+    {
+      channelId,
+    }: {
+      channelId: number;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        close_channel: {
+          channel_id: channelId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  subscribe = async (
+    {
+      periods,
+      toolId,
+    }: {
+      periods: number;
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        subscribe: {
+          periods,
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  claimSubscription = async (
+    {
+      caller,
+      toolId,
+    }: {
+      caller: string;
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        claim_subscription: {
+          caller,
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  cancelSubscription = async (
+    {
+      toolId,
+    }: {
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        cancel_subscription: {
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  updateConfig = async (
+    {
+      expiryBounds,
+      feeBps,
+      keeperRewardBps,
+      registryAddr,
+      withdrawalDelay,
+    }: {
+      expiryBounds?: ExpiryBounds;
+      feeBps?: number;
+      keeperRewardBps?: number;
+      registryAddr?: string;
+      withdrawalDelay?: number;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        update_config: {
+          expiry_bounds: expiryBounds,
+          fee_bps: feeBps,
+          keeper_reward_bps: keeperRewardBps,
+          registry_addr: registryAddr,
+          withdrawal_delay: withdrawalDelay,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  setFeeOverride = async (
+    {
+      feeBps,
+      target,
+    }: {
+      feeBps?: number;
+      target: FeeOverrideTarget;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        set_fee_override: {
+          fee_bps: feeBps,
+          target,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  proposeOwner = async (
+    {
+      newOwner,
+    }: {
+      newOwner: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        propose_owner: {
+          new_owner: newOwner,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  acceptOwner = async (
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        accept_owner: {},
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  emergencyWithdraw = async (
+    {
+      escrowId,
+    }: {
+      escrowId: number;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        emergency_withdraw: {
+          escrow_id: escrowId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  declineEscrow = async (
+    {
+      escrowId,
+    }: {
+      escrowId: number;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        decline_escrow: {
+          escrow_id: escrowId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  acknowledgeEscrow = async (
+    {
+      escrowId,
+    }: {
+      escrowId: number;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        acknowledge_escrow: {
+          escrow_id: escrowId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  cancelEscrow = async (
+    {
+      escrowId,
+    }: {
+      escrowId: number;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        cancel_escrow: {
+          escrow_id: escrowId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  setPause = async (
+    {
+      feeClaims,
+      locks,
+      releases,
+    }: {
+      feeClaims?: boolean;
+      locks?: boolean;
+      releases?: boolean;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        set_pause: {
+          fee_claims: feeClaims,
+          locks,
+          releases,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  recoverUnaccounted = async (
+    {
+      denom,
+      recipient,
+    }: {
+      denom: string;
+      recipient?: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        recover_unaccounted: {
+          denom,
+          recipient,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  claimFees = async (
+    {
+      denom,
+    }: {
+      denom?: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        claim_fees: {
+          denom,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  pruneReceipts = async (
+    {
+      escrowIds,
+    }: {
+      escrowIds: number[];
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        prune_receipts: {
+          escrow_ids: escrowIds,
         },
       },
       fee_,
@@ -14,10 +14,26 @@ import {
 } from "@tanstack/react-query";
 import { EscrowClient, EscrowQueryClient } from "./Escrow.client";
 import {
+  Binary,
+  ChannelResponse,
   CollectedFeesResponse,
+  ConfigResponse,
+  DepositResponse,
+  EffectiveFeeResponse,
   EscrowResponse,
   EscrowsResponse,
-  Uint128
+  EscrowStatus,
+  Expiration,
+  ExpiryBounds,
+  FeeOverrideTarget,
+  KeyType,
+  NullableSubscriptionResponse,
+  ReceiptResponse,
+  ReleaseItem,
+  Uint128,
+  UnaccountedBalanceResponse,
+  VerifyEscrowResponse,
+  VerifyEscrowTokenResponse,
 } from "./Escrow.types";
 
 export const escrowQueryKeys = {
@@ -44,6 +60,105 @@ export const escrowQueryKeys = {
         args,
       },
     ] as const,
+  getReceipt: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...escrowQueryKeys.address(contractAddress)[0],
+        method: "get_receipt",
+        args,
+      },
+    ] as const,
+  verifyEscrowToken: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...escrowQueryKeys.address(contractAddress)[0],
+        method: "verify_escrow_token",
+        args,
+      },
+    ] as const,
+  verifyEscrow: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...escrowQueryKeys.address(contractAddress)[0],
+        method: "verify_escrow",
+        args,
+      },
+    ] as const,
+  getDeposit: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...escrowQueryKeys.address(contractAddress)[0],
+        method: "get_deposit",
+        args,
+      },
+    ] as const,
+  getChannel: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...escrowQueryKeys.address(contractAddress)[0],
+        method: "get_channel",
+        args,
+      },
+    ] as const,
+  getSubscription: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...escrowQueryKeys.address(contractAddress)[0],
+        method: "get_subscription",
+        args,
+      },
+    ] as const,
+  getConfig: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...escrowQueryKeys.address(contractAddress)[0],
+        method: "get_config",
+        args,
+      },
+    ] as const,
+  getEffectiveFee: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...escrowQueryKeys.address(contractAddress)[0],
+        method: "get_effective_fee",
+        args,
+      },
+    ] as const,
+  getUnaccountedBalance: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...escrowQueryKeys.address(contractAddress)[0],
+        method: "get_unaccounted_balance",
+        args,
+      },
+    ] as const,
   getCollectedFees: (
     contractAddress: string | undefined,
     args?: Record<string, unknown>
@@ -82,9 +197,12 @@ export interface EscrowGetEscrowsQuery<TData>
   extends EscrowReactQuery<EscrowsResponse, TData> {
   args: {
     caller?: string;
+    descending?: boolean;
     limit?: number;
     provider?: string;
     startAfter?: number;
+    status?: EscrowStatus;
+    toolId?: string;
   };
 }
 
@@ -99,9 +217,12 @@ export function useEscrowGetEscrowsQuery<TData = EscrowsResponse>({
       client
         ? client.getEscrows({
             caller: args.caller,
+            descending: args.descending,
             limit: args.limit,
             provider: args.provider,
             startAfter: args.startAfter,
+            status: args.status,
+            toolId: args.toolId,
           })
         : Promise.reject(new Error("Invalid client")),
     {
@@ -133,24 +254,24 @@ export function useEscrowGetCollectedFeesQuery<TData = CollectedFeesResponse>({
   );
 }
 
-export interface EscrowGetEscrowQuery<TData>
-  extends EscrowReactQuery<EscrowResponse, TData> {
+export interface EscrowGetUnaccountedBalanceQuery<TData>
+  extends EscrowReactQuery<UnaccountedBalanceResponse, TData> {
   args: {
-    escrowId: number;
+    denom: string;
   };
 }
 
-export function useEscrowGetEscrowQuery<TData = EscrowResponse>({
+export function useEscrowGetUnaccountedBalanceQuery<TData = UnaccountedBalanceResponse>({
   client,
   args,
   options,
-}: EscrowGetEscrowQuery<TData>) {
-  return useQuery<EscrowResponse, Error, TData>(
-    escrowQueryKeys.getEscrow(client?.contractAddress, args),
+}: EscrowGetUnaccountedBalanceQuery<TData>) {
+  return useQuery<UnaccountedBalanceResponse, Error, TData>(
+    escrowQueryKeys.getUnaccountedBalance(client?.contractAddress, args),
     () =>
       client
-        ? client.getEscrow({
-            escrowId: args.escrowId,
+        ? client.getUnaccountedBalance({
+            denom: args.denom,
           })
         : Promise.reject(new Error("Invalid client")),
     {
@@ -161,78 +282,1022 @@ export function useEscrowGetEscrowQuery<TData = EscrowResponse>({
   );
 }
 
-export interface EscrowClaimFeesMutation {
-  client: EscrowClient;
-  msg: {
-    denom?: string;
+export interface EscrowGetEffectiveFeeQuery<TData>
+  extends EscrowReactQuery<EffectiveFeeResponse, TData> {
+  args: {
+    provider: string;
+    toolId: string;
   };
-  args?: {
-    fee?: number | StdFee | "auto";
-    memo?: string;
-    funds?: Coin[];
+}
+
+export function useEscrowGetEffectiveFeeQuery<TData = EffectiveFeeResponse>({
+  client,
+  args,
+  options,
+}: EscrowGetEffectiveFeeQuery<TData>) {
+  return useQuery<EffectiveFeeResponse, Error, TData>(
+    escrowQueryKeys.getEffectiveFee(client?.contractAddress, args),
+    () =>
+      client
+        ? client.getEffectiveFee({
+            provider: args.provider,
+            toolId: args.toolId,
+          })
+        : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
+  );
+}
+
+export interface EscrowGetConfigQuery<TData>
+  extends EscrowReactQuery<ConfigResponse, TData> {}
+
+export function useEscrowGetConfigQuery<TData = ConfigResponse>({
+  client,
+  options,
+}: EscrowGetConfigQuery<TData>) {
+  return useQuery<ConfigResponse, Error, TData>(
+    escrowQueryKeys.getConfig(client?.contractAddress),
+    () =>
+      client ? client.getConfig() : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
+  );
+}
+
+export interface EscrowGetSubscriptionQuery<TData>
+  extends EscrowReactQuery<NullableSubscriptionResponse, TData> {
+  args: {
+    caller: string;
+    toolId: string;
   };
 }
 
-export function useEscrowClaimFeesMutation(
-  options?: Omit<
-    UseMutationOptions<ExecuteResult, Error, EscrowClaimFeesMutation>,
-    "mutationFn"
-  >
-) {
-  return useMutation<ExecuteResult, Error, EscrowClaimFeesMutation>(
-    ({ client, msg, args: { fee, memo, funds } = {} }) =>
-      client.claimFees(msg, fee, memo, funds),
-    options
+export function useEscrowGetSubscriptionQuery<TData = NullableSubscriptionResponse>({
+  client,
+  args,
+  options,
+}: EscrowGetSubscriptionQuery<TData>) {
+  return useQuery<NullableSubscriptionResponse, Error, TData>(
+    escrowQueryKeys.getSubscription(client?.contractAddress, args),
+    () =>
+      client
+        ? client.getSubscription({
+            caller: args.caller,
+            toolId: args.toolId,
+          })
+        : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
   );
 }
 
-export interface EscrowRefundExpiredMutation {
-  client: EscrowClient;
-  msg: {
-    escrowId: number;
+export interface EscrowGetChannelQuery<TData>
+  extends EscrowReactQuery<ChannelResponse, TData> {
+  args: {
+    channelId: number;
   };
-  args?: {
-    fee?: number | StdFee | "auto";
-    memo?: string;
-    funds?: Coin[];
+}
+
+export function useEscrowGetChannelQuery<TData = ChannelResponse>({
+  client,
+  args,
+  options,
+}: EscrowGetChannelQuery<TData>) {
+  return useQuery<ChannelResponse, Error, TData>(
+    escrowQueryKeys.getChannel(client?.contractAddress, args),
+    () =>
+      client
+        ? client.getChannel({
+            channelId: args.channelId,
+          })
+        : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
+  );
+}
+
+export interface EscrowGetDepositQuery<TData>
+  extends EscrowReactQuery<DepositResponse, TData> {
+  args: {
+    caller: string;
+    denom: string;
   };
 }
 
-export function useEscrowRefundExpiredMutation(
-  options?: Omit<
-    UseMutationOptions<ExecuteResult, Error, EscrowRefundExpiredMutation>,
-    "mutationFn"
-  >
-) {
-  return useMutation<ExecuteResult, Error, EscrowRefundExpiredMutation>(
-    ({ client, msg, args: { fee, memo, funds } = {} }) =>
-      client.refundExpired(msg, fee, memo, funds),
-    options
+export function useEscrowGetDepositQuery<TData = DepositResponse>({
+  client,
+  args,
+  options,
+}: EscrowGetDepositQuery<TData>) {
+  return useQuery<DepositResponse, Error, TData>(
+    escrowQueryKeys.getDeposit(client?.contractAddress, args),
+    () =>
+      client
+        ? client.getDeposit({
+            caller: args.caller,
+            denom: args.denom,
+          })
+        : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
   );
 }
 
-export interface EscrowReleaseMutation {
-  client: EscrowClient;
-  msg: {
+export interface EscrowVerifyEscrowQuery<TData>
+  extends EscrowReactQuery<VerifyEscrowResponse, TData> {
+  args: {
     escrowId: number;
-    usageFee: Uint128;
+    minAmount: Uint128;
+    provider: string;
+    toolId: string;
   };
-  args?: {
-    fee?: number | StdFee | "auto";
-    memo?: string;
-    funds?: Coin[];
+}
+
+export function useEscrowVerifyEscrowQuery<TData = VerifyEscrowResponse>({
+  client,
+  args,
+  options,
+}: EscrowVerifyEscrowQuery<TData>) {
+  return useQuery<VerifyEscrowResponse, Error, TData>(
+    escrowQueryKeys.verifyEscrow(client?.contractAddress, args),
+    () =>
+      client
+        ? client.verifyEscrow({
+            escrowId: args.escrowId,
+            minAmount: args.minAmount,
+            provider: args.provider,
+            toolId: args.toolId,
+          })
+        : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
+  );
+}
+
+export interface EscrowVerifyEscrowTokenQuery<TData>
+  extends EscrowReactQuery<VerifyEscrowTokenResponse, TData> {
+  args: {
+    escrowId: number;
+    token: string;
   };
 }
 
-export function useEscrowReleaseMutation(
-  options?: Omit<
-    UseMutationOptions<ExecuteResult, Error, EscrowReleaseMutation>,
-    "mutationFn"
-  >
-) {
-  return useMutation<ExecuteResult, Error, EscrowReleaseMutation>(
-    ({ client, msg, args: { fee, memo, funds } = {} }) =>
-      client.release(msg, fee, memo, funds),
+export function useEscrowVerifyEscrowTokenQuery<TData = VerifyEscrowTokenResponse>({
+  client,
+  args,
+  options,
+}: EscrowVerifyEscrowTokenQuery<TData>) {
+  return useQuery<VerifyEscrowTokenResponse, Error, TData>(
+    escrowQueryKeys.verifyEscrowToken(client?.contractAddress, args),
+    () =>
+      client
+        ? client.verifyEscrowToken({
+            escrowId: args.escrowId,
+            token: args.token,
+          })
+        : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
+  );
+}
+
+export interface EscrowGetReceiptQuery<TData>
+  extends EscrowReactQuery<ReceiptResponse, TData> {
+  args: {
+    escrowId: number;
+  };
+}
+
+export function useEscrowGetReceiptQuery<TData = ReceiptResponse>({
+  client,
+  args,
+  options,
+}: EscrowGetReceiptQuery<TData>) {
+  return useQuery<ReceiptResponse, Error, TData>(
+    escrowQueryKeys.getReceipt(client?.contractAddress, args),
+    () =>
+      client
+        ? client.getReceipt({
+            escrowId: args.escrowId,
+          })
+        : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
+  );
+}
+
+export interface EscrowGetEscrowQuery<TData>
+  extends EscrowReactQuery<EscrowResponse, TData> {
+  args: {
+    escrowId: number;
+  };
+}
+
+export function useEscrowGetEscrowQuery<TData = EscrowResponse>({
+  client,
+  args,
+  options,
+}: EscrowGetEscrowQuery<TData>) {
+  return useQuery<EscrowResponse, Error, TData>(
+    escrowQueryKeys.getEscrow(client?.contractAddress, args),
+    () =>
+      client
+        ? client.getEscrow({
+            escrowId: args.escrowId,
+          })
+        : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
+  );
+}
+
+export interface EscrowPruneReceiptsMutation {
+  client: EscrowClient;
+  msg: {
+    escrowIds: number[];
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowPruneReceiptsMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowPruneReceiptsMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowPruneReceiptsMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.pruneReceipts(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowClaimFeesMutation {
+  client: EscrowClient;
+  msg: {
+    denom?: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowClaimFeesMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowClaimFeesMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowClaimFeesMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.claimFees(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowRecoverUnaccountedMutation {
+  client: EscrowClient;
+  msg: {
+    denom: string;
+    recipient?: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowRecoverUnaccountedMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowRecoverUnaccountedMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowRecoverUnaccountedMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.recoverUnaccounted(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowSetPauseMutation {
+  client: EscrowClient;
+  msg: {
+    feeClaims?: boolean;
+    locks?: boolean;
+    releases?: boolean;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowSetPauseMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowSetPauseMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowSetPauseMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.setPause(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowCancelEscrowMutation {
+  client: EscrowClient;
+  msg: {
+    escrowId: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowCancelEscrowMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowCancelEscrowMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowCancelEscrowMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.cancelEscrow(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowAcknowledgeEscrowMutation {
+  client: EscrowClient;
+  msg: {
+    escrowId: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowAcknowledgeEscrowMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowAcknowledgeEscrowMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowAcknowledgeEscrowMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.acknowledgeEscrow(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowDeclineEscrowMutation {
+  client: EscrowClient;
+  msg: {
+    escrowId: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowDeclineEscrowMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowDeclineEscrowMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowDeclineEscrowMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.declineEscrow(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowEmergencyWithdrawMutation {
+  client: EscrowClient;
+  msg: {
+    escrowId: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowEmergencyWithdrawMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowEmergencyWithdrawMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowEmergencyWithdrawMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.emergencyWithdraw(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowAcceptOwnerMutation {
+  client: EscrowClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowAcceptOwnerMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowAcceptOwnerMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowAcceptOwnerMutation>(
+    ({ client, args: { fee, memo, funds } = {} }) =>
+      client.acceptOwner(fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowProposeOwnerMutation {
+  client: EscrowClient;
+  msg: {
+    newOwner: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowProposeOwnerMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowProposeOwnerMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowProposeOwnerMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.proposeOwner(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowSetFeeOverrideMutation {
+  client: EscrowClient;
+  msg: {
+    feeBps?: number;
+    target: FeeOverrideTarget;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowSetFeeOverrideMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowSetFeeOverrideMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowSetFeeOverrideMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.setFeeOverride(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowUpdateConfigMutation {
+  client: EscrowClient;
+  msg: {
+    expiryBounds?: ExpiryBounds;
+    feeBps?: number;
+    keeperRewardBps?: number;
+    registryAddr?: string;
+    withdrawalDelay?: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowUpdateConfigMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowUpdateConfigMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowUpdateConfigMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.updateConfig(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowCancelSubscriptionMutation {
+  client: EscrowClient;
+  msg: {
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowCancelSubscriptionMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowCancelSubscriptionMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowCancelSubscriptionMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.cancelSubscription(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowClaimSubscriptionMutation {
+  client: EscrowClient;
+  msg: {
+    caller: string;
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowClaimSubscriptionMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowClaimSubscriptionMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowClaimSubscriptionMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.claimSubscription(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowSubscribeMutation {
+  client: EscrowClient;
+  msg: {
+    periods: number;
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowSubscribeMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowSubscribeMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowSubscribeMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.subscribe(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowCloseChannelMutation {
+  client: EscrowClient;
+  msg: {
+    channelId: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowCloseChannelMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowCloseChannelMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowCloseChannelMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.closeChannel(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowRequestCloseChannelMutation {
+  client: EscrowClient;
+  msg: {
+    channelId: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowRequestCloseChannelMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowRequestCloseChannelMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowRequestCloseChannelMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.requestCloseChannel(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowSettleChannelMutation {
+  client: EscrowClient;
+  msg: {
+    amount: Uint128;
+    channelId: number;
+    signature: Binary;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowSettleChannelMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowSettleChannelMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowSettleChannelMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.settleChannel(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowOpenChannelMutation {
+  client: EscrowClient;
+  msg: {
+    keyType: KeyType;
+    pubkey: Binary;
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowOpenChannelMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowOpenChannelMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowOpenChannelMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.openChannel(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowClaimWithdrawalMutation {
+  client: EscrowClient;
+  msg: {
+    denom: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowClaimWithdrawalMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowClaimWithdrawalMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowClaimWithdrawalMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.claimWithdrawal(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowWithdrawMutation {
+  client: EscrowClient;
+  msg: {
+    amount: Uint128;
+    denom: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowWithdrawMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowWithdrawMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowWithdrawMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.withdraw(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowDepositMutation {
+  client: EscrowClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowDepositMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowDepositMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowDepositMutation>(
+    ({ client, args: { fee, memo, funds } = {} }) =>
+      client.deposit(fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowSweepExpiredMutation {
+  client: EscrowClient;
+  msg: {
+    limit?: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowSweepExpiredMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowSweepExpiredMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowSweepExpiredMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.sweepExpired(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowRefundExpiredBatchMutation {
+  client: EscrowClient;
+  msg: {
+    escrowIds: number[];
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowRefundExpiredBatchMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowRefundExpiredBatchMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowRefundExpiredBatchMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.refundExpiredBatch(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowRefundExpiredMutation {
+  client: EscrowClient;
+  msg: {
+    escrowId: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowRefundExpiredMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowRefundExpiredMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowRefundExpiredMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.refundExpired(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowCloseMutation {
+  client: EscrowClient;
+  msg: {
+    escrowId: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowCloseMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowCloseMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowCloseMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.close(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowChargeMutation {
+  client: EscrowClient;
+  msg: {
+    amount: Uint128;
+    escrowId: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowChargeMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowChargeMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowChargeMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.charge(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowReleaseBatchMutation {
+  client: EscrowClient;
+  msg: {
+    items: ReleaseItem[];
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowReleaseBatchMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowReleaseBatchMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowReleaseBatchMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.releaseBatch(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowReleaseMutation {
+  client: EscrowClient;
+  msg: {
+    escrowId: number;
+    usageFee: Uint128;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowReleaseMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowReleaseMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowReleaseMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.release(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface EscrowReceiveMutation {
+  client: EscrowClient;
+  msg: {
+    amount: Uint128;
+    msg: Binary;
+    sender: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useEscrowReceiveMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, EscrowReceiveMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, EscrowReceiveMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.receive(msg, fee, memo, funds),
     options
   );
 }
@@ -240,8 +1305,10 @@ export function useEscrowReleaseMutation(
 export interface EscrowLockFundsMutation {
   client: EscrowClient;
   msg: {
-    authToken: string;
-    expires: number;
+    authCommitment: Binary;
+    expectedPrice?: Uint128;
+    expires: Expiration;
+    fromDeposit?: boolean;
     maxFee: Uint128;
     toolId: string;
   };
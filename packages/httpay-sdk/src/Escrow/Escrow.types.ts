@@ -5,67 +5,418 @@
  */
 
 export interface InstantiateMsg {
-  fee_percentage: number;
+  expiry_bounds?: ExpiryBounds | null;
+  fee_bps: number;
   registry_addr: string;
+  withdrawal_delay?: number | null;
+}
+export interface ExpiryBounds {
+  max_blocks: number;
+  max_seconds: number;
+  min_blocks: number;
+  min_seconds: number;
 }
 export type ExecuteMsg =
   | {
       lock_funds: {
-        auth_token: string;
-        expires: number;
+        auth_commitment: Binary;
+        expected_price?: Uint128 | null;
+        expires: Expiration;
+        from_deposit?: boolean | null;
         max_fee: Uint128;
         tool_id: string;
       };
     }
+  | {
+      receive: Cw20ReceiveMsg;
+    }
   | {
       release: {
         escrow_id: number;
         usage_fee: Uint128;
       };
     }
+  | {
+      release_batch: {
+        items: ReleaseItem[];
+      };
+    }
+  | {
+      charge: {
+        amount: Uint128;
+        escrow_id: number;
+      };
+    }
+  | {
+      close: {
+        escrow_id: number;
+      };
+    }
   | {
       refund_expired: {
         escrow_id: number;
       };
     }
+  | {
+      refund_expired_batch: {
+        escrow_ids: number[];
+      };
+    }
+  | {
+      sweep_expired: {
+        limit?: number | null;
+      };
+    }
+  | {
+      deposit: {};
+    }
+  | {
+      withdraw: {
+        amount: Uint128;
+        denom: string;
+      };
+    }
+  | {
+      claim_withdrawal: {
+        denom: string;
+      };
+    }
+  | {
+      open_channel: {
+        key_type: KeyType;
+        pubkey: Binary;
+        tool_id: string;
+      };
+    }
+  | {
+      settle_channel: {
+        amount: Uint128;
+        channel_id: number;
+        signature: Binary;
+      };
+    }
+  | {
+      request_close_channel: {
+        channel_id: number;
+      };
+    }
+  | {
+      close_channel: {
+        channel_id: number;
+      };
+    }
+  | {
+      subscribe: {
+        periods: number;
+        tool_id: string;
+      };
+    }
+  | {
+      claim_subscription: {
+        caller: string;
+        tool_id: string;
+      };
+    }
+  | {
+      cancel_subscription: {
+        tool_id: string;
+      };
+    }
+  | {
+      update_config: {
+        expiry_bounds?: ExpiryBounds | null;
+        fee_bps?: number | null;
+        keeper_reward_bps?: number | null;
+        registry_addr?: string | null;
+        withdrawal_delay?: number | null;
+      };
+    }
+  | {
+      set_fee_override: {
+        fee_bps?: number | null;
+        target: FeeOverrideTarget;
+      };
+    }
+  | {
+      propose_owner: {
+        new_owner: string;
+      };
+    }
+  | {
+      accept_owner: {};
+    }
+  | {
+      emergency_withdraw: {
+        escrow_id: number;
+      };
+    }
+  | {
+      decline_escrow: {
+        escrow_id: number;
+      };
+    }
+  | {
+      acknowledge_escrow: {
+        escrow_id: number;
+      };
+    }
+  | {
+      cancel_escrow: {
+        escrow_id: number;
+      };
+    }
+  | {
+      set_pause: {
+        fee_claims?: boolean | null;
+        locks?: boolean | null;
+        releases?: boolean | null;
+      };
+    }
+  | {
+      recover_unaccounted: {
+        denom: string;
+        recipient?: string | null;
+      };
+    }
   | {
       claim_fees: {
         denom?: string | null;
       };
+    }
+  | {
+      prune_receipts: {
+        escrow_ids: number[];
+      };
     };
+export type Binary = string;
 export type Uint128 = string;
+export type Expiration =
+  | {
+      at_height: number;
+    }
+  | {
+      at_time: Timestamp;
+    };
+export type Timestamp = Uint64;
+export type Uint64 = string;
+export type KeyType = "secp256k1" | "ed25519";
+export type FeeOverrideTarget =
+  | {
+      provider: {
+        provider: string;
+      };
+    }
+  | {
+      tool: {
+        tool_id: string;
+      };
+    };
+export interface Cw20ReceiveMsg {
+  amount: Uint128;
+  msg: Binary;
+  sender: string;
+}
+export interface ReleaseItem {
+  escrow_id: number;
+  usage_fee: Uint128;
+}
 export type QueryMsg =
   | {
       get_escrow: {
         escrow_id: number;
       };
     }
+  | {
+      get_receipt: {
+        escrow_id: number;
+      };
+    }
+  | {
+      verify_escrow_token: {
+        escrow_id: number;
+        token: string;
+      };
+    }
+  | {
+      verify_escrow: {
+        escrow_id: number;
+        min_amount: Uint128;
+        provider: string;
+        tool_id: string;
+      };
+    }
+  | {
+      get_deposit: {
+        caller: string;
+        denom: string;
+      };
+    }
+  | {
+      get_channel: {
+        channel_id: number;
+      };
+    }
+  | {
+      get_subscription: {
+        caller: string;
+        tool_id: string;
+      };
+    }
+  | {
+      get_config: {};
+    }
+  | {
+      get_effective_fee: {
+        provider: string;
+        tool_id: string;
+      };
+    }
+  | {
+      get_unaccounted_balance: {
+        denom: string;
+      };
+    }
   | {
       get_collected_fees: {};
     }
   | {
       get_escrows: {
         caller?: string | null;
+        descending?: boolean | null;
         limit?: number | null;
         provider?: string | null;
         start_after?: number | null;
+        status?: EscrowStatus | null;
+        tool_id?: string | null;
       };
     };
+export type EscrowStatus = "locked" | "released" | "refunded" | "cancelled";
+export interface MigrateMsg {}
 export type Addr = string;
+export interface ChannelResponse {
+  caller: Addr;
+  channel_id: number;
+  closed: boolean;
+  closes_at?: number | null;
+  denom: string;
+  deposit: Uint128;
+  key_type: KeyType;
+  platform_fee: Uint128;
+  provider: Addr;
+  pubkey: Binary;
+  settled: Uint128;
+  tool_id: string;
+}
 export interface CollectedFeesResponse {
   collected_fees: [string, Uint128][];
-  fee_percentage: number;
+  fee_bps: number;
   owner: Addr;
 }
+export interface ConfigResponse {
+  expiry_bounds: ExpiryBounds;
+  fee_bps: number;
+  keeper_reward_bps: number;
+  owner: Addr;
+  paused: PauseFlags;
+  pending_owner?: Addr | null;
+  registry_addr: Addr;
+  withdrawal_delay: number;
+}
+export interface PauseFlags {
+  fee_claims: boolean;
+  locks: boolean;
+  releases: boolean;
+}
+export interface DepositResponse {
+  balance: Uint128;
+  caller: Addr;
+  denom: string;
+  pending_withdrawal?: PendingWithdrawal | null;
+}
+export interface PendingWithdrawal {
+  amount: Uint128;
+  available_at: number;
+}
+export type FeeSource = "tool" | "provider" | "default";
+export interface EffectiveFeeResponse {
+  fee_bps: number;
+  source: FeeSource;
+}
 export interface EscrowResponse {
-  auth_token: string;
+  acknowledged: boolean;
+  auth_commitment: Binary;
   caller: Addr;
+  cancellable: boolean;
+  charged: Uint128;
   denom: string;
   escrow_id: number;
-  expires: number;
+  expires: Expiration;
   max_fee: Uint128;
+  platform_fee: Uint128;
+  price: Uint128;
   provider: Addr;
+  refunded: Uint128;
+  settled_at?: number | null;
+  status: EscrowStatus;
+  tool_id: string;
 }
 export interface EscrowsResponse {
   escrows: EscrowResponse[];
+  next_start_after?: number | null;
+}
+export interface ReceiptResponse {
+  caller: Addr;
+  charged: Uint128;
+  denom: string;
+  escrow_id: number;
+  max_fee: Uint128;
+  platform_fee: Uint128;
+  provider: Addr;
+  refunded: Uint128;
+  settled_at: number;
+  status: EscrowStatus;
+  tool_id: string;
+}
+export type NullableSubscriptionResponse = SubscriptionResponse | null;
+export interface SubscriptionResponse {
+  caller: Addr;
+  claimed: number;
+  denom: string;
+  entitled: boolean;
+  paid_until: number;
+  period: number;
+  periods: number;
+  prices: PaidPeriods[];
+  provider: Addr;
+  start: number;
+  tool_id: string;
+}
+export interface PaidPeriods {
+  periods: number;
+  price: Uint128;
+}
+export interface UnaccountedBalanceResponse {
+  balance: Uint128;
+  denom: string;
+  liabilities: Uint128;
+  unaccounted: Uint128;
+}
+export type VerifyEscrowReason =
+  | "not_found"
+  | "not_locked"
+  | "expired"
+  | "wrong_tool"
+  | "wrong_provider"
+  | "insufficient"
+  | "frozen";
+export interface VerifyEscrowResponse {
+  expires?: Expiration | null;
+  reason?: VerifyEscrowReason | null;
+  remaining: Uint128;
+  valid: boolean;
+}
+export interface VerifyEscrowTokenResponse {
+  expires: Expiration;
+  remaining: Uint128;
+  valid: boolean;
 }
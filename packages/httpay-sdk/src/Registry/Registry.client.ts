@@ -11,15 +11,63 @@ import {
   SigningCosmWasmClient,
 } from "@cosmjs/cosmwasm-stargate";
 import {
+  ConfigResponse,
+  ExpiryBounds,
+  NullablePendingTransferResponse,
+  ProviderStatusResponse,
+  SubscriptionTerms,
   ToolResponse,
   ToolsResponse,
-  Uint128
+  Uint128,
 } from "./Registry.types";
 
 export interface RegistryReadOnlyInterface {
   contractAddress: string;
   getTool: ({ toolId }: { toolId: string }) => Promise<ToolResponse>;
-  getTools: () => Promise<ToolsResponse>;
+  getTools: ({
+    activeOnly,
+    denom,
+    limit,
+    maxPrice,
+    provider,
+    startAfter,
+  }: {
+    activeOnly?: boolean;
+    denom?: string;
+    limit?: number;
+    maxPrice?: Uint128;
+    provider?: string;
+    startAfter?: string;
+  }) => Promise<ToolsResponse>;
+  getPendingTransfer: ({
+    toolId,
+  }: {
+    toolId: string;
+  }) => Promise<NullablePendingTransferResponse>;
+  getConfig: () => Promise<ConfigResponse>;
+  getProviderStatus: ({
+    provider,
+  }: {
+    provider: string;
+  }) => Promise<ProviderStatusResponse>;
+  listToolsByProvider: ({
+    limit,
+    provider,
+    startAfter,
+  }: {
+    limit?: number;
+    provider: string;
+    startAfter?: string;
+  }) => Promise<ToolsResponse>;
+  listToolsByDenom: ({
+    denom,
+    limit,
+    startAfter,
+  }: {
+    denom: string;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ToolsResponse>;
 }
 
 export class RegistryQueryClient implements RegistryReadOnlyInterface {
@@ -30,6 +78,11 @@ export class RegistryQueryClient implements RegistryReadOnlyInterface {
     this.contractAddress = contractAddress;
     this.getTool = this.getTool.bind(this);
     this.getTools = this.getTools.bind(this);
+    this.getPendingTransfer = this.getPendingTransfer.bind(this);
+    this.getConfig = this.getConfig.bind(this);
+    this.getProviderStatus = this.getProviderStatus.bind(this);
+    this.listToolsByProvider = this.listToolsByProvider.bind(this);
+    this.listToolsByDenom = this.listToolsByDenom.bind(this);
   }
   getTool = async ({ toolId }: { toolId: string }): Promise<ToolResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
@@ -38,9 +91,91 @@ export class RegistryQueryClient implements RegistryReadOnlyInterface {
       },
     });
   };
-  getTools = async (): Promise<ToolsResponse> => {
+  getTools = async ({
+    activeOnly,
+    denom,
+    limit,
+    maxPrice,
+    provider,
+    startAfter,
+  }: {
+    activeOnly?: boolean;
+    denom?: string;
+    limit?: number;
+    maxPrice?: Uint128;
+    provider?: string;
+    startAfter?: string;
+  }): Promise<ToolsResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      get_tools: {},
+      get_tools: {
+        active_only: activeOnly,
+        denom,
+        limit,
+        max_price: maxPrice,
+        provider,
+        start_after: startAfter,
+      },
+    });
+  };
+  getPendingTransfer = async ({
+    toolId,
+  }: {
+    toolId: string;
+  }): Promise<NullablePendingTransferResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_pending_transfer: {
+        tool_id: toolId,
+      },
+    });
+  };
+  getConfig = async (): Promise<ConfigResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_config: {},
+    });
+  };
+  getProviderStatus = async ({
+    provider,
+  }: {
+    provider: string;
+  }): Promise<ProviderStatusResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_provider_status: {
+        provider,
+      },
+    });
+  };
+  listToolsByProvider = async ({
+    limit,
+    provider,
+    startAfter,
+  }: {
+    limit?: number;
+    provider: string;
+    startAfter?: string;
+  }): Promise<ToolsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_tools_by_provider: {
+        limit,
+        provider,
+        start_after: startAfter,
+      },
+    });
+  };
+  listToolsByDenom = async ({
+    denom,
+    limit,
+    startAfter,
+  }: {
+    denom: string;
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ToolsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_tools_by_denom: {
+        denom,
+        limit,
+        start_after: startAfter,
+      },
     });
   };
 }
@@ -66,6 +201,16 @@ export interface RegistryInterface extends RegistryReadOnlyInterface {
     memo_?: string,
     funds_?: Coin[]
   ) => Promise<ExecuteResult>;
+  unregisterTool: (
+    {
+      toolId,
+    }: {
+      toolId: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
   updatePrice: (
     {
       price,
@@ -122,6 +267,130 @@ export interface RegistryInterface extends RegistryReadOnlyInterface {
     memo_?: string,
     funds_?: Coin[]
   ) => Promise<ExecuteResult>;
+  updateSubscriptionTerms: (
+    {
+      subscription,
+      toolId,
+    }: {
+      subscription?: SubscriptionTerms;
+      toolId: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  updateExpiryBounds: (
+    {
+      expiryBounds,
+      toolId,
+    }: {
+      expiryBounds?: ExpiryBounds;
+      toolId: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  updateCancellable: (
+    {
+      cancellable,
+      toolId,
+    }: {
+      cancellable: boolean;
+      toolId: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  proposeToolTransfer: (
+    {
+      newProvider,
+      toolId,
+    }: {
+      newProvider: string;
+      toolId: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  acceptToolTransfer: (
+    {
+      toolId,
+    }: {
+      toolId: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  cancelToolTransfer: (
+    {
+      toolId,
+    }: {
+      toolId: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  updateConfig: (
+    {
+      admin,
+    }: {
+      admin?: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  forcePauseTool: (
+    {
+      reason,
+      toolId,
+    }: {
+      reason?: string;
+      toolId: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  delistTool: (
+    {
+      reason,
+      toolId,
+    }: {
+      reason?: string;
+      toolId: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  blockProvider: (
+    {
+      provider,
+      reason,
+    }: {
+      provider: string;
+      reason?: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
+  unblockProvider: (
+    {
+      provider,
+    }: {
+      provider: string;
+    },
+    fee_?: number | StdFee | "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ) => Promise<ExecuteResult>;
 }
 
 export class RegistryClient
@@ -141,11 +410,23 @@ export class RegistryClient
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.registerTool = this.registerTool.bind(this);
+    this.unregisterTool = this.unregisterTool.bind(this);
     this.updatePrice = this.updatePrice.bind(this);
     this.updateDenom = this.updateDenom.bind(this);
     this.updateEndpoint = this.updateEndpoint.bind(this);
     this.pauseTool = this.pauseTool.bind(this);
     this.resumeTool = this.resumeTool.bind(this);
+    this.updateSubscriptionTerms = this.updateSubscriptionTerms.bind(this);
+    this.updateExpiryBounds = this.updateExpiryBounds.bind(this);
+    this.updateCancellable = this.updateCancellable.bind(this);
+    this.proposeToolTransfer = this.proposeToolTransfer.bind(this);
+    this.acceptToolTransfer = this.acceptToolTransfer.bind(this);
+    this.cancelToolTransfer = this.cancelToolTransfer.bind(this);
+    this.updateConfig = this.updateConfig.bind(this);
+    this.forcePauseTool = this.forcePauseTool.bind(this);
+    this.delistTool = this.delistTool.bind(this);
+    this.blockProvider = this.blockProvider.bind(this);
+    this.unblockProvider = this.unblockProvider.bind(this);
   }
   registerTool = async (
     {
@@ -182,6 +463,29 @@ export class RegistryClient
       funds_
     );
   };
+  unregisterTool = async (
+    {
+      toolId,
+    }: {
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        unregister_tool: {
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
   updatePrice = async (
     {
       price,
@@ -306,4 +610,278 @@ export class RegistryClient
       funds_
     );
   };
+  updateSubscriptionTerms = async (
+    {
+      subscription,
+      toolId,
+    }: {
+      subscription?: SubscriptionTerms;
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        update_subscription_terms: {
+          subscription,
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  updateExpiryBounds = async (
+    {
+      expiryBounds,
+      toolId,
+    }: {
+      expiryBounds?: ExpiryBounds;
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        update_expiry_bounds: {
+          expiry_bounds: expiryBounds,
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  updateCancellable = async (
+    {
+      cancellable,
+      toolId,
+    }: {
+      cancellable: boolean;
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        update_cancellable: {
+          cancellable,
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  proposeToolTransfer = async (
+    {
+      newProvider,
+      toolId,
+    }: {
+      newProvider: string;
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        propose_tool_transfer: {
+          new_provider: newProvider,
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  acceptToolTransfer = async (
+    {
+      toolId,
+    }: {
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        accept_tool_transfer: {
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  cancelToolTransfer = async (
+    {
+      toolId,
+    }: {
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        cancel_tool_transfer: {
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  updateConfig = async (
+    {
+      admin,
+    }: {
+      admin?: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        update_config: {
+          admin,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  forcePauseTool = async (
+    {
+      reason,
+      toolId,
+    }: {
+      reason?: string;
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        force_pause_tool: {
+          reason,
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  delistTool = async (
+    {
+      reason,
+      toolId,
+    }: {
+      reason?: string;
+      toolId: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        delist_tool: {
+          reason,
+          tool_id: toolId,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  blockProvider = async (
+    {
+      provider,
+      reason,
+    }: {
+      provider: string;
+      reason?: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        block_provider: {
+          provider,
+          reason,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
+  unblockProvider = async (
+    {
+      provider,
+    }: {
+      provider: string;
+    },
+    fee_: number | StdFee | "auto" = "auto",
+    memo_?: string,
+    funds_?: Coin[]
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        unblock_provider: {
+          provider,
+        },
+      },
+      fee_,
+      memo_,
+      funds_
+    );
+  };
 }
@@ -13,7 +13,16 @@ import {
   UseQueryOptions,
 } from "@tanstack/react-query";
 import { RegistryClient, RegistryQueryClient } from "./Registry.client";
-import { ToolResponse, ToolsResponse, Uint128 } from "./Registry.types";
+import {
+  ConfigResponse,
+  ExpiryBounds,
+  NullablePendingTransferResponse,
+  ProviderStatusResponse,
+  SubscriptionTerms,
+  ToolResponse,
+  ToolsResponse,
+  Uint128,
+} from "./Registry.types";
 
 export const registryQueryKeys = {
   contract: [
@@ -50,6 +59,61 @@ export const registryQueryKeys = {
         args,
       },
     ] as const,
+  getPendingTransfer: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...registryQueryKeys.address(contractAddress)[0],
+        method: "get_pending_transfer",
+        args,
+      },
+    ] as const,
+  getConfig: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...registryQueryKeys.address(contractAddress)[0],
+        method: "get_config",
+        args,
+      },
+    ] as const,
+  getProviderStatus: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...registryQueryKeys.address(contractAddress)[0],
+        method: "get_provider_status",
+        args,
+      },
+    ] as const,
+  listToolsByProvider: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...registryQueryKeys.address(contractAddress)[0],
+        method: "list_tools_by_provider",
+        args,
+      },
+    ] as const,
+  listToolsByDenom: (
+    contractAddress: string | undefined,
+    args?: Record<string, unknown>
+  ) =>
+    [
+      {
+        ...registryQueryKeys.address(contractAddress)[0],
+        method: "list_tools_by_denom",
+        args,
+      },
+    ] as const,
 };
 
 export interface RegistryReactQuery<TResponse, TData = TResponse> {
@@ -62,17 +126,175 @@ export interface RegistryReactQuery<TResponse, TData = TResponse> {
   };
 }
 
+export interface RegistryListToolsByDenomQuery<TData>
+  extends RegistryReactQuery<ToolsResponse, TData> {
+  args: {
+    denom: string;
+    limit?: number;
+    startAfter?: string;
+  };
+}
+
+export function useRegistryListToolsByDenomQuery<TData = ToolsResponse>({
+  client,
+  args,
+  options,
+}: RegistryListToolsByDenomQuery<TData>) {
+  return useQuery<ToolsResponse, Error, TData>(
+    registryQueryKeys.listToolsByDenom(client?.contractAddress, args),
+    () =>
+      client
+        ? client.listToolsByDenom({
+            denom: args.denom,
+            limit: args.limit,
+            startAfter: args.startAfter,
+          })
+        : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
+  );
+}
+
+export interface RegistryListToolsByProviderQuery<TData>
+  extends RegistryReactQuery<ToolsResponse, TData> {
+  args: {
+    limit?: number;
+    provider: string;
+    startAfter?: string;
+  };
+}
+
+export function useRegistryListToolsByProviderQuery<TData = ToolsResponse>({
+  client,
+  args,
+  options,
+}: RegistryListToolsByProviderQuery<TData>) {
+  return useQuery<ToolsResponse, Error, TData>(
+    registryQueryKeys.listToolsByProvider(client?.contractAddress, args),
+    () =>
+      client
+        ? client.listToolsByProvider({
+            limit: args.limit,
+            provider: args.provider,
+            startAfter: args.startAfter,
+          })
+        : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
+  );
+}
+
+export interface RegistryGetProviderStatusQuery<TData>
+  extends RegistryReactQuery<ProviderStatusResponse, TData> {
+  args: {
+    provider: string;
+  };
+}
+
+export function useRegistryGetProviderStatusQuery<TData = ProviderStatusResponse>({
+  client,
+  args,
+  options,
+}: RegistryGetProviderStatusQuery<TData>) {
+  return useQuery<ProviderStatusResponse, Error, TData>(
+    registryQueryKeys.getProviderStatus(client?.contractAddress, args),
+    () =>
+      client
+        ? client.getProviderStatus({
+            provider: args.provider,
+          })
+        : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
+  );
+}
+
+export interface RegistryGetConfigQuery<TData>
+  extends RegistryReactQuery<ConfigResponse, TData> {}
+
+export function useRegistryGetConfigQuery<TData = ConfigResponse>({
+  client,
+  options,
+}: RegistryGetConfigQuery<TData>) {
+  return useQuery<ConfigResponse, Error, TData>(
+    registryQueryKeys.getConfig(client?.contractAddress),
+    () =>
+      client ? client.getConfig() : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
+  );
+}
+
+export interface RegistryGetPendingTransferQuery<TData>
+  extends RegistryReactQuery<NullablePendingTransferResponse, TData> {
+  args: {
+    toolId: string;
+  };
+}
+
+export function useRegistryGetPendingTransferQuery<TData = NullablePendingTransferResponse>({
+  client,
+  args,
+  options,
+}: RegistryGetPendingTransferQuery<TData>) {
+  return useQuery<NullablePendingTransferResponse, Error, TData>(
+    registryQueryKeys.getPendingTransfer(client?.contractAddress, args),
+    () =>
+      client
+        ? client.getPendingTransfer({
+            toolId: args.toolId,
+          })
+        : Promise.reject(new Error("Invalid client")),
+    {
+      ...options,
+      enabled:
+        !!client && (options?.enabled != undefined ? options.enabled : true),
+    }
+  );
+}
+
 export interface RegistryGetToolsQuery<TData>
-  extends RegistryReactQuery<ToolsResponse, TData> {}
+  extends RegistryReactQuery<ToolsResponse, TData> {
+  args: {
+    activeOnly?: boolean;
+    denom?: string;
+    limit?: number;
+    maxPrice?: Uint128;
+    provider?: string;
+    startAfter?: string;
+  };
+}
 
-  export function useRegistryGetToolsQuery<TData = ToolsResponse>({
+export function useRegistryGetToolsQuery<TData = ToolsResponse>({
   client,
+  args,
   options,
 }: RegistryGetToolsQuery<TData>) {
   return useQuery<ToolsResponse, Error, TData>(
-    registryQueryKeys.getTools(client?.contractAddress),
+    registryQueryKeys.getTools(client?.contractAddress, args),
     () =>
-      client ? client.getTools() : Promise.reject(new Error("Invalid client")),
+      client
+        ? client.getTools({
+            activeOnly: args.activeOnly,
+            denom: args.denom,
+            limit: args.limit,
+            maxPrice: args.maxPrice,
+            provider: args.provider,
+            startAfter: args.startAfter,
+          })
+        : Promise.reject(new Error("Invalid client")),
     {
       ...options,
       enabled:
@@ -109,6 +331,312 @@ export function useRegistryGetToolQuery<TData = ToolResponse>({
   );
 }
 
+export interface RegistryUnblockProviderMutation {
+  client: RegistryClient;
+  msg: {
+    provider: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useRegistryUnblockProviderMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, RegistryUnblockProviderMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, RegistryUnblockProviderMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.unblockProvider(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface RegistryBlockProviderMutation {
+  client: RegistryClient;
+  msg: {
+    provider: string;
+    reason?: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useRegistryBlockProviderMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, RegistryBlockProviderMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, RegistryBlockProviderMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.blockProvider(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface RegistryDelistToolMutation {
+  client: RegistryClient;
+  msg: {
+    reason?: string;
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useRegistryDelistToolMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, RegistryDelistToolMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, RegistryDelistToolMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.delistTool(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface RegistryForcePauseToolMutation {
+  client: RegistryClient;
+  msg: {
+    reason?: string;
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useRegistryForcePauseToolMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, RegistryForcePauseToolMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, RegistryForcePauseToolMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.forcePauseTool(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface RegistryUpdateConfigMutation {
+  client: RegistryClient;
+  msg: {
+    admin?: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useRegistryUpdateConfigMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, RegistryUpdateConfigMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, RegistryUpdateConfigMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.updateConfig(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface RegistryCancelToolTransferMutation {
+  client: RegistryClient;
+  msg: {
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useRegistryCancelToolTransferMutation(
+  options?: Omit<
+    UseMutationOptions<
+      ExecuteResult,
+      Error,
+      RegistryCancelToolTransferMutation
+    >,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, RegistryCancelToolTransferMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.cancelToolTransfer(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface RegistryAcceptToolTransferMutation {
+  client: RegistryClient;
+  msg: {
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useRegistryAcceptToolTransferMutation(
+  options?: Omit<
+    UseMutationOptions<
+      ExecuteResult,
+      Error,
+      RegistryAcceptToolTransferMutation
+    >,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, RegistryAcceptToolTransferMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.acceptToolTransfer(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface RegistryProposeToolTransferMutation {
+  client: RegistryClient;
+  msg: {
+    newProvider: string;
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useRegistryProposeToolTransferMutation(
+  options?: Omit<
+    UseMutationOptions<
+      ExecuteResult,
+      Error,
+      RegistryProposeToolTransferMutation
+    >,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, RegistryProposeToolTransferMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.proposeToolTransfer(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface RegistryUpdateCancellableMutation {
+  client: RegistryClient;
+  msg: {
+    cancellable: boolean;
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useRegistryUpdateCancellableMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, RegistryUpdateCancellableMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, RegistryUpdateCancellableMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.updateCancellable(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface RegistryUpdateExpiryBoundsMutation {
+  client: RegistryClient;
+  msg: {
+    expiryBounds?: ExpiryBounds;
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useRegistryUpdateExpiryBoundsMutation(
+  options?: Omit<
+    UseMutationOptions<
+      ExecuteResult,
+      Error,
+      RegistryUpdateExpiryBoundsMutation
+    >,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, RegistryUpdateExpiryBoundsMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.updateExpiryBounds(msg, fee, memo, funds),
+    options
+  );
+}
+
+export interface RegistryUpdateSubscriptionTermsMutation {
+  client: RegistryClient;
+  msg: {
+    subscription?: SubscriptionTerms;
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useRegistryUpdateSubscriptionTermsMutation(
+  options?: Omit<
+    UseMutationOptions<
+      ExecuteResult,
+      Error,
+      RegistryUpdateSubscriptionTermsMutation
+    >,
+    "mutationFn"
+  >
+) {
+  return useMutation<
+    ExecuteResult,
+    Error,
+    RegistryUpdateSubscriptionTermsMutation
+  >(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.updateSubscriptionTerms(msg, fee, memo, funds),
+    options
+  );
+}
+
 export interface RegistryResumeToolMutation {
   client: RegistryClient;
   msg: {
@@ -237,6 +765,31 @@ export function useRegistryUpdatePriceMutation(
   );
 }
 
+export interface RegistryUnregisterToolMutation {
+  client: RegistryClient;
+  msg: {
+    toolId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+
+export function useRegistryUnregisterToolMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, RegistryUnregisterToolMutation>,
+    "mutationFn"
+  >
+) {
+  return useMutation<ExecuteResult, Error, RegistryUnregisterToolMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.unregisterTool(msg, fee, memo, funds),
+    options
+  );
+}
+
 export interface RegistryRegisterToolMutation {
   client: RegistryClient;
   msg: {
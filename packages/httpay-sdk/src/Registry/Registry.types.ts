@@ -4,7 +4,9 @@
  * and run the @cosmwasm/ts-codegen generate command to regenerate this file.
  */
 
-export interface InstantiateMsg {}
+export interface InstantiateMsg {
+  admin?: string | null;
+}
 export type ExecuteMsg =
   | {
       register_tool: {
@@ -15,6 +17,11 @@ export type ExecuteMsg =
         tool_id: string;
       };
     }
+  | {
+      unregister_tool: {
+        tool_id: string;
+      };
+    }
   | {
       update_price: {
         price: Uint128;
@@ -42,8 +49,80 @@ export type ExecuteMsg =
       resume_tool: {
         tool_id: string;
       };
+    }
+  | {
+      update_subscription_terms: {
+        subscription?: SubscriptionTerms | null;
+        tool_id: string;
+      };
+    }
+  | {
+      update_expiry_bounds: {
+        expiry_bounds?: ExpiryBounds | null;
+        tool_id: string;
+      };
+    }
+  | {
+      update_cancellable: {
+        cancellable: boolean;
+        tool_id: string;
+      };
+    }
+  | {
+      propose_tool_transfer: {
+        new_provider: string;
+        tool_id: string;
+      };
+    }
+  | {
+      accept_tool_transfer: {
+        tool_id: string;
+      };
+    }
+  | {
+      cancel_tool_transfer: {
+        tool_id: string;
+      };
+    }
+  | {
+      update_config: {
+        admin?: string | null;
+      };
+    }
+  | {
+      force_pause_tool: {
+        reason?: string | null;
+        tool_id: string;
+      };
+    }
+  | {
+      delist_tool: {
+        reason?: string | null;
+        tool_id: string;
+      };
+    }
+  | {
+      block_provider: {
+        provider: string;
+        reason?: string | null;
+      };
+    }
+  | {
+      unblock_provider: {
+        provider: string;
+      };
     };
 export type Uint128 = string;
+export interface SubscriptionTerms {
+  period: number;
+  price: Uint128;
+}
+export interface ExpiryBounds {
+  max_blocks: number;
+  max_seconds: number;
+  min_blocks: number;
+  min_seconds: number;
+}
 export type QueryMsg =
   | {
       get_tool: {
@@ -51,17 +130,72 @@ export type QueryMsg =
       };
     }
   | {
-      get_tools: {};
+      get_tools: {
+        active_only?: boolean | null;
+        denom?: string | null;
+        limit?: number | null;
+        max_price?: Uint128 | null;
+        provider?: string | null;
+        start_after?: string | null;
+      };
+    }
+  | {
+      get_pending_transfer: {
+        tool_id: string;
+      };
+    }
+  | {
+      get_config: {};
+    }
+  | {
+      get_provider_status: {
+        provider: string;
+      };
+    }
+  | {
+      list_tools_by_provider: {
+        limit?: number | null;
+        provider: string;
+        start_after?: string | null;
+      };
+    }
+  | {
+      list_tools_by_denom: {
+        denom: string;
+        limit?: number | null;
+        start_after?: string | null;
+      };
     };
+export interface MigrateMsg {
+  admin?: string | null;
+}
+export interface ConfigResponse {
+  admin: string;
+}
+export type NullablePendingTransferResponse = PendingTransferResponse | null;
+export interface PendingTransferResponse {
+  new_provider: string;
+  provider: string;
+  tool_id: string;
+}
+export interface ProviderStatusResponse {
+  blocked: boolean;
+  provider: string;
+}
 export interface ToolResponse {
+  cancellable: boolean;
   denom: string;
   description: string;
   endpoint: string;
+  expiry_bounds?: ExpiryBounds | null;
   is_active: boolean;
   price: Uint128;
   provider: string;
+  subscription?: SubscriptionTerms | null;
+  suspended: boolean;
   tool_id: string;
 }
 export interface ToolsResponse {
+  next_start_after?: string | null;
   tools: ToolResponse[];
 }
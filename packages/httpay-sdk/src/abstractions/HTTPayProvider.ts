@@ -71,17 +71,29 @@ export class HTTPayProvider {
         escrowId: escrowId
       });
 
-      // Validate auth token (simple comparison for now)
-      if (escrowResponse.auth_token !== payment.authToken) {
+      // Settled escrows are kept as receipts, so only a locked one can be used
+      if (escrowResponse.status !== 'locked') {
         return {
           isValid: false,
-          error: 'Invalid authentication token'
+          error: `Escrow is no longer active (${escrowResponse.status})`
         };
       }
 
-      // Check if funds are sufficient (escrow exists means it's active)
-      const maxFee = parseFloat(escrowResponse.max_fee || '0');
-      if (maxFee <= 0) {
+      // The contract hashes the token and checks it against the escrow's commitment
+      const verification = await escrowQueryClient.verifyEscrowToken({
+        escrowId: escrowId,
+        token: payment.authToken
+      });
+
+      if (!verification.valid) {
+        return {
+          isValid: false,
+          error: 'Invalid authentication token or expired escrow'
+        };
+      }
+
+      // Check if funds are sufficient for another charge
+      if (parseFloat(verification.remaining) <= 0) {
         return {
           isValid: false,
           error: 'Insufficient funds in escrow'
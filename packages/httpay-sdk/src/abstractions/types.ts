@@ -1,3 +1,5 @@
+import type { Expiration } from '../Escrow/Escrow.types';
+
 export interface HTTPayConfig {
   rpcEndpoint: string;
   registryAddress: string;
@@ -21,7 +23,7 @@ export interface PaymentValidationResult {
     id: number;
     provider: string;
     maxFee: string;
-    expires: any;
+    expires: Expiration;
   };
   error?: string;
 }
@@ -45,7 +47,7 @@ export interface APIResponse<T = any> {
     id: number;
     provider: string;
     maxFee: string;
-    expires: any;
+    expires: Expiration;
   };
   usage?: {
     timestamp: string;
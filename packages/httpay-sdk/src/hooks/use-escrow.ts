@@ -11,7 +11,7 @@ import type {
   LoadingStates, 
   HTTPayClients 
 } from "../types";
-import { handleSDKError, toBase64, extractEscrowIdFromTx } from "../utils/client-utils";
+import { handleSDKError, computeAuthCommitment, extractEscrowIdFromTx, isExpired } from "../utils/client-utils";
 import type { ToastFunction } from "./use-registry";
 
 interface UseEscrowProps {
@@ -104,8 +104,8 @@ export function useEscrow({
         setEscrows(prev => [...prev, ...result.escrows]);
       }
       
-      // Check if there are more escrows available
-      setHasMoreEscrows(result.escrows.length >= (activeFilter.limit || 10));
+      // The contract returns a cursor only when another page exists
+      setHasMoreEscrows(result.next_start_after != null);
       
     } catch (error) {
       handleError(error, "loading escrows");
@@ -142,10 +142,10 @@ export function useEscrow({
       setLoadingState("lockFunds", true);
 
       const currentBlockHeight = await getCurrentBlockHeight();
-      const expires = currentBlockHeight + parseInt(escrowData.ttl);
+      const expires = { at_height: currentBlockHeight + parseInt(escrowData.ttl) };
       
-      // Convert authToken to base64 if needed
-      const authToken = toBase64(escrowData.authToken);
+      // Only the sha256 commitment to the auth token is stored on chain
+      const authCommitment = await computeAuthCommitment(escrowData.authToken);
       
       // Prepare funds to send with the transaction
       const funds: Coin[] = [{ denom: "untrn", amount: escrowData.maxFee }];
@@ -153,7 +153,7 @@ export function useEscrow({
       const result = await clients.escrow.lockFunds({
         toolId: escrowData.toolId,
         maxFee: escrowData.maxFee,
-        authToken,
+        authCommitment,
         expires,
       }, "auto", undefined, funds);
 
@@ -200,8 +200,18 @@ export function useEscrow({
       // Get current block height
       const blockHeight = await getCurrentBlockHeight();
       
+      // Settled escrows are kept as receipts, so only a locked one can be used
+      if (escrow.status !== "locked") {
+        return {
+          isValid: false,
+          error: `Escrow is no longer active (${escrow.status})`,
+          escrow,
+          blockHeight,
+        };
+      }
+
       // Check if escrow is expired
-      if (escrow.expires < blockHeight) {
+      if (isExpired(escrow.expires, blockHeight)) {
         return {
           isValid: false,
           error: "Escrow is expired",
@@ -220,8 +230,12 @@ export function useEscrow({
         };
       }
       
-      // Check if auth token matches
-      if (escrow.auth_token !== verificationData.authToken) {
+      // The contract checks the token against the escrow's commitment
+      const { valid } = await clients.escrowQuery.verifyEscrowToken({
+        escrowId,
+        token: verificationData.authToken,
+      });
+      if (!valid) {
         return {
           isValid: false,
           error: "Auth token mismatch",
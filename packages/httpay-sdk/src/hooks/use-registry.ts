@@ -72,8 +72,15 @@ export function useRegistry({
 
     try {
       setLoadingState("tools", true);
-      const response = await clients.registryQuery.getTools();
-      setTools(response.tools);
+      // GetTools is paginated; follow the cursor until every page is loaded
+      const allTools: Tool[] = [];
+      let startAfter: string | undefined;
+      do {
+        const response = await clients.registryQuery.getTools({ startAfter });
+        allTools.push(...response.tools);
+        startAfter = response.next_start_after ?? undefined;
+      } while (startAfter);
+      setTools(allTools);
     } catch (error) {
       handleError(error, "loading tools");
     } finally {
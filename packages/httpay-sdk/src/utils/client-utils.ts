@@ -1,6 +1,7 @@
 import { CosmWasmClient, SigningCosmWasmClient } from "@cosmjs/cosmwasm-stargate";
 import { RegistryClient, RegistryQueryClient } from "../Registry/Registry.client";
 import { EscrowClient, EscrowQueryClient } from "../Escrow/Escrow.client";
+import type { Expiration } from "../Escrow/Escrow.types";
 import type { HTTPaySDKConfig, HTTPayClients } from "../types";
 
 /**
@@ -115,6 +116,25 @@ export function toBase64(input: string): string {
   return Buffer.from(input, "utf-8").toString("base64");
 }
 
+/**
+ * Compute the base64 sha256 commitment to an auth token, as stored by the escrow
+ */
+export async function computeAuthCommitment(authToken: string): Promise<string> {
+  const digest = await globalThis.crypto.subtle.digest("SHA-256", new TextEncoder().encode(authToken));
+  return Buffer.from(digest).toString("base64");
+}
+
+/**
+ * Check whether an escrow expiration has been reached, matching the contract's rules
+ */
+export function isExpired(expires: Expiration, blockHeight: number, now: Date = new Date()): boolean {
+  if ("at_height" in expires) {
+    return blockHeight >= expires.at_height;
+  }
+  // at_time is a nanosecond timestamp
+  return BigInt(now.getTime()) * BigInt(1_000_000) >= BigInt(expires.at_time);
+}
+
 /**
  * Validate wallet address format
  */
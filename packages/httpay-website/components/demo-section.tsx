@@ -121,10 +121,11 @@ const DemoSectionContent = () => {
     setEscrowCreationError(null);
 
     try {
-      // Generate auth token for this test
-      const newAuthToken = `test-${Date.now()}-${Math.random()
-        .toString(36)
-        .substr(2, 9)}`;
+      // Generate an unguessable auth token for this test
+      const randomPart = Array.from(crypto.getRandomValues(new Uint8Array(16)), (byte) =>
+        byte.toString(16).padStart(2, "0")
+      ).join("");
+      const newAuthToken = `test-${Date.now()}-${randomPart}`;
       setAuthToken(newAuthToken);
 
       const escrowData = {
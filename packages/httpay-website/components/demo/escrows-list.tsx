@@ -8,6 +8,7 @@ import { Label } from "@/components/ui/label";
 import { Alert, AlertDescription } from "@/components/ui/alert";
 import { Badge } from "@/components/ui/badge";
 import { useSDK } from "@/providers/sdk-provider";
+import type { EscrowTypes } from "httpay";
 import type { EscrowsFilter } from "./types";
 
 export const EscrowsList = () => {
@@ -75,8 +76,11 @@ export const EscrowsList = () => {
     }
   };
 
-  const isEscrowExpired = (expires: number) => {
-    return currentBlockHeight !== null && expires < currentBlockHeight;
+  const isEscrowExpired = (expires: EscrowTypes.Expiration) => {
+    if ("at_time" in expires) {
+      return BigInt(expires.at_time) <= BigInt(Date.now()) * BigInt(1_000_000);
+    }
+    return currentBlockHeight !== null && expires.at_height <= currentBlockHeight;
   };
 
   const canRefundEscrow = (escrow: any) => {
@@ -89,17 +93,21 @@ export const EscrowsList = () => {
     );
   };
 
-  const formatExpiration = (expires: number) => {
+  const formatExpiration = (expires: EscrowTypes.Expiration) => {
     const isExpired = isEscrowExpired(expires);
     const status = isExpired ? " (Expired)" : "";
-    return `Block ${expires}${status}`;
+    if ("at_time" in expires) {
+      const millis = Number(BigInt(expires.at_time) / BigInt(1_000_000));
+      return `${new Date(millis).toLocaleString()}${status}`;
+    }
+    return `Block ${expires.at_height}${status}`;
   };
 
-  const formatAuthToken = (authToken: string) => {
-    if (!authToken) return "N/A";
+  const formatAuthCommitment = (authCommitment: string) => {
+    if (!authCommitment) return "N/A";
     // Show first 8 characters and last 4 for readability
-    if (authToken.length <= 12) return authToken;
-    return `${authToken.slice(0, 8)}...${authToken.slice(-4)}`;
+    if (authCommitment.length <= 12) return authCommitment;
+    return `${authCommitment.slice(0, 8)}...${authCommitment.slice(-4)}`;
   };
 
   return (
@@ -212,9 +220,9 @@ export const EscrowsList = () => {
                       </span>
                     </div>
                     <div>
-                      <span className="font-medium">Auth Token:</span>{" "}
+                      <span className="font-medium">Auth Commitment:</span>{" "}
                       <span className="text-muted-foreground font-mono">
-                        {formatAuthToken(escrow.auth_token)}
+                        {formatAuthCommitment(escrow.auth_commitment)}
                       </span>
                     </div>
                   </div>
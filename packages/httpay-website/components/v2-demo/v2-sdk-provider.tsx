@@ -180,8 +180,15 @@ export const V2SDKProvider = ({ children }: V2SDKProviderProps) => {
     try {
       setLoadingState("tools", true);
 
-      const response: RegistryTypes.ToolsResponse = await queryClient.getTools();
-      setTools(response.tools);
+      // GetTools is paginated; follow the cursor until every page is loaded
+      const allTools: RegistryTypes.ToolResponse[] = [];
+      let startAfter: string | undefined;
+      do {
+        const response: RegistryTypes.ToolsResponse = await queryClient.getTools({ startAfter });
+        allTools.push(...response.tools);
+        startAfter = response.next_start_after ?? undefined;
+      } while (startAfter);
+      setTools(allTools);
     } catch (error) {
       handleError(error, "loading tools");
     } finally {
@@ -9,7 +9,7 @@ set -e  # Exit on any error
 # Configuration
 WALLET="devwallet"
 LABEL_SUFFIX=$(date +%s)  # Add timestamp to labels for uniqueness
-FEE_PERCENTAGE=10  # Default fee percentage for escrow contract
+FEE_BPS=1000  # Default fee for escrow contract, in basis points (1000 = 10%)
 
 # Colors for output
 RED='\033[0;31m'
@@ -214,7 +214,7 @@ print_status "Instantiating escrow contract..."
 ESCROW_INSTANTIATE_MSG=$(cat <<EOF
 {
     "registry_addr": "$REGISTRY_ADDRESS",
-    "fee_percentage": $FEE_PERCENTAGE
+    "fee_bps": $FEE_BPS
 }
 EOF
 )
@@ -272,7 +272,7 @@ echo "  Code ID: $ESCROW_CODE_ID"
 echo "  Address: $ESCROW_ADDRESS"
 echo "  Store Tx: $ESCROW_STORE_TXHASH"
 echo "  Instantiate Tx: $ESCROW_INSTANTIATE_TXHASH"
-echo "  Fee Percentage: $FEE_PERCENTAGE%"
+echo "  Fee: $FEE_BPS bps"
 echo ""
 echo "Deployment completed successfully!"
 echo ""
@@ -294,7 +294,7 @@ cat > $DEPLOYMENT_FILE <<EOF
         "address": "$ESCROW_ADDRESS",
         "store_txhash": "$ESCROW_STORE_TXHASH",
         "instantiate_txhash": "$ESCROW_INSTANTIATE_TXHASH",
-        "fee_percentage": $FEE_PERCENTAGE
+        "fee_bps": $FEE_BPS
     }
 }
 EOF